@@ -12,12 +12,14 @@ cd /workspace
 
 # WRAPIX_AGENT selects the agent runtime. 'claude' (default) runs claude with
 # config merging and permission bypass; 'pi' runs pi-mono in NDJSON RPC mode
-# and skips Claude-specific config (pi has no permission system or settings.json).
+# and skips Claude-specific config (pi has no permission system or settings.json);
+# 'command' runs a loom [backends.<name>] agent, whose argv arrives as the
+# command override, and likewise skips Claude-specific config.
 WRAPIX_AGENT="${WRAPIX_AGENT:-claude}"
 case "$WRAPIX_AGENT" in
-  claude|pi) ;;
+  claude|pi|command) ;;
   *)
-    echo "Error: unknown WRAPIX_AGENT: $WRAPIX_AGENT (expected 'claude' or 'pi')" >&2
+    echo "Error: unknown WRAPIX_AGENT: $WRAPIX_AGENT (expected 'claude', 'pi' or 'command')" >&2
     exit 1
     ;;
 esac
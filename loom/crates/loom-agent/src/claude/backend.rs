@@ -14,7 +14,7 @@ use std::process::Stdio;
 use std::time::Duration;
 
 use loom_core::agent::{
-    AgentBackend, AgentSession, Idle, NdjsonReader, ProtocolError, SpawnConfig,
+    AgentBackend, AgentSession, Idle, NdjsonReader, ProtocolError, SpawnConfig, ToolGate,
 };
 use nix::sys::signal::{Signal, kill};
 use nix::unistd::Pid;
@@ -52,7 +52,9 @@ const ENV_WRAPIX_BIN: &str = "LOOM_WRAPIX_BIN";
 /// [`AgentBackend::spawn`]. The body launches `claude --print
 /// --input-format stream-json --output-format stream-json` (via
 /// `wrapix run-bead --stdio`) with `--permission-prompt-tool stdio` so
-/// tool permissions flow over the same pipe.
+/// tool permissions flow over the same pipe — the invocation
+/// `AgentSelection::apply_to` puts at the head of `agent_args`, which the
+/// wrapper runs as the container command.
 pub struct ClaudeBackend;
 
 impl AgentBackend for ClaudeBackend {
@@ -73,7 +75,7 @@ impl AgentBackend for ClaudeBackend {
             .arg(&spawn_config_path)
             .arg("--stdio");
 
-        spawn_session(cmd, Vec::new()).await
+        spawn_session(cmd, config.tool_policy.compile()?).await
     }
 }
 
@@ -144,7 +146,7 @@ pub(crate) fn prepare_runtime(config: &SpawnConfig) -> Result<PathBuf, ProtocolE
 /// binary in place of the real `wrapix run-bead` exec.
 pub(crate) async fn spawn_session(
     mut cmd: Command,
-    denied_tools: Vec<String>,
+    gate: ToolGate,
 ) -> Result<AgentSession<Idle>, ProtocolError> {
     cmd.stdin(Stdio::piped());
     cmd.stdout(Stdio::piped());
@@ -161,7 +163,7 @@ pub(crate) async fn spawn_session(
         .take()
         .ok_or_else(|| ProtocolError::Io(io::Error::other("claude child stdout not piped")))?;
 
-    let parser = ClaudeParser::new(denied_tools);
+    let parser = ClaudeParser::new(gate);
     Ok(AgentSession::new(
         child,
         BufWriter::new(stdin),
@@ -221,7 +223,7 @@ fn send_signal(child: &Child, sig: Signal) {
 )]
 mod tests {
     use super::*;
-    use loom_core::agent::{AgentEvent, RePinContent};
+    use loom_core::agent::{AgentEvent, RePinContent, ToolPolicy};
     use std::path::PathBuf;
     use std::time::Instant;
 
@@ -256,6 +258,9 @@ mod tests {
             agent_args: vec!["--print".into()],
             repin: sample_repin(),
             model: None,
+            thinking: None,
+            command_protocol: None,
+            tool_policy: ToolPolicy::default(),
         };
 
         let spawn_config_path = prepare_runtime(&cfg).expect("prepare_runtime");
@@ -287,7 +292,7 @@ mod tests {
 
     #[tokio::test]
     async fn steering_message_reaches_mock_and_emits_followup_turn() {
-        let session = spawn_session(mock_command("steering"), Vec::new())
+        let session = spawn_session(mock_command("steering"), ToolGate::default())
             .await
             .expect("spawn session");
         let mut session = session.prompt("first prompt").await.expect("prompt ok");
@@ -336,7 +341,7 @@ mod tests {
 
     #[tokio::test]
     async fn shutdown_watchdog_escalates_to_sigkill_when_child_ignores_stdin_close() {
-        let session = spawn_session(mock_command("ignore-stdin"), Vec::new())
+        let session = spawn_session(mock_command("ignore-stdin"), ToolGate::default())
             .await
             .expect("spawn session");
         let mut session = session.prompt("hello").await.expect("prompt ok");
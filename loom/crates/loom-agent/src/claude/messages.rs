@@ -52,8 +52,9 @@ pub enum ClaudeMessage {
 
     /// Tool permission probe. With `--permission-prompt-tool stdio`, claude
     /// emits these and expects a `control_response` on stdin. Loom auto-
-    /// approves (sandbox is the trust boundary) but logs every approval at
-    /// `info!` for an audit trail.
+    /// approves whatever the `[security]` tool policy does not deny (sandbox
+    /// is the trust boundary) and logs every decision at `info!` for an
+    /// audit trail.
     #[serde(rename = "control_request")]
     ControlRequest {
         id: RequestId,
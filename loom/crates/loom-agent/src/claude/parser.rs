@@ -2,13 +2,11 @@
 //!
 //! [`LineParse`] impl that turns NDJSON lines from `claude --output-format
 //! stream-json` into [`AgentEvent`]s and encodes driver-side stream-json
-//! user messages (initial prompt, mid-session steering). Auto-approval of
-//! `control_request` lines is keyed off a `denied_tools` set passed in by
-//! the workflow layer (config-driven).
+//! user messages (initial prompt, mid-session steering). `control_request`
+//! lines are answered by a [`ToolGate`] compiled from the phase's
+//! `[security]` policy (config-driven).
 
-use std::collections::HashSet;
-
-use loom_core::agent::{AgentEvent, LineParse, ParsedLine, ProtocolError};
+use loom_core::agent::{AgentEvent, LineParse, ParsedLine, ProtocolError, ToolDecision, ToolGate};
 use loom_core::identifier::RequestId;
 use serde::Serialize;
 use tracing::{info, trace};
@@ -20,20 +18,19 @@ use super::messages::{AssistantBlock, ClaudeMessage, UserBlock};
 /// Stateless dispatch layer between
 /// [`AgentSession`](loom_core::agent::AgentSession) and
 /// [`messages::ClaudeMessage`](super::messages::ClaudeMessage). Auto-approves
-/// every tool-permission `control_request` whose tool name is **not** in
-/// `denied_tools`; denied tools receive `approved: false`.
+/// every tool-permission `control_request` its [`ToolGate`] allows; a denied
+/// call receives `approved: false` with the rule's reason, and surfaces as
+/// an [`AgentEvent::Error`].
 pub struct ClaudeParser {
-    denied_tools: HashSet<String>,
+    gate: ToolGate,
 }
 
 impl ClaudeParser {
-    /// Build a parser with the configured deny-list. The list is loaded by
-    /// the workflow layer from `[agent.claude].denied_tools`; the parser
-    /// owns no policy of its own.
-    pub fn new(denied_tools: Vec<String>) -> Self {
-        Self {
-            denied_tools: denied_tools.into_iter().collect(),
-        }
+    /// Build a parser answering permission requests with `gate`. The policy
+    /// is loaded by the workflow layer from `[security]`; the parser owns no
+    /// policy of its own.
+    pub fn new(gate: ToolGate) -> Self {
+        Self { gate }
     }
 }
 
@@ -43,6 +40,8 @@ struct ControlResponse<'a> {
     kind: &'static str,
     id: &'a RequestId,
     approved: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<&'a str>,
 }
 
 #[derive(Serialize)]
@@ -166,22 +165,34 @@ impl LineParse for ClaudeParser {
             ClaudeMessage::ControlRequest { id, tool, input } => {
                 let serialized = input.to_string();
                 let truncated: String = serialized.chars().take(200).collect();
-                let approved = !self.denied_tools.contains(&tool);
+                let decision = self.gate.decide(&tool, &input);
+                let reason = match &decision {
+                    ToolDecision::Allow => None,
+                    ToolDecision::Deny { reason } => Some(reason.as_str()),
+                };
                 info!(
                     tool = %tool,
                     input = %truncated,
-                    approved,
+                    approved = reason.is_none(),
+                    reason,
                     "claude tool permission request",
                 );
                 let resp = ControlResponse {
                     kind: "control_response",
                     id: &id,
-                    approved,
+                    approved: reason.is_none(),
+                    reason,
                 };
                 let mut response = serde_json::to_string(&resp)?;
                 response.push('\n');
+                let events = reason
+                    .map(|reason| AgentEvent::Error {
+                        message: format!("tool call `{tool}` denied: {reason}"),
+                    })
+                    .into_iter()
+                    .collect();
                 Ok(ParsedLine {
-                    events: Vec::new(),
+                    events,
                     response: Some(response),
                 })
             }
@@ -231,7 +242,7 @@ fn encode_user_message(msg: &str) -> Result<String, ProtocolError> {
 )]
 mod tests {
     use super::*;
-    use loom_core::agent::AgentEvent;
+    use loom_core::agent::{AgentEvent, ToolAction, ToolPolicy, ToolRule};
 
     fn parse(parser: &ClaudeParser, line: &str) -> ParsedLine {
         parser
@@ -240,7 +251,14 @@ mod tests {
     }
 
     fn empty() -> ClaudeParser {
-        ClaudeParser::new(Vec::new())
+        ClaudeParser::new(ToolGate::default())
+    }
+
+    fn denying(tools: &[&str]) -> ClaudeParser {
+        let gate = ToolPolicy::deny_tools(tools.iter().copied())
+            .compile()
+            .expect("deny-list compiles");
+        ClaudeParser::new(gate)
     }
 
     // -- test_claude_stream_json_parsing -----------------------------------
@@ -392,17 +410,59 @@ mod tests {
 
     #[test]
     fn control_request_denied_when_tool_in_denylist() {
-        let parser = ClaudeParser::new(vec!["WebFetch".to_string()]);
+        let parser = denying(&["WebFetch"]);
         let line = r#"{"type":"control_request","id":"req_02","tool":"WebFetch","input":{"url":"https://example.com"}}"#;
         let p = parse(&parser, line);
         let resp = p.response.expect("control_response present");
         assert!(resp.contains(r#""approved":false"#));
         assert!(resp.contains(r#""id":"req_02""#));
+        assert!(resp.contains(r#""reason":"`WebFetch` is denied by loom's tool policy""#));
+        assert!(matches!(
+            p.events.as_slice(),
+            [AgentEvent::Error { message }] if message.contains("WebFetch")
+        ));
+    }
+
+    #[test]
+    fn control_request_denied_by_path_rule_returns_its_reason() {
+        let gate = ToolPolicy {
+            rules: vec![ToolRule {
+                action: ToolAction::Deny,
+                tools: vec!["Edit".into()],
+                path: Some(".github/**".into()),
+                command: None,
+                command_regex: None,
+                reason: Some("never touch .github/".into()),
+            }],
+        }
+        .compile()
+        .expect("policy compiles");
+        let parser = ClaudeParser::new(gate);
+        let line = r#"{"type":"control_request","id":"req_04","tool":"Edit","input":{"file_path":"/workspace/.github/workflows/ci.yml"}}"#;
+        let p = parse(&parser, line);
+        let resp = p.response.expect("control_response present");
+        assert!(resp.contains(r#""approved":false"#));
+        assert!(resp.contains(r#""reason":"never touch .github/""#));
+        match p.events.as_slice() {
+            [AgentEvent::Error { message }] => {
+                assert_eq!(message, "tool call `Edit` denied: never touch .github/");
+            }
+            other => panic!("expected one Error event, got {other:?}"),
+        }
+
+        let elsewhere = r#"{"type":"control_request","id":"req_05","tool":"Edit","input":{"file_path":"/workspace/src/lib.rs"}}"#;
+        let p = parse(&parser, elsewhere);
+        assert!(p.events.is_empty());
+        assert!(
+            p.response
+                .expect("control_response")
+                .contains(r#""approved":true"#)
+        );
     }
 
     #[test]
     fn control_request_denylist_does_not_affect_other_tools() {
-        let parser = ClaudeParser::new(vec!["WebFetch".to_string()]);
+        let parser = denying(&["WebFetch"]);
         let line = r#"{"type":"control_request","id":"req_03","tool":"Read","input":{}}"#;
         let p = parse(&parser, line);
         let resp = p.response.expect("control_response present");
@@ -0,0 +1,196 @@
+//! Command backend: spawn a `[backends.<name>]` agent.
+//!
+//! [`CommandBackend::spawn`] writes the [`SpawnConfig`] the same way the pi
+//! backend does and execs `wrapix run-bead --spawn-config <file> --stdio`.
+//! The wrapper runs the agent's argv (already at the head of
+//! [`SpawnConfig::agent_args`]) as the container command; the host side
+//! reads the session with the parser for [`SpawnConfig::command_protocol`].
+//! No handshake runs — a `pi-rpc` agent is not probed, and model
+//! parameters are the argv's business.
+
+use std::ffi::OsString;
+use std::io;
+use std::process::Stdio;
+
+use loom_core::agent::{
+    AgentBackend, AgentSession, CommandProtocol, Idle, NdjsonReader, ProtocolError, SpawnConfig,
+    ToolGate,
+};
+use tokio::io::BufWriter;
+use tokio::process::Command;
+use tracing::info;
+
+use super::parser::parser_for;
+use crate::pi::backend::write_spawn_config;
+
+/// Env var that overrides the launcher binary, shared with the other
+/// backends.
+const ENV_WRAPIX_BIN: &str = "LOOM_WRAPIX_BIN";
+
+/// Zero-sized marker for command agents. Which agent runs, and how it
+/// talks, is carried entirely by the [`SpawnConfig`].
+pub struct CommandBackend;
+
+impl AgentBackend for CommandBackend {
+    async fn spawn(config: &SpawnConfig) -> Result<AgentSession<Idle>, ProtocolError> {
+        let protocol = config.command_protocol.ok_or(ProtocolError::Unsupported)?;
+        let spawn_config_path = write_spawn_config(config)?;
+
+        let wrapix_bin =
+            std::env::var_os(ENV_WRAPIX_BIN).unwrap_or_else(|| OsString::from("wrapix"));
+        info!(
+            wrapix = %wrapix_bin.to_string_lossy(),
+            spawn_config = %spawn_config_path.display(),
+            protocol = protocol.as_str(),
+            "command backend spawn",
+        );
+
+        let mut cmd = Command::new(&wrapix_bin);
+        cmd.arg("run-bead")
+            .arg("--spawn-config")
+            .arg(&spawn_config_path)
+            .arg("--stdio");
+
+        spawn_session(cmd, protocol, config.tool_policy.compile()?)
+    }
+}
+
+/// Build an [`AgentSession`] from a launcher [`Command`], the agent's
+/// protocol and the [`ToolGate`] its permission requests go to.
+/// Module-private for the same reason as the claude backend's:
+/// tests substitute a plain shell command for `wrapix run-bead`.
+pub(crate) fn spawn_session(
+    mut cmd: Command,
+    protocol: CommandProtocol,
+    gate: ToolGate,
+) -> Result<AgentSession<Idle>, ProtocolError> {
+    cmd.stdin(Stdio::piped());
+    cmd.stdout(Stdio::piped());
+    cmd.stderr(Stdio::inherit());
+    cmd.kill_on_drop(true);
+
+    let mut child = cmd.spawn().map_err(ProtocolError::Io)?;
+    let stdin = child
+        .stdin
+        .take()
+        .ok_or_else(|| ProtocolError::Io(io::Error::other("command child stdin not piped")))?;
+    let stdout = child
+        .stdout
+        .take()
+        .ok_or_else(|| ProtocolError::Io(io::Error::other("command child stdout not piped")))?;
+
+    Ok(AgentSession::new(
+        child,
+        BufWriter::new(stdin),
+        NdjsonReader::new(stdout),
+        parser_for(protocol, gate),
+    ))
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::expect_used,
+    clippy::panic,
+    reason = "tests use panicking helpers"
+)]
+mod tests {
+    use super::*;
+    use loom_core::agent::{AgentEvent, ToolPolicy};
+
+    fn sh(script: &str) -> Command {
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c").arg(script);
+        cmd
+    }
+
+    /// Drive a plain-text session to completion, returning its text and
+    /// exit code.
+    async fn run_plain_text(script: &str, prompt: &str) -> (String, i32) {
+        let session = spawn_session(sh(script), CommandProtocol::PlainText, ToolGate::default())
+            .expect("spawn");
+        let mut session = session.prompt(prompt).await.expect("prompt");
+        let mut text = String::new();
+        loop {
+            match session.next_event().await.expect("event") {
+                Some(AgentEvent::MessageDelta { text: delta }) => text.push_str(&delta),
+                Some(AgentEvent::SessionComplete { exit_code, .. }) => return (text, exit_code),
+                Some(other) => panic!("unexpected event {other:?}"),
+                None => panic!("plain-text session ended without SessionComplete"),
+            }
+        }
+    }
+
+    /// `cat` only exits once stdin closes, so finishing at all proves the
+    /// one-shot prompt closed it.
+    #[tokio::test]
+    async fn plain_text_closes_stdin_after_the_prompt() {
+        let (text, exit_code) = run_plain_text("cat", "fix the parser\nLOOM_COMPLETE").await;
+        assert_eq!(text, "fix the parser\nLOOM_COMPLETE\n");
+        assert_eq!(exit_code, 0);
+    }
+
+    #[tokio::test]
+    async fn plain_text_reports_the_exit_status_at_eof() {
+        let (text, exit_code) =
+            run_plain_text("read -r task; echo \"cannot: $task\"; exit 3", "do it").await;
+        assert_eq!(text, "cannot: do it\n");
+        assert_eq!(exit_code, 3);
+    }
+
+    #[tokio::test]
+    async fn claude_stream_json_agents_answer_to_the_tool_policy() {
+        let script = r#"read -r prompt
+echo '{"type":"control_request","id":"req_01","tool":"WebFetch","input":{"url":"https://example.com"}}'
+read -r reply
+case "$reply" in
+  *'"approved":false'*) echo '{"type":"result","subtype":"success"}' ;;
+  *) echo '{"type":"result","subtype":"error","result":"approved"}' ;;
+esac"#;
+        let gate = ToolPolicy::deny_tools(["WebFetch"])
+            .compile()
+            .expect("policy compiles");
+        let session =
+            spawn_session(sh(script), CommandProtocol::ClaudeStreamJson, gate).expect("spawn");
+        let mut session = session.prompt("fetch it").await.expect("prompt");
+        let mut errors = Vec::new();
+        loop {
+            match session.next_event().await.expect("event") {
+                Some(AgentEvent::Error { message }) => errors.push(message),
+                Some(AgentEvent::SessionComplete { exit_code, .. }) => {
+                    assert_eq!(exit_code, 0, "the agent was not denied");
+                    break;
+                }
+                Some(_) => {}
+                None => panic!("session ended without SessionComplete"),
+            }
+        }
+        assert!(
+            matches!(errors.as_slice(), [message] if message.contains("WebFetch")),
+            "{errors:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn spawn_without_a_protocol_is_unsupported() {
+        let config = SpawnConfig {
+            image: "wrapix-base:latest".into(),
+            workspace: std::env::temp_dir(),
+            env: vec![],
+            initial_prompt: String::new(),
+            agent_args: vec![],
+            repin: loom_core::agent::RePinContent {
+                orientation: String::new(),
+                pinned_context: String::new(),
+                partial_bodies: vec![],
+            },
+            model: None,
+            thinking: None,
+            command_protocol: None,
+            tool_policy: ToolPolicy::default(),
+        };
+        assert!(matches!(
+            CommandBackend::spawn(&config).await,
+            Err(ProtocolError::Unsupported)
+        ));
+    }
+}
@@ -0,0 +1,15 @@
+//! Command backend: `[backends.<name>]` agents.
+//!
+//! Two submodules split the wire surface from the process surface:
+//!
+//! - [`parser`] — picks the [`LineParse`](loom_core::agent::LineParse) for
+//!   the configured protocol: the claude or pi parser, or the minimal
+//!   line-oriented [`PlainTextParser`](parser::PlainTextParser).
+//! - [`backend`] — the [`CommandBackend`] zero-sized type plus its
+//!   [`AgentBackend`](loom_core::agent::AgentBackend) impl.
+
+pub mod backend;
+pub mod parser;
+
+pub use backend::CommandBackend;
+pub use parser::{PlainTextParser, parser_for};
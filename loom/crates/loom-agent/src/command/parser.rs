@@ -0,0 +1,93 @@
+//! Line parsers for command agents.
+
+use loom_core::agent::{
+    AgentEvent, CommandProtocol, LineParse, ParsedLine, ProtocolError, ToolGate,
+};
+
+use crate::claude::parser::ClaudeParser;
+use crate::pi::parser::PiParser;
+
+/// The parser for a command agent speaking `protocol`. A claude
+/// stream-json agent's permission requests are answered by `gate`, the
+/// phase's `[security]` rules; the other protocols never ask.
+pub fn parser_for(protocol: CommandProtocol, gate: ToolGate) -> Box<dyn LineParse + Send> {
+    match protocol {
+        CommandProtocol::ClaudeStreamJson => Box::new(ClaudeParser::new(gate)),
+        CommandProtocol::PiRpc => Box::new(PiParser::new()),
+        CommandProtocol::PlainText => Box::new(PlainTextParser),
+    }
+}
+
+/// `plain-text` protocol parser. The prompt goes to stdin as-is and stdin
+/// is closed after it; each non-empty stdout line becomes a `MessageDelta`,
+/// so exit signals (`LOOM_COMPLETE`, `LOOM_BLOCKED`, ...) still reach the
+/// driver. The session ends when the process exits.
+pub struct PlainTextParser;
+
+impl LineParse for PlainTextParser {
+    fn parse_line(&self, line: &str) -> Result<ParsedLine, ProtocolError> {
+        Ok(ParsedLine {
+            events: vec![AgentEvent::MessageDelta {
+                text: format!("{line}\n"),
+            }],
+            response: None,
+        })
+    }
+
+    fn encode_prompt(&self, msg: &str) -> Result<String, ProtocolError> {
+        if msg.ends_with('\n') {
+            Ok(msg.to_string())
+        } else {
+            Ok(format!("{msg}\n"))
+        }
+    }
+
+    /// Stdin is closed once the prompt is sent, so there is nothing to
+    /// steer through.
+    fn encode_steer(&self, _msg: &str) -> Result<String, ProtocolError> {
+        Err(ProtocolError::Unsupported)
+    }
+
+    fn encode_abort(&self) -> Result<Option<String>, ProtocolError> {
+        Ok(None)
+    }
+
+    fn one_shot(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::expect_used,
+    clippy::panic,
+    reason = "tests use panicking helpers"
+)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_text_lines_become_message_deltas() {
+        let parsed = PlainTextParser
+            .parse_line("LOOM_COMPLETE")
+            .expect("plain text always parses");
+        match parsed.events.as_slice() {
+            [AgentEvent::MessageDelta { text }] => assert_eq!(text, "LOOM_COMPLETE\n"),
+            other => panic!("expected one MessageDelta, got {other:?}"),
+        }
+        assert!(parsed.response.is_none());
+    }
+
+    #[test]
+    fn plain_text_prompt_is_newline_terminated_and_one_shot() {
+        let encode = |msg| PlainTextParser.encode_prompt(msg).expect("encode");
+        assert_eq!(encode("fix it"), "fix it\n");
+        assert_eq!(encode("fix it\n"), "fix it\n");
+        assert!(matches!(
+            PlainTextParser.encode_steer("more"),
+            Err(ProtocolError::Unsupported)
+        ));
+        assert!(PlainTextParser.one_shot());
+        assert!(!parser_for(CommandProtocol::PiRpc, ToolGate::default()).one_shot());
+    }
+}
@@ -15,13 +15,30 @@
 //! - `claude/parser.rs`, `claude/messages.rs` — wx-pkht8.7
 //! - `claude/backend.rs` — wx-pkht8.8
 //!
+//! [`CommandBackend`] runs any `[backends.<name>]` agent, reading it with
+//! one of those parsers or a plain-text one according to its configured
+//! protocol.
+//!
+//! [`ReplayBackend`] is a test-and-diagnostics backend: it feeds a
+//! recorded wire capture back through the real parsers (see [`replay`]).
+//! With the `mock` feature, `MockBackend` plays a scripted scenario the
+//! same way, for end-to-end tests without a container or model.
+//!
 //! This crate currently exposes the skeleton: the ZST types, the module
 //! layout, and `AgentBackend` impls that fail closed with
 //! [`ProtocolError::Unsupported`](loom_core::agent::ProtocolError::Unsupported)
 //! so a half-wired call site cannot accidentally drive a real container.
 
 pub mod claude;
+pub mod command;
+#[cfg(feature = "mock")]
+pub mod mock;
 pub mod pi;
+pub mod replay;
 
 pub use claude::ClaudeBackend;
+pub use command::CommandBackend;
+#[cfg(feature = "mock")]
+pub use mock::MockBackend;
 pub use pi::PiBackend;
+pub use replay::{ReplayBackend, WireRecording, replay_session};
@@ -0,0 +1,419 @@
+//! Mock backend: play a scripted scenario instead of running an agent.
+//!
+//! Built only with the `mock` feature, for end-to-end tests of `loom run`,
+//! serial and `--parallel`, against a real git repo and a fake `bd` — no
+//! container, no model. `loom check` is not covered: its reviewer dispatch
+//! is still a stub, so a scenario never reaches it. [`MockBackend::spawn`] reads the [`MockScenario`]
+//! named by `LOOM_MOCK_SCENARIO`, carries out its file edits and commits in
+//! the session's workspace host-side, and replays the matching
+//! [`AgentEvent`]s through the same stand-in process as [`crate::replay`],
+//! so the session, the event stream and the exit-signal parsing downstream
+//! all run as they would against a real agent.
+//!
+//! A scenario is JSON:
+//!
+//! ```json
+//! {
+//!   "fail_times": 1,
+//!   "steps": [
+//!     { "say": "Fixing the parser." },
+//!     { "edit": { "path": "src/{workspace}.rs", "content": "// fixed\n" } },
+//!     { "tool": { "name": "bash", "params": { "command": "cargo test" }, "output": "ok" } },
+//!     { "commit": "Fix the parser" }
+//!   ],
+//!   "finish": "complete"
+//! }
+//! ```
+//!
+//! The first `fail_times` sessions started against a scenario fail (an
+//! error event and exit code 1, no edits); every later one plays `steps`
+//! and ends on `finish` — `"complete"`, `{ "blocked": "<reason>" }` or
+//! `{ "clarify": "<question>" }`, emitted as the matching `LOOM_*` signal.
+//! `{workspace}` in any step is replaced by the name of the session's
+//! workspace directory — the bead id in a `--parallel` worktree — so one
+//! scenario can drive several slots without their edits conflicting.
+//! Sessions are counted by claiming `<scenario>.attempt-<n>` files next to
+//! the scenario, so concurrent `--parallel` slots each get their own number.
+
+use std::fs::OpenOptions;
+use std::io::{self, ErrorKind};
+use std::path::{Component, Path, PathBuf};
+
+use loom_core::agent::{
+    AgentBackend, AgentEvent, AgentSession, Idle, LineParse, ParsedLine, ProtocolError, SpawnConfig,
+};
+use loom_core::identifier::ToolCallId;
+use serde::Deserialize;
+use serde_json::{Value, json};
+use tracing::info;
+
+use crate::replay::spawn_feed;
+
+/// Env var naming the scenario file [`MockBackend::spawn`] plays.
+pub const ENV_MOCK_SCENARIO: &str = "LOOM_MOCK_SCENARIO";
+
+/// A scripted agent session.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MockScenario {
+    /// Sessions that fail before the scenario starts succeeding.
+    #[serde(default)]
+    pub fail_times: u32,
+    #[serde(default)]
+    pub steps: Vec<MockStep>,
+    #[serde(default)]
+    pub finish: MockFinish,
+}
+
+/// One thing the mock agent does, in order.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum MockStep {
+    /// Stream `text` as assistant message.
+    Say(String),
+    /// Report a tool call and its result; nothing is run.
+    Tool {
+        name: String,
+        #[serde(default)]
+        params: Value,
+        #[serde(default)]
+        output: String,
+        #[serde(default)]
+        is_error: bool,
+    },
+    /// Write `content` to `path`, relative to the workspace.
+    Edit { path: PathBuf, content: String },
+    /// `git add -A && git commit -m <message>` in the workspace.
+    Commit(String),
+}
+
+/// How a successful session ends.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum MockFinish {
+    #[default]
+    Complete,
+    Blocked(String),
+    Clarify(String),
+}
+
+impl MockScenario {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+    }
+
+    /// Play session number `attempt` (0-based) in `workspace`: carry out
+    /// its edits and commits, and return the events the agent would have
+    /// emitted.
+    pub fn play(&self, attempt: u32, workspace: &Path) -> io::Result<Vec<AgentEvent>> {
+        if attempt < self.fail_times {
+            return Ok(vec![
+                AgentEvent::Error {
+                    message: format!(
+                        "mock scenario: scripted failure {} of {}",
+                        attempt + 1,
+                        self.fail_times
+                    ),
+                },
+                AgentEvent::SessionComplete {
+                    exit_code: 1,
+                    cost_usd: None,
+                },
+            ]);
+        }
+        let name = workspace
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let fill = |text: &str| text.replace("{workspace}", &name);
+        let mut events = Vec::new();
+        for (n, step) in self.steps.iter().enumerate() {
+            let id = ToolCallId::new(format!("mock-{n}"));
+            match step {
+                MockStep::Say(text) => events.push(AgentEvent::MessageDelta {
+                    text: format!("{}\n", fill(text).trim_end()),
+                }),
+                MockStep::Tool {
+                    name,
+                    params,
+                    output,
+                    is_error,
+                } => tool(&mut events, id, name, params.clone(), output, *is_error),
+                MockStep::Edit { path, content } => {
+                    let path = PathBuf::from(fill(&path.to_string_lossy()));
+                    if !is_workspace_relative(&path) {
+                        return Err(io::Error::new(
+                            ErrorKind::InvalidInput,
+                            format!("mock edit path escapes the workspace: {}", path.display()),
+                        ));
+                    }
+                    let content = fill(content);
+                    let target = workspace.join(&path);
+                    if let Some(parent) = target.parent() {
+                        std::fs::create_dir_all(parent)?;
+                    }
+                    std::fs::write(&target, &content)?;
+                    let output = format!("wrote {} bytes", content.len());
+                    tool(
+                        &mut events,
+                        id,
+                        "write",
+                        json!({ "path": path }),
+                        &output,
+                        false,
+                    );
+                }
+                MockStep::Commit(message) => {
+                    let message = fill(message);
+                    let (output, failed) = commit(workspace, &message)?;
+                    let params = json!({ "command": format!("git commit -m {message:?}") });
+                    tool(&mut events, id, "bash", params, &output, failed);
+                }
+            }
+        }
+        let signal = match &self.finish {
+            MockFinish::Complete => "LOOM_COMPLETE\n".to_string(),
+            MockFinish::Blocked(reason) => format!("{reason}\nLOOM_BLOCKED\n"),
+            MockFinish::Clarify(question) => format!("{question}\nLOOM_CLARIFY\n"),
+        };
+        events.push(AgentEvent::MessageDelta { text: signal });
+        events.push(AgentEvent::TurnEnd);
+        events.push(AgentEvent::SessionComplete {
+            exit_code: 0,
+            cost_usd: None,
+        });
+        Ok(events)
+    }
+}
+
+/// Zero-sized mock backend. Ignores everything in the [`SpawnConfig`] but
+/// the workspace; the script comes from [`ENV_MOCK_SCENARIO`].
+pub struct MockBackend;
+
+impl AgentBackend for MockBackend {
+    async fn spawn(config: &SpawnConfig) -> Result<AgentSession<Idle>, ProtocolError> {
+        let path = std::env::var_os(ENV_MOCK_SCENARIO)
+            .map(PathBuf::from)
+            .ok_or_else(|| {
+                ProtocolError::Io(io::Error::other(format!("{ENV_MOCK_SCENARIO} is not set")))
+            })?;
+        let scenario = MockScenario::load(&path)?;
+        let attempt = claim_attempt(&path)?;
+        info!(
+            scenario = %path.display(),
+            attempt,
+            workspace = %config.workspace.display(),
+            "mock backend spawn",
+        );
+        let events = scenario.play(attempt, &config.workspace)?;
+        spawn_feed(feed_lines(&events)?, Box::new(MockParser))
+    }
+}
+
+/// Reads the feed's serialized [`AgentEvent`]s back; the prompt is written
+/// to a stand-in that discards it.
+struct MockParser;
+
+impl LineParse for MockParser {
+    fn parse_line(&self, line: &str) -> Result<ParsedLine, ProtocolError> {
+        Ok(ParsedLine {
+            events: vec![serde_json::from_str(line)?],
+            response: None,
+        })
+    }
+
+    fn encode_prompt(&self, msg: &str) -> Result<String, ProtocolError> {
+        Ok(format!("{}\n", json!({ "type": "prompt", "message": msg })))
+    }
+
+    fn encode_steer(&self, msg: &str) -> Result<String, ProtocolError> {
+        Ok(format!("{}\n", json!({ "type": "steer", "message": msg })))
+    }
+
+    fn encode_abort(&self) -> Result<Option<String>, ProtocolError> {
+        Ok(None)
+    }
+}
+
+fn tool(
+    events: &mut Vec<AgentEvent>,
+    id: ToolCallId,
+    name: &str,
+    params: Value,
+    output: &str,
+    is_error: bool,
+) {
+    events.push(AgentEvent::ToolCall {
+        id: id.clone(),
+        tool: name.to_string(),
+        params,
+    });
+    events.push(AgentEvent::ToolResult {
+        id,
+        output: output.to_string(),
+        is_error,
+    });
+}
+
+/// Commit everything in `workspace`, returning git's output and whether
+/// the commit failed (nothing to commit, say) — a failed commit is a
+/// failing tool call, not a spawn error.
+fn commit(workspace: &Path, message: &str) -> io::Result<(String, bool)> {
+    let add = std::process::Command::new("git")
+        .current_dir(workspace)
+        .args(["add", "-A"])
+        .output()?;
+    let out = if add.status.success() {
+        std::process::Command::new("git")
+            .current_dir(workspace)
+            .args(["commit", "-q", "-m", message])
+            .output()?
+    } else {
+        add
+    };
+    let mut text = String::from_utf8_lossy(&out.stdout).into_owned();
+    text.push_str(&String::from_utf8_lossy(&out.stderr));
+    Ok((text, !out.status.success()))
+}
+
+/// Claim the next session number for the scenario at `path` by creating
+/// `<path>.attempt-<n>` for the first free `n`.
+fn claim_attempt(path: &Path) -> io::Result<u32> {
+    let mut marker = path.as_os_str().to_owned();
+    marker.push(".attempt-");
+    for n in 0.. {
+        let mut candidate = marker.clone();
+        candidate.push(n.to_string());
+        match OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&candidate)
+        {
+            Ok(_) => return Ok(n),
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e),
+        }
+    }
+    Err(io::Error::other("mock scenario attempts exhausted"))
+}
+
+fn is_workspace_relative(path: &Path) -> bool {
+    path.components()
+        .all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+}
+
+/// The stand-in's stdout: one serialized event per line, read back by
+/// [`MockParser`].
+fn feed_lines(events: &[AgentEvent]) -> Result<Vec<String>, serde_json::Error> {
+    events.iter().map(serde_json::to_string).collect()
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::expect_used,
+    clippy::panic,
+    reason = "tests use panicking helpers"
+)]
+mod tests {
+    use super::*;
+
+    fn scenario(src: &str) -> MockScenario {
+        serde_json::from_str(src).expect("scenario parses")
+    }
+
+    #[test]
+    fn scripted_failures_come_before_the_steps() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let s = scenario(
+            r#"{"fail_times": 1, "steps": [{"edit": {"path": "a.txt", "content": "x"}}]}"#,
+        );
+        let failed = s.play(0, dir.path()).expect("play");
+        assert!(matches!(
+            failed.last(),
+            Some(AgentEvent::SessionComplete { exit_code: 1, .. })
+        ));
+        assert!(!dir.path().join("a.txt").exists());
+
+        let passed = s.play(1, dir.path()).expect("play");
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("a.txt")).expect("edited"),
+            "x"
+        );
+        match &passed[passed.len() - 3] {
+            AgentEvent::MessageDelta { text } => assert_eq!(text, "LOOM_COMPLETE\n"),
+            other => panic!("expected the exit signal, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn finish_blocked_ends_on_the_reason_and_signal() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let s = scenario(
+            r#"{"steps": [{"tool": {"name": "bash", "params": {"command": "make"}, "output": "boom", "is_error": true}}],
+                "finish": {"blocked": "The build is broken."}}"#,
+        );
+        let events = s.play(0, dir.path()).expect("play");
+        assert!(matches!(
+            &events[1],
+            AgentEvent::ToolResult { output, is_error: true, .. } if output == "boom"
+        ));
+        assert!(matches!(
+            &events[2],
+            AgentEvent::MessageDelta { text } if text == "The build is broken.\nLOOM_BLOCKED\n"
+        ));
+    }
+
+    #[test]
+    fn edits_fill_the_workspace_name_and_stay_inside_it() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let workspace = dir.path().join("wx-1");
+        std::fs::create_dir(&workspace).expect("mkdir");
+        scenario(
+            r#"{"steps": [{"edit": {"path": "out/{workspace}.txt", "content": "{workspace}"}}]}"#,
+        )
+        .play(0, &workspace)
+        .expect("play");
+        assert_eq!(
+            std::fs::read_to_string(workspace.join("out/wx-1.txt")).expect("edited"),
+            "wx-1"
+        );
+
+        let err = scenario(r#"{"steps": [{"edit": {"path": "../escape", "content": ""}}]}"#)
+            .play(0, &workspace)
+            .expect_err("escaping edit");
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        assert!(!dir.path().join("escape").exists());
+    }
+
+    #[test]
+    fn attempts_are_claimed_in_order() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("scenario.json");
+        assert_eq!(claim_attempt(&path).expect("claim"), 0);
+        assert_eq!(claim_attempt(&path).expect("claim"), 1);
+    }
+
+    #[tokio::test]
+    async fn spawned_session_streams_the_scenario() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let events = scenario(r#"{"steps": [{"say": "hello"}]}"#)
+            .play(0, dir.path())
+            .expect("play");
+        let lines = feed_lines(&events).expect("serialize");
+        let session = spawn_feed(lines, Box::new(MockParser)).expect("spawn");
+        let mut session = session.prompt("go").await.expect("prompt");
+        let mut text = String::new();
+        loop {
+            match session.next_event().await.expect("event") {
+                Some(AgentEvent::MessageDelta { text: delta }) => text.push_str(&delta),
+                Some(AgentEvent::SessionComplete { exit_code, .. }) => {
+                    assert_eq!(exit_code, 0);
+                    break;
+                }
+                Some(_) => {}
+                None => panic!("feed ended without SessionComplete"),
+            }
+        }
+        assert_eq!(text, "hello\nLOOM_COMPLETE\n");
+    }
+}
@@ -1,4 +1,5 @@
-//! Pi-mono RPC backend: spawn + startup probe + optional `set_model`.
+//! Pi-mono RPC backend: spawn + startup probe + optional `set_model` and
+//! `set_thinking_level`.
 //!
 //! [`PiBackend::spawn`] serializes the [`SpawnConfig`] to a JSON file,
 //! execs `wrapix run-bead --spawn-config <file> --stdio` (the wrapper that
@@ -11,6 +12,11 @@
 //!    version mismatch is caught before any workflow begins.
 //! 2. `set_model` (optional) — sent only when [`SpawnConfig::model`] is
 //!    populated by per-phase config. Failure is hard-fail.
+//! 3. `set_thinking_level` (optional) — sent only when
+//!    [`SpawnConfig::thinking`] is set. Best-effort: it is not a required
+//!    command, so a pi whose probe does not list it (an older pi) is not
+//!    sent it, and a rejection (a provider without reasoning levels) is
+//!    logged; either way the session continues.
 //!
 //! Process IO during the handshake is direct (no [`AgentSession`] yet) —
 //! the typestate session only starts taking events once `prompt` is
@@ -26,11 +32,12 @@ use std::sync::atomic::{AtomicU64, Ordering};
 
 use loom_core::agent::{
     AgentBackend, AgentSession, Idle, ModelSelection, NdjsonReader, ProtocolError, SpawnConfig,
+    ThinkingLevel,
 };
 use serde::Serialize;
 use tokio::io::{AsyncWriteExt, BufWriter};
 use tokio::process::{ChildStdin, Command};
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
 
 use super::messages::{PiEnvelope, PiResponse};
 use super::parser::PiParser;
@@ -47,6 +54,9 @@ const PROBE_REQUEST_ID: &str = "loom-pi-probe";
 /// Request id used for the optional post-probe `set_model` request.
 const SET_MODEL_REQUEST_ID: &str = "loom-pi-set-model";
 
+/// Request id used for the optional best-effort `set_thinking_level` request.
+const SET_THINKING_REQUEST_ID: &str = "loom-pi-set-thinking";
+
 /// Pi commands Loom depends on. A missing entry in the `get_commands`
 /// response is a hard fail (`ProtocolError::Unsupported`).
 const REQUIRED_COMMANDS: &[&str] = &["prompt", "steer", "abort", "set_model"];
@@ -83,7 +93,7 @@ impl AgentBackend for PiBackend {
             .arg(&spawn_config_path)
             .arg("--stdio");
 
-        spawn_with_handshake(cmd, config.model.as_ref()).await
+        spawn_with_handshake(cmd, config.model.as_ref(), config.thinking).await
     }
 }
 
@@ -109,8 +119,19 @@ struct SetModelCommand<'a> {
     model_id: &'a str,
 }
 
+/// `set_thinking_level` request body. Sent only when
+/// [`SpawnConfig::thinking`] is populated.
+#[derive(Serialize)]
+struct SetThinkingLevelCommand<'a> {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    id: &'a str,
+    level: &'a str,
+}
+
 /// Spawn the launcher [`Command`], drive the startup handshake (probe +
-/// optional `set_model`), and return a session in the [`Idle`] state.
+/// optional `set_model` / `set_thinking_level`), and return a session in
+/// the [`Idle`] state.
 ///
 /// Module-public so unit tests can substitute a mock pi binary in place
 /// of the real `wrapix run-bead` exec without going through the
@@ -118,6 +139,7 @@ struct SetModelCommand<'a> {
 pub(crate) async fn spawn_with_handshake(
     mut cmd: Command,
     model: Option<&ModelSelection>,
+    thinking: Option<ThinkingLevel>,
 ) -> Result<AgentSession<Idle>, ProtocolError> {
     cmd.stdin(Stdio::piped());
     cmd.stdout(Stdio::piped());
@@ -137,24 +159,37 @@ pub(crate) async fn spawn_with_handshake(
     let mut writer = BufWriter::new(stdin);
     let mut reader = NdjsonReader::new(stdout);
 
-    run_probe(&mut writer, &mut reader).await?;
+    let commands = run_probe(&mut writer, &mut reader).await?;
 
     if let Some(model) = model {
         run_set_model(&mut writer, &mut reader, model).await?;
     }
 
+    if let Some(level) = thinking {
+        // A pi that does not know the command may never answer it, and
+        // `await_response` has no timeout — ask only when it is offered.
+        if commands.iter().any(|c| c == "set_thinking_level") {
+            run_set_thinking_level(&mut writer, &mut reader, level).await?;
+        } else {
+            warn!(
+                level = level.as_str(),
+                "pi does not offer set_thinking_level — continuing at its default",
+            );
+        }
+    }
+
     let parser = PiParser::new();
     Ok(AgentSession::new(child, writer, reader, Box::new(parser)))
 }
 
-/// Send `get_commands` on stdin and wait for the matching response. Events
-/// emitted before the response are observed but ignored — pi can interleave
-/// telemetry around request handling, so the loop drains lines until the
-/// correlated response arrives.
+/// Send `get_commands` on stdin and wait for the matching response,
+/// returning the commands pi offers. Events emitted before the response are
+/// observed but ignored — pi can interleave telemetry around request
+/// handling, so the loop drains lines until the correlated response arrives.
 async fn run_probe(
     writer: &mut BufWriter<ChildStdin>,
     reader: &mut NdjsonReader,
-) -> Result<(), ProtocolError> {
+) -> Result<Vec<String>, ProtocolError> {
     let cmd = GetCommandsCommand {
         kind: "get_commands",
         id: PROBE_REQUEST_ID,
@@ -185,7 +220,7 @@ async fn run_probe(
     }
 
     debug!(commands = ?commands, "pi get_commands probe succeeded");
-    Ok(())
+    Ok(commands)
 }
 
 /// Send `set_model` on stdin and wait for the matching response. A failure
@@ -223,6 +258,35 @@ async fn run_set_model(
     Ok(())
 }
 
+/// Send `set_thinking_level` on stdin and wait for the matching response.
+/// Unlike `set_model` a failure response is not an error: the session runs
+/// at pi's default reasoning effort. Only a broken pipe or EOF — pi is gone
+/// — fails the spawn.
+async fn run_set_thinking_level(
+    writer: &mut BufWriter<ChildStdin>,
+    reader: &mut NdjsonReader,
+    level: ThinkingLevel,
+) -> Result<(), ProtocolError> {
+    let cmd = SetThinkingLevelCommand {
+        kind: "set_thinking_level",
+        id: SET_THINKING_REQUEST_ID,
+        level: level.as_str(),
+    };
+    write_command(writer, &cmd).await?;
+
+    let resp = await_response(reader, SET_THINKING_REQUEST_ID).await?;
+    if resp.success {
+        info!(level = level.as_str(), "pi set_thinking_level succeeded");
+    } else {
+        warn!(
+            error = ?resp.error,
+            level = level.as_str(),
+            "pi rejected set_thinking_level — continuing at its default",
+        );
+    }
+    Ok(())
+}
+
 /// Encode `payload` as NDJSON and flush it to pi's stdin.
 async fn write_command<T: Serialize>(
     writer: &mut BufWriter<ChildStdin>,
@@ -294,7 +358,7 @@ fn extract_commands(resp: &PiResponse) -> Result<Vec<String>, ProtocolError> {
 /// under the system temp dir. The path is handed to `wrapix run-bead
 /// --spawn-config`; the wrapper reads it back and ignores any unknown
 /// fields (`model` is consumed by the host-side backend, not the wrapper).
-fn write_spawn_config(config: &SpawnConfig) -> Result<PathBuf, ProtocolError> {
+pub(crate) fn write_spawn_config(config: &SpawnConfig) -> Result<PathBuf, ProtocolError> {
     let dir = std::env::temp_dir();
     let pid = std::process::id();
     let counter = SPAWN_CONFIG_COUNTER.fetch_add(1, Ordering::Relaxed);
@@ -317,7 +381,7 @@ fn write_spawn_config_to(path: &Path, config: &SpawnConfig) -> Result<(), Protoc
 )]
 mod tests {
     use super::*;
-    use loom_core::agent::{AgentEvent, RePinContent};
+    use loom_core::agent::{AgentEvent, RePinContent, ToolPolicy};
     use std::path::PathBuf;
 
     fn mock_pi_path() -> PathBuf {
@@ -347,6 +411,9 @@ mod tests {
             agent_args: vec![],
             repin: sample_repin(),
             model,
+            thinking: None,
+            command_protocol: None,
+            tool_policy: ToolPolicy::default(),
         }
     }
 
@@ -373,7 +440,7 @@ mod tests {
 
     #[tokio::test]
     async fn startup_probe_succeeds_when_required_commands_present() {
-        let session = spawn_with_handshake(mock_command("happy-path"), None)
+        let session = spawn_with_handshake(mock_command("happy-path"), None, None)
             .await
             .expect("probe should succeed");
         // Drive a prompt to confirm the session is wired and the mock keeps
@@ -390,7 +457,8 @@ mod tests {
 
     #[tokio::test]
     async fn startup_probe_fails_fast_when_required_command_missing() {
-        let result = spawn_with_handshake(mock_command("probe-missing-set-model"), None).await;
+        let result =
+            spawn_with_handshake(mock_command("probe-missing-set-model"), None, None).await;
         match result {
             Err(ProtocolError::Unsupported) => {}
             Err(other) => panic!("expected Unsupported, got {other:?}"),
@@ -402,7 +470,7 @@ mod tests {
 
     #[tokio::test]
     async fn driver_sends_prompt_as_ndjson_line() {
-        let session = spawn_with_handshake(mock_command("echo-prompt"), None)
+        let session = spawn_with_handshake(mock_command("echo-prompt"), None, None)
             .await
             .expect("spawn");
         let mut session = session.prompt("HELLO_PROMPT").await.expect("prompt ok");
@@ -427,7 +495,7 @@ mod tests {
 
     #[tokio::test]
     async fn driver_steers_mid_session_and_mock_observes_payload() {
-        let session = spawn_with_handshake(mock_command("steering"), None)
+        let session = spawn_with_handshake(mock_command("steering"), None, None)
             .await
             .expect("spawn");
         let mut session = session.prompt("first prompt").await.expect("prompt ok");
@@ -467,7 +535,7 @@ mod tests {
 
     #[tokio::test]
     async fn driver_repins_on_compaction_start_via_steer() {
-        let session = spawn_with_handshake(mock_command("compaction"), None)
+        let session = spawn_with_handshake(mock_command("compaction"), None, None)
             .await
             .expect("spawn");
         let repin_text = "REPIN_PAYLOAD_TEXT";
@@ -506,7 +574,7 @@ mod tests {
             provider: "deepseek".into(),
             model_id: "deepseek-v3".into(),
         };
-        let session = spawn_with_handshake(mock_command("set-model"), Some(&model))
+        let session = spawn_with_handshake(mock_command("set-model"), Some(&model), None)
             .await
             .expect("spawn with model");
 
@@ -533,4 +601,38 @@ mod tests {
         assert!(saw_provider, "mock did not observe provider");
         assert!(saw_model_id, "mock did not observe model_id");
     }
+
+    // -- test_pi_set_thinking_level_best_effort ----------------------------
+
+    async fn thinking_echo(mode: &str) -> String {
+        let session = spawn_with_handshake(mock_command(mode), None, Some(ThinkingLevel::High))
+            .await
+            .expect("spawn with thinking level");
+        let mut session = session.prompt("hi").await.expect("prompt ok");
+        let mut text = String::new();
+        loop {
+            match session.next_event().await.expect("event ok") {
+                Some(AgentEvent::MessageDelta { text: delta }) => text.push_str(&delta),
+                Some(AgentEvent::SessionComplete { .. }) => break,
+                Some(_) => continue,
+                None => panic!("unexpected EOF"),
+            }
+        }
+        text
+    }
+
+    #[tokio::test]
+    async fn set_thinking_level_reaches_mock_pi() {
+        assert_eq!(thinking_echo("thinking-ok").await, "thinking:high");
+    }
+
+    #[tokio::test]
+    async fn rejected_set_thinking_level_does_not_fail_the_spawn() {
+        assert_eq!(thinking_echo("thinking-rejected").await, "thinking:default");
+    }
+
+    #[tokio::test]
+    async fn unlisted_set_thinking_level_is_skipped() {
+        assert_eq!(thinking_echo("thinking-unlisted").await, "thinking:skipped");
+    }
 }
@@ -0,0 +1,286 @@
+//! Replay backend: drive a recorded wire capture back through
+//! [`AgentSession`].
+//!
+//! A [`WireRecording`] holds the stdout lines an agent produced during a
+//! real session (read from a `.wire.jsonl` capture, or from bare stdout
+//! lines). [`replay_session`] hands them to a stand-in child process that
+//! prints them and then drains stdin, so the session's reader, the
+//! backend's real [`LineParse`] implementation, and any stdin replies
+//! (claude `control_response`, pi extension UI cancels) all run exactly as
+//! they did in production — without a container or a model.
+//!
+//! [`ReplayBackend`] is the [`AgentBackend`] face of the same machinery:
+//! it replays the capture named by `LOOM_REPLAY_LOG`, so a whole `loom
+//! run` can be driven off a recording via `run_agent::<ReplayBackend>`.
+
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+
+use loom_core::agent::{
+    AgentBackend, AgentKind, AgentSession, Idle, LineParse, NdjsonReader, ParsedLine,
+    ProtocolError, SpawnConfig, ToolGate,
+};
+use loom_core::logging::{WireDirection, WireRecord};
+use serde_json::Value;
+use tempfile::NamedTempFile;
+use tokio::io::BufWriter;
+use tokio::process::Command;
+use tracing::info;
+
+use crate::claude::parser::ClaudeParser;
+use crate::pi::parser::PiParser;
+
+/// Env var naming the wire capture [`ReplayBackend::spawn`] replays.
+pub const ENV_REPLAY_LOG: &str = "LOOM_REPLAY_LOG";
+
+/// Stand-in agent: print the recorded stdout, close stdout so the session
+/// sees EOF, then swallow whatever loom writes on stdin until it hangs up.
+const REPLAY_SCRIPT: &str = r#"cat -- "$1"; exec 1>&-; exec cat >/dev/null"#;
+
+/// The agent-side half of a recorded session.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WireRecording {
+    stdout: Vec<String>,
+}
+
+impl WireRecording {
+    /// Read a capture from disk. See [`WireRecord::parse_log`] for the
+    /// accepted shapes.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        Ok(Self::parse(&std::fs::read_to_string(path)?))
+    }
+
+    /// Keep the stdout half of `text`; lines loom wrote to stdin are
+    /// regenerated by the parser during replay.
+    pub fn parse(text: &str) -> Self {
+        Self {
+            stdout: WireRecord::parse_log(text)
+                .into_iter()
+                .filter(|r| r.dir == WireDirection::Out)
+                .map(|r| r.line)
+                .collect(),
+        }
+    }
+
+    pub fn stdout_lines(&self) -> &[String] {
+        &self.stdout
+    }
+
+    /// Guess which backend produced the capture from the first line whose
+    /// `type` belongs to only one protocol. `None` when nothing is
+    /// conclusive (empty capture, or only shared/unknown types).
+    pub fn detect_kind(&self) -> Option<AgentKind> {
+        self.stdout.iter().find_map(|line| {
+            let value: Value = serde_json::from_str(line).ok()?;
+            match value.get("type")?.as_str()? {
+                "system" | "assistant" | "user" | "result" | "control_request" | "stream_event" => {
+                    Some(AgentKind::Claude)
+                }
+                "agent_start"
+                | "agent_end"
+                | "turn_start"
+                | "message_start"
+                | "message_update"
+                | "message_end"
+                | "tool_execution_start"
+                | "tool_execution_update"
+                | "tool_execution_end"
+                | "response"
+                | "extension_ui_request"
+                | "compaction_start"
+                | "compaction_end" => Some(AgentKind::Pi),
+                _ => None,
+            }
+        })
+    }
+}
+
+/// Zero-sized replay backend. Ignores everything in the [`SpawnConfig`]
+/// except that a session is wanted; the capture comes from
+/// [`ENV_REPLAY_LOG`] and its protocol from [`WireRecording::detect_kind`].
+pub struct ReplayBackend;
+
+impl AgentBackend for ReplayBackend {
+    async fn spawn(_config: &SpawnConfig) -> Result<AgentSession<Idle>, ProtocolError> {
+        let path = std::env::var_os(ENV_REPLAY_LOG)
+            .map(PathBuf::from)
+            .ok_or_else(|| {
+                ProtocolError::Io(io::Error::other(format!("{ENV_REPLAY_LOG} is not set")))
+            })?;
+        let recording = WireRecording::load(&path)?;
+        let kind = recording.detect_kind().ok_or(ProtocolError::Unsupported)?;
+        info!(path = %path.display(), ?kind, "replay backend spawn");
+        replay_session(&recording, kind).await
+    }
+}
+
+/// Spawn a session that replays `recording` through `kind`'s parser.
+///
+/// Claude replays with an allow-all gate, so every recorded
+/// `control_request` is approved — the reply is written to the stand-in's
+/// stdin and discarded. Pi skips the startup probe: its response, if
+/// captured, is simply one more stdout line the parser ignores.
+pub async fn replay_session(
+    recording: &WireRecording,
+    kind: AgentKind,
+) -> Result<AgentSession<Idle>, ProtocolError> {
+    let parser: Box<dyn LineParse + Send> = match kind {
+        AgentKind::Claude => Box::new(ClaudeParser::new(ToolGate::default())),
+        AgentKind::Pi => Box::new(PiParser::new()),
+        // A command agent's capture is in the protocol it spoke, which
+        // `detect_kind` already reports as claude or pi.
+        AgentKind::Command => return Err(ProtocolError::Unsupported),
+        #[cfg(feature = "mock")]
+        AgentKind::Mock => return Err(ProtocolError::Unsupported),
+    };
+    spawn_feed(&recording.stdout, parser)
+}
+
+/// Start the stand-in agent printing `lines` as its stdout and wrap it in a
+/// session read by `parser`. The lines go through a temp file the session
+/// owns, so the file is removed once the session (and with it the
+/// stand-in) is gone. Shared with the mock backend, whose lines are a
+/// scenario's events rather than a capture.
+pub(crate) fn spawn_feed<S: AsRef<str>>(
+    lines: impl IntoIterator<Item = S>,
+    parser: Box<dyn LineParse + Send>,
+) -> Result<AgentSession<Idle>, ProtocolError> {
+    let feed = write_feed(lines)?;
+    let mut cmd = Command::new("sh");
+    cmd.arg("-c")
+        .arg(REPLAY_SCRIPT)
+        .arg("loom-replay")
+        .arg(feed.path())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .kill_on_drop(true);
+
+    let mut child = cmd.spawn().map_err(ProtocolError::Io)?;
+    let stdin = child
+        .stdin
+        .take()
+        .ok_or_else(|| ProtocolError::Io(io::Error::other("replay child stdin not piped")))?;
+    let stdout = child
+        .stdout
+        .take()
+        .ok_or_else(|| ProtocolError::Io(io::Error::other("replay child stdout not piped")))?;
+
+    Ok(AgentSession::new(
+        child,
+        BufWriter::new(stdin),
+        NdjsonReader::new(stdout),
+        Box::new(FeedParser {
+            parser,
+            _feed: feed,
+        }),
+    ))
+}
+
+/// `parser`, plus the feed file it is reading, kept alive for the life of
+/// the session.
+struct FeedParser {
+    parser: Box<dyn LineParse + Send>,
+    _feed: NamedTempFile,
+}
+
+impl LineParse for FeedParser {
+    fn parse_line(&self, line: &str) -> Result<ParsedLine, ProtocolError> {
+        self.parser.parse_line(line)
+    }
+
+    fn encode_prompt(&self, msg: &str) -> Result<String, ProtocolError> {
+        self.parser.encode_prompt(msg)
+    }
+
+    fn encode_steer(&self, msg: &str) -> Result<String, ProtocolError> {
+        self.parser.encode_steer(msg)
+    }
+
+    fn encode_abort(&self) -> Result<Option<String>, ProtocolError> {
+        self.parser.encode_abort()
+    }
+
+    fn one_shot(&self) -> bool {
+        self.parser.one_shot()
+    }
+}
+
+/// Write `lines` to a fresh temp file for the stand-in process to print.
+fn write_feed<S: AsRef<str>>(
+    lines: impl IntoIterator<Item = S>,
+) -> Result<NamedTempFile, ProtocolError> {
+    let mut feed = tempfile::Builder::new()
+        .prefix("loom-feed-")
+        .suffix(".ndjson")
+        .tempfile()?;
+    for line in lines {
+        writeln!(feed, "{}", line.as_ref())?;
+    }
+    feed.flush()?;
+    Ok(feed)
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::expect_used,
+    clippy::panic,
+    reason = "tests use panicking helpers"
+)]
+mod tests {
+    use super::*;
+    use loom_core::agent::AgentEvent;
+
+    const CLAUDE_CAPTURE: &str = concat!(
+        r#"{"dir":"out","ts":"20260503T120000Z","line":"{\"type\":\"system\",\"subtype\":\"init\",\"session_id\":\"s\"}"}"#,
+        "\n",
+        r#"{"dir":"in","ts":"20260503T120000Z","line":"{\"type\":\"user\"}"}"#,
+        "\n",
+        r#"{"type":"control_request","id":"req_01","tool":"Read","input":{"path":"/tmp/x"}}"#,
+        "\n",
+        r#"{"type":"assistant","message":{"role":"assistant","content":[{"type":"tool_use","id":"tu-1","name":"Read","input":{"file_path":"/tmp/x"}}]}}"#,
+        "\n",
+        r#"{"type":"result","subtype":"success","total_cost_usd":0.42}"#,
+        "\n",
+    );
+
+    #[test]
+    fn detect_kind_reads_protocol_specific_types() {
+        let claude = WireRecording::parse(CLAUDE_CAPTURE);
+        assert_eq!(claude.stdout_lines().len(), 4);
+        assert_eq!(claude.detect_kind(), Some(AgentKind::Claude));
+
+        let pi = WireRecording::parse(r#"{"type":"agent_start"}"#);
+        assert_eq!(pi.detect_kind(), Some(AgentKind::Pi));
+
+        assert_eq!(WireRecording::parse("not json").detect_kind(), None);
+    }
+
+    #[tokio::test]
+    async fn claude_capture_replays_to_the_recorded_outcome() {
+        let recording = WireRecording::parse(CLAUDE_CAPTURE);
+        let session = replay_session(&recording, AgentKind::Claude)
+            .await
+            .expect("spawn");
+        let mut session = session.prompt("replay").await.expect("prompt");
+        let mut events = Vec::new();
+        while let Some(event) = session.next_event().await.expect("event") {
+            events.push(event);
+        }
+        assert!(
+            matches!(events.first(), Some(AgentEvent::ToolCall { tool, .. }) if tool == "Read"),
+            "{events:?}"
+        );
+        match events.last() {
+            Some(AgentEvent::SessionComplete {
+                exit_code,
+                cost_usd,
+            }) => {
+                assert_eq!(*exit_code, 0);
+                assert_eq!(*cost_usd, Some(0.42));
+            }
+            other => panic!("expected SessionComplete, got {other:?}"),
+        }
+    }
+}
@@ -14,7 +14,7 @@
 use loom_agent::claude::messages::ClaudeMessage;
 use loom_agent::claude::parser::ClaudeParser;
 use loom_agent::pi::parser::PiParser;
-use loom_core::agent::{LineParse, MAX_LINE_BYTES, ProtocolError};
+use loom_core::agent::{LineParse, MAX_LINE_BYTES, ProtocolError, ToolGate};
 use proptest::prelude::*;
 
 fn pi_parser() -> PiParser {
@@ -22,7 +22,7 @@ fn pi_parser() -> PiParser {
 }
 
 fn claude_parser() -> ClaudeParser {
-    ClaudeParser::new(Vec::new())
+    ClaudeParser::new(ToolGate::default())
 }
 
 /// JSONL framing constant is the 10 MB cap documented in the spec.
@@ -9,7 +9,7 @@
 #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 
 use loom_agent::{ClaudeBackend, PiBackend};
-use loom_core::agent::{AgentBackend, ProtocolError, SessionOutcome, SpawnConfig};
+use loom_core::agent::{AgentBackend, ProtocolError, SessionOutcome, SpawnConfig, ToolPolicy};
 
 async fn run_agent<B: AgentBackend>(config: &SpawnConfig) -> Result<SessionOutcome, ProtocolError> {
     let _session = B::spawn(config).await?;
@@ -52,5 +52,8 @@ fn sample_config() -> SpawnConfig {
             partial_bodies: Vec::new(),
         },
         model: None,
+        thinking: None,
+        command_protocol: None,
+        tool_policy: ToolPolicy::default(),
     }
 }
@@ -3,6 +3,7 @@ use std::path::PathBuf;
 use serde::{Deserialize, Serialize};
 
 use super::error::ProtocolError;
+use super::policy::ToolPolicy;
 use super::repin::RePinContent;
 use super::session::{AgentSession, Idle};
 
@@ -29,6 +30,23 @@ pub struct SpawnConfig {
     /// remains identical to existing fixtures.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub model: Option<ModelSelection>,
+    /// Optional reasoning effort for the pi backend, sent as a best-effort
+    /// `set_thinking_level` RPC after `set_model`; a rejection is logged
+    /// and the session continues. Claude receives its thinking budget via
+    /// `env` instead. Skipped during serialization when `None`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thinking: Option<ThinkingLevel>,
+    /// Wire protocol of a `[backends.<name>]` command agent, telling the
+    /// host-side command backend which parser reads the session. The
+    /// wrapper runs the agent's argv from `agent_args` and ignores this
+    /// field. Skipped during serialization when `None`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command_protocol: Option<CommandProtocol>,
+    /// Rules the claude backend applies to each `control_request`, from
+    /// `[security]` for the session's phase. Host-side only; skipped during
+    /// serialization when empty.
+    #[serde(default, skip_serializing_if = "ToolPolicy::is_empty")]
+    pub tool_policy: ToolPolicy,
 }
 
 /// Per-session model override: pi RPC's `set_model { provider, modelId }`.
@@ -36,12 +54,67 @@ pub struct SpawnConfig {
 /// Lives on [`SpawnConfig`] rather than a backend-specific config object so
 /// the [`AgentBackend::spawn`] trait surface stays a single-argument call.
 /// The wrapper ignores this field; it is consumed only by host-side backends.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ModelSelection {
     pub provider: String,
     pub model_id: String,
 }
 
+/// Per-phase reasoning effort (`[agent.<phase>] thinking`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThinkingLevel {
+    Low,
+    Medium,
+    High,
+}
+
+impl ThinkingLevel {
+    /// The level as pi's `set_thinking_level { level }` spells it.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ThinkingLevel::Low => "low",
+            ThinkingLevel::Medium => "medium",
+            ThinkingLevel::High => "high",
+        }
+    }
+
+    /// Claude's `MAX_THINKING_TOKENS` budget for the level — the budgets
+    /// claude itself uses for "think", "think hard" and "ultrathink".
+    pub fn claude_budget_tokens(&self) -> u32 {
+        match self {
+            ThinkingLevel::Low => 4_000,
+            ThinkingLevel::Medium => 10_000,
+            ThinkingLevel::High => 31_999,
+        }
+    }
+}
+
+/// Stdin/stdout protocol spoken by a `[backends.<name>]` command agent.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CommandProtocol {
+    /// Claude Code's `--input-format/--output-format stream-json`.
+    ClaudeStreamJson,
+    /// Pi-mono's NDJSON RPC mode, without pi's startup handshake.
+    PiRpc,
+    /// The prompt on stdin, closed after it; every stdout line is message
+    /// text, and the session ends when the process exits.
+    #[default]
+    PlainText,
+}
+
+impl CommandProtocol {
+    /// The protocol as `[backends.<name>] protocol` spells it.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CommandProtocol::ClaudeStreamJson => "claude-stream-json",
+            CommandProtocol::PiRpc => "pi-rpc",
+            CommandProtocol::PlainText => "plain-text",
+        }
+    }
+}
+
 /// Outcome of a completed agent session — what the workflow engine receives
 /// after the session reaches `SessionComplete`.
 #[derive(Debug, Clone)]
@@ -86,6 +159,9 @@ mod tests {
                 partial_bodies: vec![],
             },
             model,
+            thinking: None,
+            command_protocol: None,
+            tool_policy: ToolPolicy::default(),
         }
     }
 
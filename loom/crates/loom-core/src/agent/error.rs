@@ -3,6 +3,8 @@ use std::io;
 use displaydoc::Display;
 use thiserror::Error;
 
+use super::policy::ToolPolicyError;
+
 /// Errors raised at the NDJSON / agent-protocol boundary.
 ///
 /// The variants cover the layers where loom-core is the only code that knows
@@ -30,4 +32,7 @@ pub enum ProtocolError {
 
     /// operation not supported by this backend
     Unsupported,
+
+    /// {0}
+    ToolPolicy(#[from] ToolPolicyError),
 }
@@ -1,4 +1,4 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::identifier::ToolCallId;
 
@@ -8,8 +8,10 @@ use crate::identifier::ToolCallId;
 /// backend produced it.
 ///
 /// `Serialize` is derived so the on-disk NDJSON log file is the same event
-/// stream the terminal renderer consumes (see `logging::LogSink`).
-#[derive(Debug, Serialize)]
+/// stream the terminal renderer consumes (see `logging::LogSink`);
+/// `Deserialize` lets readers of that log (`loom status --watch`) feed the
+/// recorded events back through the same renderer.
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(tag = "kind", rename_all = "snake_case")]
 pub enum AgentEvent {
     /// Streaming text fragment from the agent.
@@ -52,7 +54,7 @@ pub enum AgentEvent {
 }
 
 /// Why the agent compacted its context.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum CompactionReason {
     /// Approaching or exceeded the model context limit.
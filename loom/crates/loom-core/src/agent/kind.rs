@@ -4,11 +4,18 @@ use serde::{Deserialize, Serialize};
 ///
 /// Per spec NF-7 this is an enum, not a newtype: the variants are a closed
 /// set known at compile time and dispatch is via `match`, not parsing.
+/// `Command` covers every `[backends.<name>]` agent — which one, and its
+/// argv and protocol, travel beside the kind in
+/// [`AgentSelection::command`](crate::config::AgentSelection::command).
+/// `Mock`, the scripted test backend, exists only with the `mock` feature.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum AgentKind {
     Pi,
     Claude,
+    Command,
+    #[cfg(feature = "mock")]
+    Mock,
 }
 
 #[cfg(test)]
@@ -20,6 +27,7 @@ mod tests {
     fn serde_uses_lowercase_variant_names() -> Result<()> {
         assert_eq!(serde_json::to_string(&AgentKind::Pi)?, "\"pi\"");
         assert_eq!(serde_json::to_string(&AgentKind::Claude)?, "\"claude\"");
+        assert_eq!(serde_json::to_string(&AgentKind::Command)?, "\"command\"");
         let back: AgentKind = serde_json::from_str("\"claude\"")?;
         assert_eq!(back, AgentKind::Claude);
         Ok(())
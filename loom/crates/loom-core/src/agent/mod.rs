@@ -15,6 +15,8 @@
 //!   compaction.
 //! - [`SpawnConfig`] / [`SessionOutcome`] — the contract between loom and
 //!   `wrapix run-bead`.
+//! - [`ToolPolicy`] / [`ToolGate`] — host-side allow/deny rules for the
+//!   tool calls a backend asks permission for.
 //!
 //! Protocol-parsing of backend-specific message types (`PiMessage`,
 //! `ClaudeMessage`) lives in `loom-agent`, not here.
@@ -25,14 +27,18 @@ mod event;
 mod kind;
 mod ndjson;
 mod parse;
+mod policy;
 mod repin;
 mod session;
 
-pub use backend::{AgentBackend, ModelSelection, SessionOutcome, SpawnConfig};
+pub use backend::{
+    AgentBackend, CommandProtocol, ModelSelection, SessionOutcome, SpawnConfig, ThinkingLevel,
+};
 pub use error::ProtocolError;
 pub use event::{AgentEvent, CompactionReason};
 pub use kind::AgentKind;
 pub use ndjson::{MAX_LINE_BYTES, NdjsonReader};
 pub use parse::{LineParse, ParsedLine};
+pub use policy::{ToolAction, ToolDecision, ToolGate, ToolPolicy, ToolPolicyError, ToolRule};
 pub use repin::RePinContent;
 pub use session::{Active, AgentSession, Idle};
@@ -45,4 +45,11 @@ pub trait LineParse: Send {
     /// Encode an abort command, or `None` if the backend has no abort wire
     /// command (claude is killed via signals instead).
     fn encode_abort(&self) -> Result<Option<String>, ProtocolError>;
+
+    /// `true` for a protocol with no terminal message of its own (the
+    /// `plain-text` command protocol): the session closes stdin after the
+    /// prompt and reports the process's exit at EOF as `SessionComplete`.
+    fn one_shot(&self) -> bool {
+        false
+    }
 }
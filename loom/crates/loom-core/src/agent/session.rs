@@ -1,13 +1,16 @@
 use std::collections::VecDeque;
+use std::io;
 use std::marker::PhantomData;
 
 use tokio::io::{AsyncWriteExt, BufWriter};
 use tokio::process::{Child, ChildStdin};
+use tracing::warn;
 
 use super::error::ProtocolError;
 use super::event::AgentEvent;
 use super::ndjson::NdjsonReader;
 use super::parse::LineParse;
+use crate::logging::{WireDirection, WireSink};
 
 /// Typestate marker — session has been spawned but no prompt has been sent.
 pub struct Idle;
@@ -21,10 +24,12 @@ pub struct Active;
 /// [`Self::new`] and hand it back through `AgentBackend::spawn`.
 pub struct AgentSession<S> {
     child: Child,
-    stdin: BufWriter<ChildStdin>,
+    /// `None` once closed after a one-shot prompt ([`LineParse::one_shot`]).
+    stdin: Option<BufWriter<ChildStdin>>,
     reader: NdjsonReader,
     parser: Box<dyn LineParse + Send>,
     pending: VecDeque<AgentEvent>,
+    wire: Option<WireSink>,
     _state: PhantomData<S>,
 }
 
@@ -40,27 +45,34 @@ impl AgentSession<Idle> {
     ) -> Self {
         Self {
             child,
-            stdin,
+            stdin: Some(stdin),
             reader,
             parser,
             pending: VecDeque::new(),
+            wire: None,
             _state: PhantomData,
         }
     }
 
     /// Send the initial prompt and transition the session to [`Active`].
     /// The parser owns wire framing — this method only writes the encoded
-    /// bytes and flushes.
+    /// bytes and flushes, then closes stdin for a one-shot protocol so the
+    /// agent sees the end of its prompt.
     pub async fn prompt(mut self, msg: &str) -> Result<AgentSession<Active>, ProtocolError> {
         let line = self.parser.encode_prompt(msg)?;
-        self.stdin.write_all(line.as_bytes()).await?;
-        self.stdin.flush().await?;
+        self.capture(WireDirection::In, &line);
+        self.writer()?.write_all(line.as_bytes()).await?;
+        self.writer()?.flush().await?;
+        if self.parser.one_shot() {
+            self.stdin = None;
+        }
         Ok(AgentSession {
             child: self.child,
             stdin: self.stdin,
             reader: self.reader,
             parser: self.parser,
             pending: self.pending,
+            wire: self.wire,
             _state: PhantomData,
         })
     }
@@ -73,7 +85,9 @@ impl AgentSession<Active> {
     /// reads NDJSON lines until one yields at least one event, writing any
     /// `ParsedLine::response` payload back to stdin in between (the canonical
     /// case is claude's `control_request` auto-approve). Returns `Ok(None)`
-    /// on clean EOF.
+    /// on clean EOF — except for a one-shot protocol, whose EOF is the end of
+    /// the session: the process is reaped and its exit status comes back as
+    /// `SessionComplete`.
     pub async fn next_event(&mut self) -> Result<Option<AgentEvent>, ProtocolError> {
         if let Some(evt) = self.pending.pop_front() {
             return Ok(Some(evt));
@@ -81,15 +95,25 @@ impl AgentSession<Active> {
         loop {
             let line_owned = match self.reader.next_line().await? {
                 Some(line) => line.to_owned(),
+                None if self.parser.one_shot() => {
+                    let status = self.child.wait().await?;
+                    return Ok(Some(AgentEvent::SessionComplete {
+                        // `None` when killed by a signal — a failure.
+                        exit_code: status.code().unwrap_or(-1),
+                        cost_usd: None,
+                    }));
+                }
                 None => return Ok(None),
             };
+            self.capture(WireDirection::Out, &line_owned);
             let parsed = self.parser.parse_line(&line_owned)?;
             if let Some(response) = parsed.response {
-                self.stdin.write_all(response.as_bytes()).await?;
+                self.capture(WireDirection::In, &response);
+                self.writer()?.write_all(response.as_bytes()).await?;
                 if !response.ends_with('\n') {
-                    self.stdin.write_all(b"\n").await?;
+                    self.writer()?.write_all(b"\n").await?;
                 }
-                self.stdin.flush().await?;
+                self.writer()?.flush().await?;
             }
             let mut iter = parsed.events.into_iter();
             if let Some(first) = iter.next() {
@@ -103,8 +127,9 @@ impl AgentSession<Active> {
     /// payload (pi: NDJSON `steer` command, claude: stream-json user message).
     pub async fn steer(&mut self, msg: &str) -> Result<(), ProtocolError> {
         let line = self.parser.encode_steer(msg)?;
-        self.stdin.write_all(line.as_bytes()).await?;
-        self.stdin.flush().await?;
+        self.capture(WireDirection::In, &line);
+        self.writer()?.write_all(line.as_bytes()).await?;
+        self.writer()?.flush().await?;
         Ok(())
     }
 
@@ -116,8 +141,9 @@ impl AgentSession<Active> {
     /// the claude backend). The pending event queue is drained.
     pub async fn abort(mut self) -> Result<AgentSession<Idle>, ProtocolError> {
         if let Some(line) = self.parser.encode_abort()? {
-            self.stdin.write_all(line.as_bytes()).await?;
-            self.stdin.flush().await?;
+            self.capture(WireDirection::In, &line);
+            self.writer()?.write_all(line.as_bytes()).await?;
+            self.writer()?.flush().await?;
         }
         Ok(AgentSession {
             child: self.child,
@@ -125,12 +151,44 @@ impl AgentSession<Active> {
             reader: self.reader,
             parser: self.parser,
             pending: VecDeque::new(),
+            wire: self.wire,
             _state: PhantomData,
         })
     }
 }
 
 impl<S> AgentSession<S> {
+    /// Record the raw wire traffic from here on into `sink` (see
+    /// [`WireSink`]). Lines exchanged before the call — a backend's startup
+    /// handshake — are not captured.
+    pub fn with_wire_capture(mut self, sink: WireSink) -> Self {
+        self.wire = Some(sink);
+        self
+    }
+
+    /// The stdin writer, or a broken-pipe error once a one-shot prompt has
+    /// closed it.
+    fn writer(&mut self) -> Result<&mut BufWriter<ChildStdin>, ProtocolError> {
+        self.stdin.as_mut().ok_or_else(|| {
+            ProtocolError::Io(io::Error::new(
+                io::ErrorKind::BrokenPipe,
+                "agent stdin closed after a one-shot prompt",
+            ))
+        })
+    }
+
+    /// Best-effort capture: a failing write disables the capture with a
+    /// warning rather than failing the session it is only observing.
+    fn capture(&mut self, dir: WireDirection, line: &str) {
+        let Some(sink) = self.wire.as_mut() else {
+            return;
+        };
+        if let Err(e) = sink.record(dir, line) {
+            warn!(error = %e, path = %sink.path().display(), "raw wire capture disabled");
+            self.wire = None;
+        }
+    }
+
     /// Borrow the underlying child process — backends use this to wire up
     /// shutdown watchdogs without giving up ownership of the session.
     pub fn child_mut(&mut self) -> &mut Child {
@@ -138,10 +196,11 @@ impl<S> AgentSession<S> {
     }
 
     /// Decompose the session into the underlying child process and stdin
-    /// writer. Used by the claude backend's shutdown watchdog after a
-    /// `result` event: it must drop the writer (closing the pipe so claude
-    /// observes EOF) then wait/signal the child.
-    pub fn into_parts(self) -> (Child, BufWriter<ChildStdin>) {
+    /// writer (`None` once a one-shot prompt closed it). Used by the claude
+    /// backend's shutdown watchdog after a `result` event: it must drop the
+    /// writer (closing the pipe so claude observes EOF) then wait/signal
+    /// the child.
+    pub fn into_parts(self) -> (Child, Option<BufWriter<ChildStdin>>) {
         (self.child, self.stdin)
     }
 }
@@ -0,0 +1,46 @@
+use std::ffi::OsString;
+use std::time::Duration;
+
+use super::error::BdError;
+use super::fake::FakeBd;
+use super::runner::{CommandRunner, RunOutput, TokioRunner};
+
+/// The runner a production [`BdClient`](super::BdClient) dispatches to,
+/// chosen once per `loom` invocation by `--bd-backend`.
+#[derive(Debug, Clone)]
+pub enum BdBackend {
+    /// Shell out to the real `bd` binary.
+    Cli(TokioRunner),
+    /// Interpret commands against an in-memory [`FakeBd`] graph.
+    Fake(FakeBd),
+}
+
+impl BdBackend {
+    /// Environment variable `loom` reads `--bd-backend` from. Child `loom`
+    /// processes (the run → check handoff, `msg --resume`) get it set so a
+    /// fake-backed session stays fake end to end.
+    pub const ENV: &'static str = "LOOM_BD_BACKEND";
+
+    /// The `--bd-backend` value selecting this backend.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Cli(_) => "bd",
+            Self::Fake(_) => "fake",
+        }
+    }
+}
+
+impl Default for BdBackend {
+    fn default() -> Self {
+        Self::Cli(TokioRunner)
+    }
+}
+
+impl CommandRunner for BdBackend {
+    async fn run(&self, args: Vec<OsString>, t: Duration) -> Result<RunOutput, BdError> {
+        match self {
+            Self::Cli(runner) => runner.run(args, t).await,
+            Self::Fake(fake) => fake.run(args, t).await,
+        }
+    }
+}
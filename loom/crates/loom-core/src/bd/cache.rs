@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::fmt;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// `bd` usage counters for one [`BdClient`](super::BdClient), read with
+/// [`BdClient::stats`](super::BdClient::stats).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BdStats {
+    /// Commands that reached the runner — one `bd` subprocess each on the
+    /// CLI backend.
+    pub invocations: u64,
+    /// Of those, the ones that mutated the store.
+    pub mutations: u64,
+    /// Reads answered from the read cache without invoking `bd`.
+    pub cache_hits: u64,
+    /// Reads answered over Dolt SQL without invoking `bd`.
+    pub sql_reads: u64,
+}
+
+impl fmt::Display for BdStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} bd call(s), {} mutation(s), {} read(s) served from cache, {} over SQL",
+            self.invocations, self.mutations, self.cache_hits, self.sql_reads
+        )
+    }
+}
+
+#[derive(Debug, Default)]
+pub(super) struct Counters {
+    invocations: AtomicU64,
+    mutations: AtomicU64,
+    cache_hits: AtomicU64,
+    sql_reads: AtomicU64,
+}
+
+impl Counters {
+    pub(super) fn invoked(&self, mutation: bool) {
+        self.invocations.fetch_add(1, Ordering::Relaxed);
+        if mutation {
+            self.mutations.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    pub(super) fn hit(&self) {
+        self.cache_hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(super) fn sql_read(&self) {
+        self.sql_reads.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(super) fn snapshot(&self) -> BdStats {
+        BdStats {
+            invocations: self.invocations.load(Ordering::Relaxed),
+            mutations: self.mutations.load(Ordering::Relaxed),
+            cache_hits: self.cache_hits.load(Ordering::Relaxed),
+            sql_reads: self.sql_reads.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Successful read outputs keyed by argv, each served for `ttl` after it
+/// was fetched. Any write through the same client clears the lot: the
+/// cache only ever hides writes made by *other* processes, and only for
+/// `ttl`.
+#[derive(Debug)]
+pub(super) struct ReadCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<Vec<OsString>, Entry>>,
+}
+
+/// When a read was fetched, and its stdout.
+type Entry = (Instant, Vec<u8>);
+
+impl ReadCache {
+    pub(super) fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub(super) fn get(&self, args: &[OsString]) -> Option<Vec<u8>> {
+        let mut entries = self.entries.lock().unwrap_or_else(|p| p.into_inner());
+        match entries.get(args) {
+            Some((at, stdout)) if at.elapsed() < self.ttl => Some(stdout.clone()),
+            Some(_) => {
+                entries.remove(args);
+                None
+            }
+            None => None,
+        }
+    }
+
+    pub(super) fn put(&self, args: Vec<OsString>, stdout: Vec<u8>) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .insert(args, (Instant::now(), stdout));
+    }
+
+    pub(super) fn clear(&self) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .clear();
+    }
+}
@@ -5,32 +5,41 @@ use serde::Deserialize;
 
 use crate::identifier::{BeadId, MoleculeId};
 
+use super::backend::BdBackend;
+use super::cache::{BdStats, Counters, ReadCache};
 use super::error::BdError;
 use super::models::{Bead, MolProgress};
 use super::runner::{CommandRunner, RunOutput, TokioRunner, render_args};
+use super::sql::SqlReader;
 
 /// Default subprocess timeout. Configurable per [`BdClient`] instance via
 /// [`BdClient::with_timeout`]. Matches the 60-second ceiling used by
 /// `GitClient`.
 pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
 
+/// Read-cache lifetime the `loom run` / `loom check` loops opt into via
+/// [`BdClient::with_read_cache`]: long enough to collapse the repeated
+/// snapshots within one iteration, short enough that an agent's own `bd`
+/// writes show up by the next.
+pub const DEFAULT_READ_CACHE_TTL: Duration = Duration::from_secs(2);
+
 /// Typed wrapper around the `bd` CLI.
-pub struct BdClient<R: CommandRunner = TokioRunner> {
+pub struct BdClient<R: CommandRunner = BdBackend> {
     runner: R,
     timeout: Duration,
+    cache: Option<ReadCache>,
+    sql: Option<SqlReader>,
+    counters: Counters,
 }
 
-impl BdClient<TokioRunner> {
+impl BdClient<BdBackend> {
     /// Construct a client that shells out to the real `bd` binary.
     pub fn new() -> Self {
-        Self {
-            runner: TokioRunner,
-            timeout: DEFAULT_TIMEOUT,
-        }
+        Self::with_runner(BdBackend::Cli(TokioRunner))
     }
 }
 
-impl Default for BdClient<TokioRunner> {
+impl Default for BdClient<BdBackend> {
     fn default() -> Self {
         Self::new()
     }
@@ -43,6 +52,9 @@ impl<R: CommandRunner> BdClient<R> {
         Self {
             runner,
             timeout: DEFAULT_TIMEOUT,
+            cache: None,
+            sql: None,
+            counters: Counters::default(),
         }
     }
 
@@ -56,10 +68,56 @@ impl<R: CommandRunner> BdClient<R> {
         self.timeout
     }
 
+    /// Serve repeated reads (`show`, `list`, `ready`, `mol progress`)
+    /// with identical arguments from memory for up to `ttl`. Every write through this client drops the
+    /// cache; writes by other processes (an agent's `bd` calls) are only
+    /// seen once an entry expires or [`invalidate_reads`](Self::invalidate_reads)
+    /// is called.
+    pub fn with_read_cache(mut self, ttl: Duration) -> Self {
+        self.cache = Some(ReadCache::new(ttl));
+        self
+    }
+
+    /// Answer [`show`](Self::show), [`list`](Self::list),
+    /// [`ready`](Self::ready) and [`mol_progress`](Self::mol_progress)
+    /// straight from the Dolt SQL server (`[beads] backend = "sql"`). Every
+    /// other command, and all writes, still go through the runner.
+    ///
+    /// SQL reads bypass [`with_read_cache`](Self::with_read_cache): the
+    /// cache exists to save a `bd` subprocess per repeated read, and a
+    /// pooled query costs none, while caching it would hide an agent's own
+    /// `bd` writes for the cache's lifetime. The `show` / `list` / `ready` /
+    /// `mol_progress` answers are therefore always current.
+    pub fn with_sql_reads(mut self, reader: SqlReader) -> Self {
+        self.sql = Some(reader);
+        self
+    }
+
+    /// Drop every cached read. Call after handing control to something
+    /// that may write to `bd` behind this client's back.
+    pub fn invalidate_reads(&self) {
+        if let Some(cache) = &self.cache {
+            cache.clear();
+        }
+    }
+
+    /// `bd` usage through this client so far.
+    pub fn stats(&self) -> BdStats {
+        self.counters.snapshot()
+    }
+
+    /// The runner commands are dispatched to.
+    pub fn runner(&self) -> &R {
+        &self.runner
+    }
+
     /// `bd show <id> --json` → first row.
     pub async fn show(&self, id: &BeadId) -> Result<Bead, BdError> {
+        if let Some(sql) = &self.sql {
+            return self.counted_sql(sql.show(id).await);
+        }
         let args = args(["show", id.as_str(), "--json"]);
-        let out = self.invoke(args).await?;
+        let out = self.read(args).await?;
         let mut beads: Vec<Bead> = decode(&out.stdout, &out.args)?;
         if beads.is_empty() {
             return Err(BdError::ShowEmpty);
@@ -97,7 +155,7 @@ impl<R: CommandRunner> BdClient<R> {
             args.push("--labels".into());
             args.push(opts.labels.join(",").into());
         }
-        let out = self.invoke(args).await?;
+        let out = self.write(args).await?;
         let stdout = String::from_utf8(out.stdout)?;
         let trimmed = stdout.trim();
         if trimmed.is_empty() {
@@ -108,19 +166,39 @@ impl<R: CommandRunner> BdClient<R> {
 
     /// `bd close <id>` (optionally with `--reason`).
     pub async fn close(&self, id: &BeadId, reason: Option<&str>) -> Result<(), BdError> {
-        let mut args: Vec<OsString> = vec!["close".into(), id.as_str().to_owned().into()];
+        self.close_many(std::slice::from_ref(id), reason).await
+    }
+
+    /// `bd close <id>... [--reason]` — close several beads in one
+    /// invocation. An empty `ids` is a no-op that never reaches `bd`.
+    pub async fn close_many(&self, ids: &[BeadId], reason: Option<&str>) -> Result<(), BdError> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+        let mut args: Vec<OsString> = vec!["close".into()];
+        args.extend(ids.iter().map(|id| OsString::from(id.as_str())));
         if let Some(r) = reason {
             args.push("--reason".into());
             args.push(r.to_owned().into());
         }
-        self.invoke(args).await?;
+        self.write(args).await?;
         Ok(())
     }
 
     /// `bd update <id> [flags]`. Flags map onto the corresponding `bd
     /// update` switches; unset fields are not forwarded.
     pub async fn update(&self, id: &BeadId, opts: UpdateOpts) -> Result<(), BdError> {
-        let mut args: Vec<OsString> = vec!["update".into(), id.as_str().to_owned().into()];
+        self.update_many(std::slice::from_ref(id), opts).await
+    }
+
+    /// `bd update <id>... [flags]` — apply the same [`UpdateOpts`] to
+    /// several beads in one invocation. An empty `ids` is a no-op.
+    pub async fn update_many(&self, ids: &[BeadId], opts: UpdateOpts) -> Result<(), BdError> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+        let mut args: Vec<OsString> = vec!["update".into()];
+        args.extend(ids.iter().map(|id| OsString::from(id.as_str())));
         if opts.claim {
             args.push("--claim".into());
         }
@@ -140,12 +218,23 @@ impl<R: CommandRunner> BdClient<R> {
             args.push("--remove-label".into());
             args.push(label.into());
         }
-        self.invoke(args).await?;
+        if let Some(notes) = opts.notes {
+            args.push("--notes".into());
+            args.push(notes.into());
+        }
+        if let Some(notes) = opts.append_notes {
+            args.push("--append-notes".into());
+            args.push(notes.into());
+        }
+        self.write(args).await?;
         Ok(())
     }
 
     /// `bd list --json` filtered by status and/or label.
     pub async fn list(&self, opts: ListOpts) -> Result<Vec<Bead>, BdError> {
+        if let Some(sql) = &self.sql {
+            return self.counted_sql(sql.list(&opts).await);
+        }
         let mut args: Vec<OsString> = vec!["list".into(), "--json".into()];
         if let Some(status) = opts.status {
             args.push(format!("--status={status}").into());
@@ -153,24 +242,14 @@ impl<R: CommandRunner> BdClient<R> {
         if let Some(label) = opts.label {
             args.push(format!("--label={label}").into());
         }
-        let out = self.invoke(args).await?;
-        // `bd list --json` returns `null` when the result set is empty.
-        if out.stdout.iter().all(u8::is_ascii_whitespace) {
-            return Ok(Vec::new());
-        }
-        let trimmed = std::str::from_utf8(&out.stdout)
-            .map(str::trim)
-            .unwrap_or_default();
-        if trimmed == "null" {
-            return Ok(Vec::new());
-        }
-        decode(&out.stdout, &out.args)
+        let out = self.read(args).await?;
+        decode_rows(&out.stdout, &out.args)
     }
 
     /// `bd dep add <issue> <depends-on>`.
     pub async fn dep_add(&self, issue: &BeadId, depends_on: &BeadId) -> Result<(), BdError> {
         let args = args(["dep", "add", issue.as_str(), depends_on.as_str()]);
-        self.invoke(args).await?;
+        self.write(args).await?;
         Ok(())
     }
 
@@ -178,6 +257,9 @@ impl<R: CommandRunner> BdClient<R> {
     /// (open, no active blockers). Step (1) of the parallel batch driver:
     /// pulls up to `limit` candidates per batch.
     pub async fn ready(&self, opts: ReadyOpts) -> Result<Vec<Bead>, BdError> {
+        if let Some(sql) = &self.sql {
+            return self.counted_sql(sql.ready(&opts).await);
+        }
         let mut args: Vec<OsString> = vec!["ready".into(), "--json".into()];
         if let Some(n) = opts.limit {
             args.push(format!("--limit={n}").into());
@@ -185,17 +267,8 @@ impl<R: CommandRunner> BdClient<R> {
         if let Some(label) = opts.label {
             args.push(format!("--label={label}").into());
         }
-        let out = self.invoke(args).await?;
-        if out.stdout.iter().all(u8::is_ascii_whitespace) {
-            return Ok(Vec::new());
-        }
-        let trimmed = std::str::from_utf8(&out.stdout)
-            .map(str::trim)
-            .unwrap_or_default();
-        if trimmed == "null" {
-            return Ok(Vec::new());
-        }
-        decode(&out.stdout, &out.args)
+        let out = self.read(args).await?;
+        decode_rows(&out.stdout, &out.args)
     }
 
     /// `bd mol bond <left> <right>`. The polymorphic semantics of
@@ -203,19 +276,55 @@ impl<R: CommandRunner> BdClient<R> {
     /// caller's concern; this wrapper just forwards two operands.
     pub async fn mol_bond(&self, left: &str, right: &str) -> Result<(), BdError> {
         let args = args(["mol", "bond", left, right]);
-        self.invoke(args).await?;
+        self.write(args).await?;
         Ok(())
     }
 
     /// `bd mol progress <id> --json`.
     pub async fn mol_progress(&self, id: &MoleculeId) -> Result<MolProgress, BdError> {
+        if let Some(sql) = &self.sql {
+            return self.counted_sql(sql.mol_progress(id).await);
+        }
         let args = args(["mol", "progress", id.as_str(), "--json"]);
-        let out = self.invoke(args).await?;
+        let out = self.read(args).await?;
         decode(&out.stdout, &out.args)
     }
 
-    async fn invoke(&self, args: Vec<OsString>) -> Result<Invocation, BdError> {
+    /// Count a read the SQL reader answered; failed queries served nothing.
+    fn counted_sql<T>(&self, result: Result<T, BdError>) -> Result<T, BdError> {
+        if result.is_ok() {
+            self.counters.sql_read();
+        }
+        result
+    }
+
+    /// A read: served from the cache when one is configured and holds a
+    /// fresh entry for exactly these arguments.
+    async fn read(&self, args: Vec<OsString>) -> Result<Invocation, BdError> {
+        let Some(cache) = &self.cache else {
+            return self.invoke(args, false).await;
+        };
+        if let Some(stdout) = cache.get(&args) {
+            self.counters.hit();
+            return Ok(Invocation {
+                stdout,
+                args: render_args(&args),
+            });
+        }
+        let out = self.invoke(args.clone(), false).await?;
+        cache.put(args, out.stdout.clone());
+        Ok(out)
+    }
+
+    /// A mutation: drops every cached read, whether or not it succeeds.
+    async fn write(&self, args: Vec<OsString>) -> Result<Invocation, BdError> {
+        self.invalidate_reads();
+        self.invoke(args, true).await
+    }
+
+    async fn invoke(&self, args: Vec<OsString>, mutation: bool) -> Result<Invocation, BdError> {
         let rendered = render_args(&args);
+        self.counters.invoked(mutation);
         let output: RunOutput = self.runner.run(args, self.timeout).await?;
         if !output.success() {
             return Err(BdError::Cli {
@@ -251,6 +360,18 @@ fn decode<T: for<'de> Deserialize<'de>>(stdout: &[u8], args: &str) -> Result<T,
     })
 }
 
+/// Decode a `--json` array, treating empty output and `null` (what `bd`
+/// prints for an empty result set) as no rows.
+fn decode_rows<T: for<'de> Deserialize<'de>>(stdout: &[u8], args: &str) -> Result<Vec<T>, BdError> {
+    let trimmed = std::str::from_utf8(stdout)
+        .map(str::trim)
+        .unwrap_or_default();
+    if stdout.iter().all(u8::is_ascii_whitespace) || trimmed == "null" {
+        return Ok(Vec::new());
+    }
+    decode(stdout, args)
+}
+
 /// Fields accepted by `bd create`. Only fields the workflow actually sets
 /// today are modelled; extend as new call sites need them.
 #[derive(Debug, Clone, Default)]
@@ -271,6 +392,10 @@ pub struct UpdateOpts {
     pub priority: Option<u8>,
     pub add_labels: Vec<String>,
     pub remove_labels: Vec<String>,
+    /// Replace the notes field (`--notes`).
+    pub notes: Option<String>,
+    /// Append a paragraph to the existing notes (`--append-notes`).
+    pub append_notes: Option<String>,
 }
 
 /// Filters accepted by `bd list`. Both fields are optional; passing
@@ -293,7 +418,7 @@ pub struct ReadyOpts {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::bd::Label;
+    use crate::bd::{BdStats, Label};
     use anyhow::{Result, anyhow};
     use std::collections::VecDeque;
     use std::sync::Mutex;
@@ -717,4 +842,162 @@ mod tests {
         assert_eq!(progress.current_step_id.as_deref(), Some("wx-3hhwq.5"));
         Ok(())
     }
+
+    #[tokio::test]
+    async fn show_parses_notes_timestamps_parent_and_dependencies() -> Result<()> {
+        let json = br#"[{
+            "id": "wx-3hhwq.5",
+            "title": "BdClient",
+            "status": "closed",
+            "notes": "Chose option 1",
+            "assignee": "loom",
+            "parent": "wx-3hhwq",
+            "created_at": "2026-05-03T12:00:00Z",
+            "updated_at": "2026-05-03T13:00:00Z",
+            "closed_at": "2026-05-03T14:00:00Z",
+            "dependencies": [
+                {"id": "wx-3hhwq.4", "title": "Runner", "status": "closed", "dependency_type": "blocks"},
+                {"issue_id": "wx-3hhwq.5", "depends_on_id": "wx-3hhwq", "type": "parent-child"}
+            ]
+        }]"#;
+        let runner = CapturingRunner::new([ok(json)]);
+        let client = BdClient::with_runner(runner);
+        let bead = client.show(&BeadId::new("wx-3hhwq.5")?).await?;
+        assert_eq!(bead.notes, "Chose option 1");
+        assert_eq!(bead.assignee.as_deref(), Some("loom"));
+        assert_eq!(bead.parent, Some(BeadId::new("wx-3hhwq")?));
+        assert_eq!(bead.closed_at.as_deref(), Some("2026-05-03T14:00:00Z"));
+        assert_eq!(bead.dependencies.len(), 2);
+        assert_eq!(bead.dependencies[0].id, BeadId::new("wx-3hhwq.4")?);
+        assert_eq!(bead.dependencies[0].kind, "blocks");
+        assert_eq!(bead.dependencies[1].id, BeadId::new("wx-3hhwq")?);
+        assert_eq!(bead.dependencies[1].kind, "parent-child");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn update_forwards_notes_flags() -> Result<()> {
+        let runner = CapturingRunner::new([ok(b"")]);
+        let client = BdClient::with_runner(runner);
+        client
+            .update(
+                &BeadId::new("wx-x")?,
+                UpdateOpts {
+                    append_notes: Some("Chose option 2".into()),
+                    ..UpdateOpts::default()
+                },
+            )
+            .await?;
+        let argv = argv_of(&client.runner, 0);
+        assert_eq!(
+            argv,
+            vec!["update", "wx-x", "--append-notes", "Chose option 2"]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn close_many_and_update_many_batch_into_one_invocation() -> Result<()> {
+        let client = BdClient::with_runner(CapturingRunner::new([]));
+        let ids = [BeadId::new("wx-a")?, BeadId::new("wx-b")?];
+        client.close_many(&ids, Some("merged")).await?;
+        client
+            .update_many(
+                &ids,
+                UpdateOpts {
+                    add_labels: vec!["loom:clarify".into()],
+                    ..UpdateOpts::default()
+                },
+            )
+            .await?;
+        client.close_many(&[], None).await?;
+        client.update_many(&[], UpdateOpts::default()).await?;
+
+        assert_eq!(
+            argv_of(&client.runner, 0),
+            vec!["close", "wx-a", "wx-b", "--reason", "merged"]
+        );
+        assert_eq!(
+            argv_of(&client.runner, 1),
+            vec!["update", "wx-a", "wx-b", "--add-label", "loom:clarify"]
+        );
+        assert_eq!(
+            client.stats().invocations,
+            2,
+            "empty batches never reach bd"
+        );
+        assert_eq!(client.stats().mutations, 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_cache_serves_repeats_until_a_write() -> Result<()> {
+        let row = ok(br#"[
+            {"id":"wx-a","title":"A","status":"open"},
+            {"id":"wx-b","title":"B","status":"open"}
+        ]"#);
+        let runner = CapturingRunner::new([row.clone(), ok(b""), row]);
+        let client = BdClient::with_runner(runner).with_read_cache(Duration::from_secs(60));
+        let spec = || ListOpts {
+            status: None,
+            label: Some("spec:alpha".into()),
+        };
+
+        assert_eq!(client.list(spec()).await?.len(), 2);
+        assert_eq!(client.list(spec()).await?.len(), 2);
+        assert_eq!(
+            client.stats(),
+            BdStats {
+                invocations: 1,
+                mutations: 0,
+                cache_hits: 1,
+                sql_reads: 0,
+            }
+        );
+
+        client.close(&BeadId::new("wx-a")?, None).await?;
+        assert_eq!(client.list(spec()).await?.len(), 2);
+        assert_eq!(
+            client.stats(),
+            BdStats {
+                invocations: 3,
+                mutations: 1,
+                cache_hits: 1,
+                sql_reads: 0,
+            }
+        );
+
+        client.invalidate_reads();
+        client.list(spec()).await?;
+        assert_eq!(client.stats().invocations, 4);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reads_are_not_cached_unless_enabled_or_when_expired() -> Result<()> {
+        let client = BdClient::with_runner(CapturingRunner::new([]));
+        client.list(ListOpts::default()).await?;
+        client.list(ListOpts::default()).await?;
+        assert_eq!(client.stats().invocations, 2);
+
+        let client =
+            BdClient::with_runner(CapturingRunner::new([])).with_read_cache(Duration::ZERO);
+        client.list(ListOpts::default()).await?;
+        client.list(ListOpts::default()).await?;
+        assert_eq!(client.stats().invocations, 2);
+        assert_eq!(client.stats().cache_hits, 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn failed_sql_reads_are_not_counted() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let client = BdClient::with_runner(CapturingRunner::new([]))
+            .with_read_cache(Duration::from_secs(60))
+            .with_sql_reads(SqlReader::socket(&dir.path().join("dolt.sock"), "beads"));
+        assert!(client.show(&BeadId::new("wx-a")?).await.is_err());
+        assert!(client.list(ListOpts::default()).await.is_err());
+        assert_eq!(client.stats(), BdStats::default());
+        Ok(())
+    }
 }
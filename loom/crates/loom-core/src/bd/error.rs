@@ -36,6 +36,19 @@ pub enum BdError {
     /// `bd create --silent` returned a malformed bead id: {0}
     CreateInvalidId(#[from] ParseBeadIdError),
 
+    /// Dolt SQL read failed: {what}
+    Sql {
+        what: &'static str,
+        #[source]
+        source: mysql_async::Error,
+    },
+
+    /// unexpected Dolt SQL row for {what}: {detail}
+    SqlRow { what: &'static str, detail: String },
+
+    /// invalid Dolt SQL url
+    SqlUrl(#[source] mysql_async::UrlError),
+
     /// invalid utf-8 in `bd` output
     Utf8(#[from] std::string::FromUtf8Error),
 
@@ -0,0 +1,677 @@
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+use serde::{Deserialize, Serialize};
+
+use crate::identifier::{BeadId, MoleculeId};
+use crate::logging::format_rfc3339;
+
+use super::error::BdError;
+use super::label::Label;
+use super::models::{Bead, Dependency, MolProgress};
+use super::runner::{CommandRunner, RunOutput, render_args};
+
+/// Workspace-relative store `loom --bd-backend=fake` persists the fake
+/// bead graph to, so consecutive commands see each other's writes.
+pub const FAKE_BD_STORE: &str = ".wrapix/loom/fake-bd.json";
+
+/// Id prefix for beads the fake creates without a parent.
+const DEFAULT_PREFIX: &str = "fk";
+
+/// Assignee recorded by `update --claim`.
+const CLAIM_ASSIGNEE: &str = "loom";
+
+/// In-memory `bd`: a [`CommandRunner`] that interprets the argv
+/// [`BdClient`](super::BdClient) emits against a bead graph held in memory.
+///
+/// Covers the subcommands the client wraps — `show`, `create`, `close`,
+/// `update`, `list`, `ready`, `dep add`, `mol bond|progress` — with `bd`'s observable semantics: `list`
+/// hides closed beads unless asked, `ready` returns open non-epic beads
+/// with no unclosed `blocks` dependency in priority order, `create
+/// --parent` mints `<parent>.<n>` ids and a `parent-child` edge. Anything
+/// else exits 1 with a `fake bd:` message, like an unknown `bd` command.
+///
+/// Clones share one graph, so a test can hand a clone to the code under
+/// test and inspect the same state afterwards via [`bead`](Self::bead) /
+/// [`beads`](Self::beads). [`open`](Self::open) backs the graph with a JSON
+/// file that is rewritten after every mutation.
+#[derive(Debug, Clone, Default)]
+pub struct FakeBd {
+    state: Arc<Mutex<FakeState>>,
+    store: Option<PathBuf>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct FakeState {
+    #[serde(default)]
+    next_id: u64,
+    #[serde(default)]
+    beads: Vec<Bead>,
+}
+
+impl FakeBd {
+    /// An empty graph that lives only as long as its clones.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A graph persisted at `path`. A missing file starts empty; the file
+    /// (and its parent directory) is created on the first mutation.
+    ///
+    /// Every command re-reads the file first, so a `loom` child process
+    /// handed the same store (run → check handoff) and its parent never
+    /// overwrite each other with a stale graph.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, BdError> {
+        let path = path.into();
+        let state = load(&path)?;
+        Ok(Self {
+            state: Arc::new(Mutex::new(state)),
+            store: Some(path),
+        })
+    }
+
+    /// Seed `bead` as-is, replacing any bead with the same id.
+    pub fn insert(&self, bead: Bead) {
+        let mut state = self.lock();
+        match state.beads.iter_mut().find(|b| b.id == bead.id) {
+            Some(existing) => *existing = bead,
+            None => state.beads.push(bead),
+        }
+    }
+
+    /// Current copy of one bead.
+    pub fn bead(&self, id: &BeadId) -> Option<Bead> {
+        self.lock().beads.iter().find(|b| &b.id == id).cloned()
+    }
+
+    /// Every bead, closed ones included, in creation order.
+    pub fn beads(&self) -> Vec<Bead> {
+        self.lock().beads.clone()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, FakeState> {
+        self.state.lock().unwrap_or_else(|p| p.into_inner())
+    }
+
+    fn persist(&self, state: &FakeState) -> std::io::Result<()> {
+        let Some(path) = &self.store else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let body = serde_json::to_vec_pretty(state).map_err(std::io::Error::other)?;
+        let tmp = path.with_extension("json.tmp");
+        std::fs::write(&tmp, body)?;
+        std::fs::rename(&tmp, path)
+    }
+
+    /// Interpret one argv. `Ok` carries stdout; `Err` is stderr for a
+    /// non-zero exit.
+    fn execute(&self, argv: &[String]) -> Result<String, String> {
+        let args = Args::parse(argv);
+        let mut state = self.lock();
+        if let Some(path) = &self.store {
+            *state = load(path).map_err(|e| format!("fake bd: {e}"))?;
+        }
+        let positional = args.positional();
+        let (stdout, mutated) = match positional.as_slice() {
+            ["show", ids @ ..] if !ids.is_empty() => (state.show(ids)?, false),
+            ["create"] => (state.create(&args)?, true),
+            ["close", ids @ ..] if !ids.is_empty() => (state.close(ids)?, true),
+            ["update", ids @ ..] if !ids.is_empty() => (state.update(ids, &args)?, true),
+            ["list"] => (state.list(&args), false),
+            ["ready"] => (state.ready(&args), false),
+            ["dep", "add", issue, depends_on] => {
+                let kind = args.value("--type").unwrap_or("blocks");
+                (state.dep_add(issue, depends_on, kind)?, true)
+            }
+            ["mol", "bond", left, right] => (state.mol_bond(left, right)?, true),
+            ["mol", "progress", id] => (state.mol_progress(id)?, false),
+            _ => return Err(format!("fake bd: unsupported command: {}", argv.join(" "))),
+        };
+        if mutated {
+            self.persist(&state)
+                .map_err(|e| format!("fake bd: failed to persist store: {e}"))?;
+        }
+        Ok(stdout)
+    }
+}
+
+impl CommandRunner for FakeBd {
+    async fn run(&self, args: Vec<OsString>, _t: Duration) -> Result<RunOutput, BdError> {
+        let argv: Vec<String> = args
+            .iter()
+            .map(|a| a.to_string_lossy().into_owned())
+            .collect();
+        tracing::debug!(args = %render_args(&args), "fake bd");
+        Ok(match self.execute(&argv) {
+            Ok(stdout) => RunOutput {
+                status: 0,
+                stdout: stdout.into_bytes(),
+                stderr: Vec::new(),
+            },
+            Err(stderr) => RunOutput {
+                status: 1,
+                stdout: Vec::new(),
+                stderr: stderr.into_bytes(),
+            },
+        })
+    }
+}
+
+impl FakeState {
+    fn find(&self, id: &str) -> Result<&Bead, String> {
+        self.beads
+            .iter()
+            .find(|b| b.id.as_str() == id)
+            .ok_or_else(|| not_found(id))
+    }
+
+    fn find_mut(&mut self, id: &str) -> Result<&mut Bead, String> {
+        self.beads
+            .iter_mut()
+            .find(|b| b.id.as_str() == id)
+            .ok_or_else(|| not_found(id))
+    }
+
+    /// A bead as `bd show` prints it: dependency edges carry the far
+    /// bead's current title and status.
+    fn rendered(&self, bead: &Bead) -> Bead {
+        let mut out = bead.clone();
+        for dep in &mut out.dependencies {
+            if let Some(target) = self.beads.iter().find(|b| b.id == dep.id) {
+                dep.title.clone_from(&target.title);
+                dep.status.clone_from(&target.status);
+            }
+        }
+        out
+    }
+
+    fn show(&self, ids: &[&str]) -> Result<String, String> {
+        let beads = ids
+            .iter()
+            .map(|id| self.find(id).map(|b| self.rendered(b)))
+            .collect::<Result<Vec<_>, _>>()?;
+        to_json(&beads)
+    }
+
+    fn create(&mut self, args: &Args) -> Result<String, String> {
+        let title = args
+            .value("--title")
+            .ok_or("fake bd: --title is required")?;
+        let parent = match args.value("--parent") {
+            Some(p) => Some(self.find(p)?.id.clone()),
+            None => None,
+        };
+        let id = self.mint_id(parent.as_ref())?;
+        let now = now();
+        let mut bead = Bead {
+            id: id.clone(),
+            title: title.to_string(),
+            description: args.value("--description").unwrap_or_default().to_string(),
+            status: "open".to_string(),
+            priority: args
+                .value("--priority")
+                .map(|p| p.parse().map_err(|_| format!("fake bd: bad priority {p}")))
+                .transpose()?
+                .unwrap_or(2),
+            issue_type: args.value("--type").unwrap_or("task").to_string(),
+            labels: args
+                .value("--labels")
+                .map(|l| {
+                    l.split(',')
+                        .filter(|s| !s.is_empty())
+                        .map(Label::new)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            created_at: Some(now.clone()),
+            updated_at: Some(now),
+            closed_at: None,
+            notes: String::new(),
+            assignee: None,
+            parent: None,
+            dependencies: Vec::new(),
+        };
+        if let Some(parent) = parent {
+            bead.dependencies.push(edge(&parent, "parent-child"));
+            bead.parent = Some(parent);
+        }
+        self.beads.push(bead);
+        Ok(format!("{id}\n"))
+    }
+
+    /// `<parent>.<n>` under a top-level parent, else `<prefix>-<n>`. Ids
+    /// nest one level only, matching the [`BeadId`] shape.
+    fn mint_id(&mut self, parent: Option<&BeadId>) -> Result<BeadId, String> {
+        let raw = match parent {
+            Some(p) if !p.as_str().contains('.') => {
+                let prefix = format!("{p}.");
+                let n = self
+                    .beads
+                    .iter()
+                    .filter(|b| b.id.as_str().starts_with(&prefix))
+                    .count();
+                format!("{prefix}{}", n + 1)
+            }
+            _ => {
+                self.next_id += 1;
+                format!("{DEFAULT_PREFIX}-{}", self.next_id)
+            }
+        };
+        BeadId::new(&raw).map_err(|e| format!("fake bd: {e}"))
+    }
+
+    /// Every id must exist before any is closed, so a typo does not leave
+    /// half a batch applied.
+    fn close(&mut self, ids: &[&str]) -> Result<String, String> {
+        for id in ids {
+            self.find(id)?;
+        }
+        let now = now();
+        for id in ids {
+            let bead = self.find_mut(id)?;
+            bead.status = "closed".to_string();
+            bead.closed_at = Some(now.clone());
+            bead.updated_at = Some(now.clone());
+        }
+        Ok(String::new())
+    }
+
+    /// Like [`close`](Self::close), every id must exist before any is
+    /// touched, so a typo does not leave half a batch applied.
+    fn update(&mut self, ids: &[&str], args: &Args) -> Result<String, String> {
+        for id in ids {
+            self.find(id)?;
+        }
+        for id in ids {
+            self.update_one(id, args)?;
+        }
+        Ok(String::new())
+    }
+
+    fn update_one(&mut self, id: &str, args: &Args) -> Result<(), String> {
+        let bead = self.find_mut(id)?;
+        if args.has("--claim") {
+            if let Some(owner) = bead.assignee.as_deref()
+                && owner != CLAIM_ASSIGNEE
+            {
+                return Err(format!("fake bd: {id} is already claimed by {owner}"));
+            }
+            bead.status = "in_progress".to_string();
+            bead.assignee = Some(CLAIM_ASSIGNEE.to_string());
+        }
+        if let Some(status) = args.value("--status") {
+            bead.status = status.to_string();
+            bead.closed_at = (status == "closed").then(now);
+        }
+        if let Some(p) = args.value("--priority") {
+            bead.priority = p
+                .parse()
+                .map_err(|_| format!("fake bd: bad priority {p}"))?;
+        }
+        for label in args.values("--add-label") {
+            if !bead.labels.iter().any(|l| l.as_str() == label) {
+                bead.labels.push(Label::new(label));
+            }
+        }
+        for label in args.values("--remove-label") {
+            bead.labels.retain(|l| l.as_str() != label);
+        }
+        if let Some(notes) = args.value("--notes") {
+            bead.notes = notes.to_string();
+        }
+        if let Some(notes) = args.value("--append-notes") {
+            if !bead.notes.is_empty() {
+                bead.notes.push('\n');
+            }
+            bead.notes.push_str(notes);
+        }
+        bead.updated_at = Some(now());
+        Ok(())
+    }
+
+    fn list(&self, args: &Args) -> String {
+        let status = args.value("--status");
+        let beads: Vec<Bead> = self
+            .beads
+            .iter()
+            .filter(|b| match status {
+                Some(s) => b.status == s,
+                None => args.has("--all") || b.status != "closed",
+            })
+            .filter(|b| has_labels(b, args))
+            .map(|b| self.rendered(b))
+            .collect();
+        to_json(&beads).unwrap_or_default()
+    }
+
+    fn ready(&self, args: &Args) -> String {
+        let mut ready: Vec<&Bead> = self
+            .beads
+            .iter()
+            .filter(|b| b.status == "open" && b.issue_type != "epic")
+            .filter(|b| !self.blocked(b))
+            .filter(|b| has_labels(b, args))
+            .collect();
+        ready.sort_by_key(|b| b.priority);
+        if let Some(limit) = args.value("--limit").and_then(|n| n.parse().ok()) {
+            ready.truncate(limit);
+        }
+        let beads: Vec<Bead> = ready.into_iter().map(|b| self.rendered(b)).collect();
+        to_json(&beads).unwrap_or_default()
+    }
+
+    fn blocked(&self, bead: &Bead) -> bool {
+        bead.dependencies.iter().any(|dep| {
+            dep.kind == "blocks"
+                && self
+                    .beads
+                    .iter()
+                    .any(|b| b.id == dep.id && b.status != "closed")
+        })
+    }
+
+    fn dep_add(&mut self, issue: &str, depends_on: &str, kind: &str) -> Result<String, String> {
+        let target = self.find(depends_on)?.id.clone();
+        let bead = self.find_mut(issue)?;
+        if !bead.dependencies.iter().any(|d| d.id == target) {
+            bead.dependencies.push(edge(&target, kind));
+        }
+        Ok(String::new())
+    }
+
+    /// Only bead-to-bead bonding is modelled: `right` joins `left`'s
+    /// molecule as a child.
+    fn mol_bond(&mut self, left: &str, right: &str) -> Result<String, String> {
+        let root = self.find(left)?.id.clone();
+        let child = self.find_mut(right)?;
+        child.parent = Some(root.clone());
+        if !child.dependencies.iter().any(|d| d.id == root) {
+            child.dependencies.push(edge(&root, "parent-child"));
+        }
+        Ok(String::new())
+    }
+
+    fn children(&self, root: &BeadId) -> Vec<&Bead> {
+        self.beads
+            .iter()
+            .filter(|b| b.parent.as_ref() == Some(root))
+            .collect()
+    }
+
+    fn mol_progress(&self, id: &str) -> Result<String, String> {
+        let root = self.find(id)?;
+        let progress = MolProgress::tally(
+            MoleculeId::new(root.id.as_str()),
+            root.title.clone(),
+            self.children(&root.id)
+                .into_iter()
+                .map(|b| (b.id.as_str(), b.status.as_str())),
+        );
+        to_json(&progress)
+    }
+}
+
+fn load(path: &Path) -> Result<FakeState, BdError> {
+    match std::fs::read(path) {
+        Ok(bytes) => serde_json::from_slice(&bytes).map_err(|source| BdError::Decode {
+            args: format!("fake store {}", path.display()),
+            source,
+        }),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(FakeState::default()),
+        Err(e) => Err(BdError::Spawn(e)),
+    }
+}
+
+/// `--label` may repeat; every one must be present (bd's AND semantics).
+fn has_labels(bead: &Bead, args: &Args) -> bool {
+    args.values("--label")
+        .all(|want| bead.labels.iter().any(|l| l.as_str() == want))
+}
+
+fn edge(target: &BeadId, kind: &str) -> Dependency {
+    Dependency {
+        id: target.clone(),
+        title: String::new(),
+        status: String::new(),
+        kind: kind.to_string(),
+    }
+}
+
+fn not_found(id: &str) -> String {
+    format!("no issue found matching \"{id}\"")
+}
+
+fn now() -> String {
+    format_rfc3339(SystemTime::now())
+}
+
+fn to_json<T: Serialize + ?Sized>(value: &T) -> Result<String, String> {
+    serde_json::to_string(value).map_err(|e| format!("fake bd: {e}"))
+}
+
+/// `bd` argv split into positionals and flags. Flags take the next token
+/// as their value (or `--flag=value`) unless they are one of the boolean
+/// switches the client emits.
+struct Args {
+    positional: Vec<String>,
+    flags: Vec<(String, Option<String>)>,
+}
+
+const SWITCHES: &[&str] = &["--json", "--silent", "--claim", "--all"];
+
+impl Args {
+    fn parse(argv: &[String]) -> Self {
+        let mut positional = Vec::new();
+        let mut flags = Vec::new();
+        let mut iter = argv.iter();
+        while let Some(arg) = iter.next() {
+            if !arg.starts_with("--") {
+                positional.push(arg.clone());
+            } else if let Some((name, value)) = arg.split_once('=') {
+                flags.push((name.to_string(), Some(value.to_string())));
+            } else if SWITCHES.contains(&arg.as_str()) {
+                flags.push((arg.clone(), None));
+            } else {
+                flags.push((arg.clone(), iter.next().cloned()));
+            }
+        }
+        Self { positional, flags }
+    }
+
+    fn positional(&self) -> Vec<&str> {
+        self.positional.iter().map(String::as_str).collect()
+    }
+
+    fn has(&self, name: &str) -> bool {
+        self.flags.iter().any(|(n, _)| n == name)
+    }
+
+    fn value(&self, name: &str) -> Option<&str> {
+        self.flags
+            .iter()
+            .rev()
+            .find(|(n, _)| n == name)
+            .and_then(|(_, v)| v.as_deref())
+    }
+
+    fn values<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a str> {
+        self.flags
+            .iter()
+            .filter(move |(n, _)| n == name)
+            .filter_map(|(_, v)| v.as_deref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bd::{BdClient, CreateOpts, ListOpts, ReadyOpts, UpdateOpts};
+    use anyhow::Result;
+
+    async fn create(
+        bd: &BdClient<FakeBd>,
+        title: &str,
+        labels: &[&str],
+        parent: Option<&BeadId>,
+    ) -> Result<BeadId> {
+        Ok(bd
+            .create(CreateOpts {
+                title: title.into(),
+                description: format!("{title} body"),
+                labels: labels.iter().map(|s| s.to_string()).collect(),
+                parent: parent.cloned(),
+                ..CreateOpts::default()
+            })
+            .await?)
+    }
+
+    #[tokio::test]
+    async fn ready_respects_blockers_labels_and_priority() -> Result<()> {
+        let fake = FakeBd::new();
+        let bd = BdClient::with_runner(fake.clone());
+        let a = create(&bd, "a", &["spec:alpha"], None).await?;
+        let b = create(&bd, "b", &["spec:alpha"], None).await?;
+        let c = create(&bd, "c", &["spec:beta"], None).await?;
+        bd.dep_add(&b, &a).await?;
+        bd.update(
+            &c,
+            UpdateOpts {
+                priority: Some(0),
+                ..UpdateOpts::default()
+            },
+        )
+        .await?;
+
+        let ids = |beads: Vec<Bead>| -> Vec<String> {
+            beads.into_iter().map(|b| b.id.to_string()).collect()
+        };
+        let ready = bd.ready(ReadyOpts::default()).await?;
+        assert_eq!(ids(ready), [c.to_string(), a.to_string()]);
+
+        let alpha = bd
+            .ready(ReadyOpts {
+                limit: Some(5),
+                label: Some("spec:alpha".into()),
+            })
+            .await?;
+        assert_eq!(ids(alpha), [a.to_string()]);
+
+        bd.close(&a, None).await?;
+        let alpha = bd
+            .ready(ReadyOpts {
+                limit: None,
+                label: Some("spec:alpha".into()),
+            })
+            .await?;
+        assert_eq!(ids(alpha), [b.to_string()]);
+        assert!(fake.bead(&a).and_then(|b| b.closed_at).is_some());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn update_list_and_show_round_trip_through_the_client() -> Result<()> {
+        let bd = BdClient::with_runner(FakeBd::new());
+        let id = create(&bd, "q", &["spec:alpha", "loom:clarify"], None).await?;
+        bd.update(
+            &id,
+            UpdateOpts {
+                remove_labels: vec!["loom:clarify".into()],
+                append_notes: Some("Chose option 1".into()),
+                claim: true,
+                ..UpdateOpts::default()
+            },
+        )
+        .await?;
+
+        let clarifies = bd
+            .list(ListOpts {
+                status: None,
+                label: Some("loom:clarify".into()),
+            })
+            .await?;
+        assert!(clarifies.is_empty());
+
+        let bead = bd.show(&id).await?;
+        assert_eq!(bead.status, "in_progress");
+        assert_eq!(bead.assignee.as_deref(), Some("loom"));
+        assert_eq!(bead.notes, "Chose option 1");
+        assert_eq!(bead.labels, [Label::new("spec:alpha")]);
+        assert!(bead.created_at.is_some());
+
+        let err = bd
+            .show(&BeadId::new("fk-404")?)
+            .await
+            .err()
+            .ok_or_else(|| anyhow::anyhow!("missing bead must fail"))?;
+        assert!(err.to_string().contains("no issue found"), "{err}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn molecules_and_deps() -> Result<()> {
+        let bd = BdClient::with_runner(FakeBd::new());
+        let root = create(&bd, "epic", &[], None).await?;
+        let one = create(&bd, "step one", &[], Some(&root)).await?;
+        let two = create(&bd, "step two", &[], Some(&root)).await?;
+        assert_eq!(one.as_str(), format!("{root}.1"));
+        assert_eq!(two.as_str(), format!("{root}.2"));
+        bd.dep_add(&two, &one).await?;
+        bd.update(
+            &one,
+            UpdateOpts {
+                claim: true,
+                ..UpdateOpts::default()
+            },
+        )
+        .await?;
+
+        let mol_id = MoleculeId::new(root.as_str());
+        let progress = bd.mol_progress(&mol_id).await?;
+        assert_eq!((progress.completed, progress.in_progress), (0, 1));
+        assert_eq!(progress.total, 2);
+        assert_eq!(progress.current_step_id.as_deref(), Some(one.as_str()));
+
+        let deps = bd.show(&two).await?.dependencies;
+        assert!(
+            deps.iter()
+                .any(|d| d.id == one && d.kind == "blocks" && d.status == "in_progress")
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn unsupported_commands_fail_like_the_cli() -> Result<()> {
+        let bd = BdClient::with_runner(FakeBd::new());
+        let err = bd
+            .mol_bond("fk-1", "fk-2")
+            .await
+            .err()
+            .ok_or_else(|| anyhow::anyhow!("unknown ids must fail"))?;
+        assert!(matches!(err, BdError::Cli { status: 1, .. }), "{err:?}");
+        let out = FakeBd::new()
+            .run(vec!["frobnicate".into()], Duration::from_secs(1))
+            .await?;
+        assert_eq!(out.status, 1);
+        assert!(String::from_utf8_lossy(&out.stderr).contains("unsupported command"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn open_persists_the_graph_between_instances() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let first = BdClient::with_runner(FakeBd::open(dir.path().join(FAKE_BD_STORE))?);
+        let id = create(&first, "persisted", &["spec:alpha"], None).await?;
+
+        let second = BdClient::with_runner(FakeBd::open(dir.path().join(FAKE_BD_STORE))?);
+        assert_eq!(second.show(&id).await?.title, "persisted");
+        let next = create(&second, "next", &[], None).await?;
+        assert_ne!(next, id);
+        assert!(dir.path().join(FAKE_BD_STORE).is_file());
+        Ok(())
+    }
+}
@@ -1,9 +1,14 @@
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
 
+use crate::agent::{AgentKind, ModelSelection};
+use crate::config::parse_backend_name;
 use crate::identifier::{ProfileName, SpecLabel};
 
 /// One bead label. Wraps the raw `bd` string so the prefix families
-/// (`spec:<X>`, `profile:<X>`, `loom:<X>`) parse exactly once at
+/// (`spec:<X>`, `profile:<X>`, `loom:<X>` and the per-bead overrides
+/// `model:`, `agent:`, `retries:`, `timeout:`) parse exactly once at
 /// deserialization time and call sites read through typed accessors instead
 /// of re-doing `strip_prefix` walks.
 ///
@@ -15,6 +20,10 @@ pub struct Label(String);
 
 const SPEC_PREFIX: &str = "spec:";
 const PROFILE_PREFIX: &str = "profile:";
+const MODEL_PREFIX: &str = "model:";
+const AGENT_PREFIX: &str = "agent:";
+const RETRIES_PREFIX: &str = "retries:";
+const TIMEOUT_PREFIX: &str = "timeout:";
 const CLARIFY: &str = "loom:clarify";
 const ACTIVE: &str = "loom:active";
 
@@ -37,6 +46,36 @@ impl Label {
         self.0.strip_prefix(PROFILE_PREFIX).map(ProfileName::new)
     }
 
+    /// `Some(ModelSelection)` when the label is `model:<provider>/<id>` with
+    /// both parts non-empty.
+    pub fn model(&self) -> Option<ModelSelection> {
+        let (provider, model_id) = self.0.strip_prefix(MODEL_PREFIX)?.split_once('/')?;
+        if provider.is_empty() || model_id.is_empty() {
+            return None;
+        }
+        Some(ModelSelection {
+            provider: provider.to_string(),
+            model_id: model_id.to_string(),
+        })
+    }
+
+    /// `Some(AgentKind)` when the label is `agent:pi` or `agent:claude` —
+    /// or `agent:mock` in builds with the `mock` feature.
+    pub fn agent_kind(&self) -> Option<AgentKind> {
+        parse_backend_name(self.0.strip_prefix(AGENT_PREFIX)?).ok()
+    }
+
+    /// `Some(n)` when the label is `retries:<n>`.
+    pub fn retries(&self) -> Option<u32> {
+        self.0.strip_prefix(RETRIES_PREFIX)?.parse().ok()
+    }
+
+    /// `Some(duration)` when the label is `timeout:<dur>` — whole numbers
+    /// with `h`, `m` or `s` units (`90s`, `20m`, `1h30m`), or bare seconds.
+    pub fn timeout(&self) -> Option<Duration> {
+        parse_duration(self.0.strip_prefix(TIMEOUT_PREFIX)?)
+    }
+
     /// `true` when the label is exactly `loom:clarify`.
     pub fn is_clarify(&self) -> bool {
         self.0 == CLARIFY
@@ -48,6 +87,31 @@ impl Label {
     }
 }
 
+/// `90s` / `20m` / `1h30m` / `45` → [`Duration`]; `None` on anything else,
+/// including an empty string or a zero duration.
+fn parse_duration(s: &str) -> Option<Duration> {
+    if let Ok(secs) = s.parse::<u64>() {
+        return (secs > 0).then(|| Duration::from_secs(secs));
+    }
+    let mut total = 0u64;
+    let mut digits = String::new();
+    for c in s.chars() {
+        if c.is_ascii_digit() {
+            digits.push(c);
+            continue;
+        }
+        let unit = match c {
+            'h' => 3600,
+            'm' => 60,
+            's' => 1,
+            _ => return None,
+        };
+        let n: u64 = std::mem::take(&mut digits).parse().ok()?;
+        total = total.checked_add(n.checked_mul(unit)?)?;
+    }
+    (digits.is_empty() && total > 0).then(|| Duration::from_secs(total))
+}
+
 impl ::std::fmt::Display for Label {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         f.write_str(&self.0)
@@ -84,6 +148,68 @@ mod tests {
         assert!(!Label::new("loom:clarify").is_active());
     }
 
+    #[test]
+    fn override_families_parse_typed_values() {
+        assert_eq!(
+            Label::new("model:anthropic/claude-opus").model(),
+            Some(ModelSelection {
+                provider: "anthropic".into(),
+                model_id: "claude-opus".into(),
+            })
+        );
+        assert_eq!(Label::new("agent:pi").agent_kind(), Some(AgentKind::Pi));
+        assert_eq!(
+            Label::new("agent:claude").agent_kind(),
+            Some(AgentKind::Claude)
+        );
+        assert_eq!(Label::new("retries:4").retries(), Some(4));
+        assert_eq!(
+            Label::new("timeout:1h30m").timeout(),
+            Some(Duration::from_secs(5400))
+        );
+        assert_eq!(
+            Label::new("timeout:90s").timeout(),
+            Some(Duration::from_secs(90))
+        );
+        assert_eq!(
+            Label::new("timeout:600").timeout(),
+            Some(Duration::from_secs(600))
+        );
+        assert!(
+            Label::new("model:anthropic/claude-opus")
+                .profile_name()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn agent_mock_label_follows_the_mock_feature() {
+        let kind = Label::new("agent:mock").agent_kind();
+        #[cfg(feature = "mock")]
+        assert_eq!(kind, Some(AgentKind::Mock));
+        #[cfg(not(feature = "mock"))]
+        assert_eq!(kind, None);
+    }
+
+    #[test]
+    fn malformed_override_values_yield_none() {
+        for label in ["model:opus", "model:/opus", "model:anthropic/"] {
+            assert!(Label::new(label).model().is_none(), "{label}");
+        }
+        assert!(Label::new("agent:gpt").agent_kind().is_none());
+        assert!(Label::new("retries:-1").retries().is_none());
+        assert!(Label::new("retries:many").retries().is_none());
+        for label in [
+            "timeout:",
+            "timeout:0",
+            "timeout:5d",
+            "timeout:m",
+            "timeout:1h30",
+        ] {
+            assert!(Label::new(label).timeout().is_none(), "{label}");
+        }
+    }
+
     #[test]
     fn unrecognised_label_yields_no_typed_value() {
         let l = Label::new("urgent");
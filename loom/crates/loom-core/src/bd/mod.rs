@@ -4,19 +4,33 @@
 //! passed through `.arg()` — never shell interpolation — and parses
 //! `--json` output into typed Rust structs. Subprocess execution flows
 //! through the [`CommandRunner`] trait so unit tests can substitute a
-//! capturing fake without spawning a real binary.
+//! capturing fake without spawning a real binary. [`FakeBd`] is the
+//! full-featured one: an in-memory bead graph that interprets the same argv,
+//! selected at runtime through [`BdBackend`] (`loom --bd-backend=fake`).
+//! With `[beads] backend = "sql"`, the hot reads skip the subprocess and
+//! query the Dolt server directly through [`SqlReader`].
 //!
 //! `BdError` lives in this module (NF-5: nested module structure with
 //! per-module error enums).
 
+mod backend;
+mod cache;
 mod client;
 mod error;
+mod fake;
 mod label;
 mod models;
 mod runner;
+mod sql;
 
-pub use client::{BdClient, CreateOpts, ListOpts, ReadyOpts, UpdateOpts};
+pub use backend::BdBackend;
+pub use cache::BdStats;
+pub use client::{BdClient, CreateOpts, DEFAULT_READ_CACHE_TTL, ListOpts, ReadyOpts, UpdateOpts};
 pub use error::BdError;
+pub use fake::{FAKE_BD_STORE, FakeBd};
 pub use label::Label;
-pub use models::{Bead, MolProgress, Molecule};
+pub use models::{Bead, Dependency, MolProgress, Molecule};
+#[cfg(any(test, feature = "test-util"))]
+pub use runner::ScriptedRunner;
 pub use runner::{CommandRunner, RunOutput, TokioRunner};
+pub use sql::SqlReader;
@@ -5,10 +5,10 @@ use crate::identifier::{BeadId, MoleculeId};
 
 /// One bead as produced by `bd show --json` and `bd list --json`.
 ///
-/// `bd` emits more fields than these (timestamps, owner, dependency lists,
-/// metadata blobs); they are intentionally not modelled here. `serde`
-/// ignores unknown fields by default, so the wrapper does not break when
-/// `bd` adds new keys. Add fields when a caller needs them.
+/// `bd` emits more fields than these (owner, metadata blobs); they are
+/// intentionally not modelled here. `serde` ignores unknown fields by
+/// default, so the wrapper does not break when `bd` adds new keys. Add
+/// fields when a caller needs them.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Bead {
     pub id: BeadId,
@@ -22,6 +22,49 @@ pub struct Bead {
     pub issue_type: String,
     #[serde(default)]
     pub labels: Vec<Label>,
+    /// RFC 3339 creation timestamp as emitted by `bd`. Kept as the raw
+    /// string; parse with [`crate::logging::parse_rfc3339`] when an instant
+    /// is needed.
+    #[serde(default)]
+    pub created_at: Option<String>,
+    /// RFC 3339 timestamp of the last mutation (label, status, notes).
+    #[serde(default)]
+    pub updated_at: Option<String>,
+    /// RFC 3339 timestamp the bead was closed; `None` while it is open.
+    #[serde(default)]
+    pub closed_at: Option<String>,
+    /// Free-form notes (`bd update --notes` / `--append-notes`). Empty when
+    /// none were written.
+    #[serde(default)]
+    pub notes: String,
+    #[serde(default)]
+    pub assignee: Option<String>,
+    /// Parent epic for hierarchical ids (`wx-abc.3` → `wx-abc`).
+    #[serde(default)]
+    pub parent: Option<BeadId>,
+    /// Outgoing dependency edges. `bd show` inlines them; `bd list` leaves
+    /// them out, so an empty list from a listing means "not fetched".
+    #[serde(default)]
+    pub dependencies: Vec<Dependency>,
+}
+
+/// One dependency edge as `bd` reports it.
+///
+/// `bd show --json` inlines the related bead (its
+/// `id`, `title`, `status`) plus a `dependency_type`; older `bd` builds emit
+/// raw edges keyed by `depends_on_id` and `type`. Both shapes decode here —
+/// `id` is always the bead on the far side of the edge.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Dependency {
+    #[serde(alias = "depends_on_id")]
+    pub id: BeadId,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub status: String,
+    /// Edge kind: `blocks`, `parent-child`, `related`, `discovered-from`, …
+    #[serde(default, rename = "dependency_type", alias = "type")]
+    pub kind: String,
 }
 
 /// One molecule row. Beads exposes `bd mol show --json`; the shape is the
@@ -49,3 +92,43 @@ pub struct MolProgress {
     #[serde(default)]
     pub current_step_id: Option<String>,
 }
+
+impl MolProgress {
+    /// Progress over a molecule's children, given each child's `(id,
+    /// status)` in order — what `bd mol progress` reports for readers that
+    /// see the graph directly. The current step is the first child in
+    /// progress.
+    pub(super) fn tally<'a>(
+        molecule_id: MoleculeId,
+        molecule_title: String,
+        children: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Self {
+        let (mut completed, mut in_progress, mut total) = (0u32, 0u32, 0u32);
+        let mut current_step_id = None;
+        for (id, status) in children {
+            total += 1;
+            match status {
+                "closed" => completed += 1,
+                "in_progress" => {
+                    in_progress += 1;
+                    current_step_id.get_or_insert_with(|| id.to_string());
+                }
+                _ => {}
+            }
+        }
+        let percent = if total == 0 {
+            0.0
+        } else {
+            f64::from(completed) * 100.0 / f64::from(total)
+        };
+        Self {
+            molecule_id,
+            molecule_title,
+            completed,
+            in_progress,
+            total,
+            percent,
+            current_step_id,
+        }
+    }
+}
@@ -67,3 +67,71 @@ pub(super) fn render_args(args: &[OsString]) -> String {
         .collect::<Vec<_>>()
         .join(" ")
 }
+
+/// Test runner: answers `bd` calls with canned stdout, one per call in
+/// order, and records every argv. Once the script runs out, calls fail
+/// with exit 1 unless [`always`](Self::always) set a standing answer.
+///
+/// For tests that only need `bd`'s output; [`FakeBd`](super::FakeBd) is
+/// the one to reach for when the code under test writes back.
+#[cfg(any(test, feature = "test-util"))]
+#[derive(Debug, Default)]
+pub struct ScriptedRunner {
+    responses: std::sync::Mutex<std::collections::VecDeque<RunOutput>>,
+    standing: Option<RunOutput>,
+    calls: std::sync::Mutex<Vec<Vec<OsString>>>,
+}
+
+#[cfg(any(test, feature = "test-util"))]
+impl ScriptedRunner {
+    /// Answer the first call with `stdouts[0]`, the next with `stdouts[1]`,
+    /// and so on.
+    pub fn new(stdouts: &[&str]) -> Self {
+        Self {
+            responses: std::sync::Mutex::new(stdouts.iter().map(|s| ok(s)).collect()),
+            ..Self::default()
+        }
+    }
+
+    /// Answer every call with `stdout`.
+    pub fn always(stdout: &str) -> Self {
+        Self {
+            standing: Some(ok(stdout)),
+            ..Self::default()
+        }
+    }
+
+    /// Every argv seen so far, in call order.
+    pub fn calls(&self) -> Vec<Vec<OsString>> {
+        self.calls.lock().unwrap_or_else(|p| p.into_inner()).clone()
+    }
+}
+
+#[cfg(any(test, feature = "test-util"))]
+fn ok(stdout: &str) -> RunOutput {
+    RunOutput {
+        status: 0,
+        stdout: stdout.as_bytes().to_vec(),
+        stderr: Vec::new(),
+    }
+}
+
+#[cfg(any(test, feature = "test-util"))]
+impl CommandRunner for ScriptedRunner {
+    async fn run(&self, args: Vec<OsString>, _t: Duration) -> Result<RunOutput, BdError> {
+        self.calls
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .push(args);
+        let next = self
+            .responses
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .pop_front();
+        Ok(next.or_else(|| self.standing.clone()).unwrap_or(RunOutput {
+            status: 1,
+            stdout: Vec::new(),
+            stderr: b"bd: no scripted response".to_vec(),
+        }))
+    }
+}
@@ -0,0 +1,364 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use mysql_async::prelude::Queryable;
+use mysql_async::{Opts, OptsBuilder, Pool, Row, Value};
+
+use crate::identifier::{BeadId, MoleculeId};
+
+use super::client::{ListOpts, ReadyOpts};
+use super::error::BdError;
+use super::label::Label;
+use super::models::{Bead, Dependency, MolProgress};
+
+/// Dolt's default account; the `wrapix-beads` server runs without a
+/// password behind its socket.
+const DOLT_USER: &str = "root";
+
+/// Columns of one [`BeadRow`], in tuple order. DATETIMEs come back in the
+/// RFC 3339 UTC form `bd --json` prints.
+const BEAD_COLUMNS: &str = "i.id, i.title, COALESCE(i.description, ''), i.status, i.priority, \
+     COALESCE(i.issue_type, ''), i.assignee, \
+     DATE_FORMAT(i.created_at, '%Y-%m-%dT%H:%i:%sZ'), \
+     DATE_FORMAT(i.updated_at, '%Y-%m-%dT%H:%i:%sZ'), \
+     DATE_FORMAT(i.closed_at, '%Y-%m-%dT%H:%i:%sZ'), \
+     COALESCE(i.notes, '')";
+
+/// `(id, title, description, status, priority, issue_type, assignee,
+/// created_at, updated_at, closed_at, notes)`.
+type BeadRow = (
+    String,
+    String,
+    String,
+    String,
+    i64,
+    String,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    String,
+);
+
+/// `(issue_id, depends_on_id, type, target title, target status)`.
+type EdgeRow = (String, String, String, String, String);
+
+/// Read-only view of the beads database straight over the Dolt SQL server
+/// (MySQL wire protocol), bypassing a `bd` subprocess per read.
+///
+/// Covers the hot read paths — [`show`](Self::show), [`list`](Self::list),
+/// [`ready`](Self::ready), [`mol_progress`](Self::mol_progress) — with the
+/// same filters and result shapes as the CLI, so a
+/// [`BdClient`](super::BdClient) can swap it in via
+/// [`with_sql_reads`](super::BdClient::with_sql_reads) while writes keep
+/// going through `bd`, which owns the schema's invariants. Connections are
+/// pooled and opened lazily on the first query.
+#[derive(Debug, Clone)]
+pub struct SqlReader {
+    pool: Pool,
+}
+
+impl SqlReader {
+    /// Reader over the Dolt server listening on the unix socket at `path`
+    /// (the bind-mounted `.wrapix/dolt.sock`), using `database`.
+    pub fn socket(path: &Path, database: &str) -> Self {
+        let opts = OptsBuilder::default()
+            .socket(Some(path.to_string_lossy()))
+            .user(Some(DOLT_USER))
+            .db_name(Some(database));
+        Self {
+            pool: Pool::new(opts),
+        }
+    }
+
+    /// Reader from a `mysql://user@host:port/database` URL — a local
+    /// `dolt sql-server` in tests.
+    pub fn from_url(url: &str) -> Result<Self, BdError> {
+        let opts = Opts::from_url(url).map_err(BdError::SqlUrl)?;
+        Ok(Self {
+            pool: Pool::new(opts),
+        })
+    }
+
+    /// `bd show <id>`: the bead with labels, parent and dependency edges.
+    pub async fn show(&self, id: &BeadId) -> Result<Bead, BdError> {
+        let query = format!("SELECT {BEAD_COLUMNS} FROM issues i WHERE i.id = ?");
+        let rows = self
+            .rows("show", &query, vec![Value::from(id.as_str())])
+            .await?;
+        let mut beads = self.hydrate(rows, true).await?;
+        if beads.is_empty() {
+            return Err(BdError::ShowEmpty);
+        }
+        Ok(beads.remove(0))
+    }
+
+    /// `bd list`: non-closed beads unless `status` asks otherwise, with
+    /// labels and parent but no dependency edges (as the CLI prints them).
+    pub async fn list(&self, opts: &ListOpts) -> Result<Vec<Bead>, BdError> {
+        let mut query = format!("SELECT {BEAD_COLUMNS} FROM issues i WHERE ");
+        let mut params = Vec::new();
+        match &opts.status {
+            Some(status) => {
+                query.push_str("i.status = ?");
+                params.push(Value::from(status.as_str()));
+            }
+            None => query.push_str("i.status NOT IN ('closed', 'tombstone')"),
+        }
+        push_label_filter(&mut query, &mut params, opts.label.as_deref());
+        query.push_str(" ORDER BY i.created_at, i.id");
+        let rows = self.rows("list", &query, params).await?;
+        self.hydrate(rows, false).await
+    }
+
+    /// `bd ready`: open, non-epic beads with no unclosed `blocks`
+    /// dependency, highest priority first.
+    pub async fn ready(&self, opts: &ReadyOpts) -> Result<Vec<Bead>, BdError> {
+        let mut query = format!(
+            "SELECT {BEAD_COLUMNS} FROM issues i \
+             WHERE i.status = 'open' AND COALESCE(i.issue_type, '') <> 'epic' \
+             AND NOT EXISTS (SELECT 1 FROM dependencies d \
+             JOIN issues b ON b.id = d.depends_on_id \
+             WHERE d.issue_id = i.id AND d.type = 'blocks' AND b.status <> 'closed')"
+        );
+        let mut params = Vec::new();
+        push_label_filter(&mut query, &mut params, opts.label.as_deref());
+        query.push_str(" ORDER BY i.priority, i.created_at, i.id");
+        if let Some(limit) = opts.limit {
+            query.push_str(&format!(" LIMIT {limit}"));
+        }
+        let rows = self.rows("ready", &query, params).await?;
+        self.hydrate(rows, false).await
+    }
+
+    /// `bd mol progress <id>` over the root's `parent-child` children.
+    pub async fn mol_progress(&self, id: &MoleculeId) -> Result<MolProgress, BdError> {
+        let titles: Vec<(String,)> = self
+            .rows(
+                "mol progress",
+                "SELECT title FROM issues WHERE id = ?",
+                vec![Value::from(id.as_str())],
+            )
+            .await?;
+        let Some((title,)) = titles.into_iter().next() else {
+            return Err(BdError::ShowEmpty);
+        };
+        let children: Vec<(String, String)> = self
+            .rows(
+                "mol progress",
+                "SELECT i.id, i.status FROM issues i \
+                 JOIN dependencies d ON d.issue_id = i.id \
+                 WHERE d.depends_on_id = ? AND d.type = 'parent-child' \
+                 ORDER BY i.created_at, i.id",
+                vec![Value::from(id.as_str())],
+            )
+            .await?;
+        Ok(MolProgress::tally(
+            id.clone(),
+            title,
+            children
+                .iter()
+                .map(|(id, status)| (id.as_str(), status.as_str())),
+        ))
+    }
+
+    /// Fetch the labels (and, for `show`, the edges) of `rows` and assemble
+    /// the beads in row order.
+    async fn hydrate(&self, rows: Vec<BeadRow>, with_deps: bool) -> Result<Vec<Bead>, BdError> {
+        if rows.is_empty() {
+            return Ok(Vec::new());
+        }
+        let ids: Vec<Value> = rows.iter().map(|r| Value::from(r.0.as_str())).collect();
+        let marks = vec!["?"; ids.len()].join(", ");
+        let labels: Vec<(String, String)> = self
+            .rows(
+                "labels",
+                &format!(
+                    "SELECT issue_id, label FROM labels WHERE issue_id IN ({marks}) \
+                     ORDER BY issue_id, label"
+                ),
+                ids.clone(),
+            )
+            .await?;
+        let edges: Vec<EdgeRow> = self
+            .rows(
+                "dependencies",
+                &format!(
+                    "SELECT d.issue_id, d.depends_on_id, d.type, \
+                     COALESCE(t.title, ''), COALESCE(t.status, '') \
+                     FROM dependencies d LEFT JOIN issues t ON t.id = d.depends_on_id \
+                     WHERE d.issue_id IN ({marks}) ORDER BY d.issue_id, d.created_at"
+                ),
+                ids,
+            )
+            .await?;
+        assemble(rows, labels, edges, with_deps)
+    }
+
+    async fn rows<T: mysql_async::prelude::FromRow>(
+        &self,
+        what: &'static str,
+        query: &str,
+        params: Vec<Value>,
+    ) -> Result<Vec<T>, BdError> {
+        let sql = |source| BdError::Sql { what, source };
+        let mut conn = self.pool.get_conn().await.map_err(sql)?;
+        let rows: Vec<Row> = conn.exec(query, params).await.map_err(sql)?;
+        rows.into_iter()
+            .map(|row| {
+                mysql_async::from_row_opt(row).map_err(|e| BdError::SqlRow {
+                    what,
+                    detail: e.to_string(),
+                })
+            })
+            .collect()
+    }
+}
+
+fn push_label_filter(query: &mut String, params: &mut Vec<Value>, label: Option<&str>) {
+    if let Some(label) = label {
+        query.push_str(
+            " AND EXISTS (SELECT 1 FROM labels l WHERE l.issue_id = i.id AND l.label = ?)",
+        );
+        params.push(Value::from(label));
+    }
+}
+
+/// Join issue rows with their label and edge rows. `parent` comes from the
+/// `parent-child` edge; the edge list itself is kept only for `show`.
+fn assemble(
+    rows: Vec<BeadRow>,
+    labels: Vec<(String, String)>,
+    edges: Vec<EdgeRow>,
+    with_deps: bool,
+) -> Result<Vec<Bead>, BdError> {
+    let mut labels_by: HashMap<String, Vec<Label>> = HashMap::new();
+    for (issue, label) in labels {
+        labels_by.entry(issue).or_default().push(Label::new(label));
+    }
+    let mut edges_by: HashMap<String, Vec<Dependency>> = HashMap::new();
+    for (issue, target, kind, title, status) in edges {
+        edges_by.entry(issue).or_default().push(Dependency {
+            id: parse_id(&target)?,
+            title,
+            status,
+            kind,
+        });
+    }
+    rows.into_iter()
+        .map(|row| {
+            let (id, title, description, status, priority, issue_type, assignee) =
+                (row.0, row.1, row.2, row.3, row.4, row.5, row.6);
+            let dependencies = edges_by.remove(&id).unwrap_or_default();
+            let parent = dependencies
+                .iter()
+                .find(|d| d.kind == "parent-child")
+                .map(|d| d.id.clone());
+            Ok(Bead {
+                id: parse_id(&id)?,
+                title,
+                description,
+                status,
+                priority: u8::try_from(priority).map_err(|_| BdError::SqlRow {
+                    what: "issues",
+                    detail: format!("priority {priority} out of range for {id}"),
+                })?,
+                issue_type,
+                labels: labels_by.remove(&id).unwrap_or_default(),
+                created_at: row.7,
+                updated_at: row.8,
+                closed_at: row.9,
+                notes: row.10,
+                assignee,
+                parent,
+                dependencies: if with_deps { dependencies } else { Vec::new() },
+            })
+        })
+        .collect()
+}
+
+fn parse_id(raw: &str) -> Result<BeadId, BdError> {
+    BeadId::new(raw).map_err(|e| BdError::SqlRow {
+        what: "issues",
+        detail: e.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    fn row(id: &str, title: &str) -> BeadRow {
+        (
+            id.into(),
+            title.into(),
+            String::new(),
+            "open".into(),
+            1,
+            "task".into(),
+            None,
+            Some("2026-01-02T03:04:05Z".into()),
+            None,
+            None,
+            String::new(),
+        )
+    }
+
+    #[test]
+    fn assemble_joins_labels_parent_and_edges() -> Result<()> {
+        let rows = vec![row("wx-1.1", "child"), row("wx-2", "lone")];
+        let labels = vec![
+            ("wx-1.1".into(), "spec:alpha".into()),
+            ("wx-1.1".into(), "loom:clarify".into()),
+        ];
+        let edges = vec![
+            (
+                "wx-1.1".into(),
+                "wx-1".into(),
+                "parent-child".into(),
+                "epic".into(),
+                "open".into(),
+            ),
+            (
+                "wx-1.1".into(),
+                "wx-2".into(),
+                "blocks".into(),
+                "lone".into(),
+                "open".into(),
+            ),
+        ];
+
+        let beads = assemble(rows.clone(), labels.clone(), edges.clone(), true)?;
+        assert_eq!(beads.len(), 2);
+        let child = &beads[0];
+        assert_eq!(
+            child.labels,
+            [Label::new("spec:alpha"), Label::new("loom:clarify")]
+        );
+        assert_eq!(child.parent, Some(BeadId::new("wx-1")?));
+        assert_eq!(child.dependencies.len(), 2);
+        assert_eq!(child.dependencies[1].title, "lone");
+        assert_eq!(child.created_at.as_deref(), Some("2026-01-02T03:04:05Z"));
+        assert!(beads[1].labels.is_empty());
+
+        let listed = assemble(rows, labels, edges, false)?;
+        assert!(listed[0].dependencies.is_empty());
+        assert_eq!(listed[0].parent, Some(BeadId::new("wx-1")?));
+        Ok(())
+    }
+
+    #[test]
+    fn assemble_rejects_malformed_rows() {
+        let mut bad = row("wx-1", "t");
+        bad.4 = 900;
+        assert!(matches!(
+            assemble(vec![bad], vec![], vec![], false),
+            Err(BdError::SqlRow { .. })
+        ));
+        assert!(matches!(
+            assemble(vec![row("Not An Id", "t")], vec![], vec![], false),
+            Err(BdError::SqlRow { .. })
+        ));
+    }
+}
@@ -4,7 +4,12 @@ use displaydoc::Display;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::agent::AgentKind;
+use tracing::warn;
+
+use crate::agent::{
+    AgentKind, CommandProtocol, ModelSelection, SpawnConfig, ThinkingLevel, ToolPolicy,
+    ToolPolicyError,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
 #[serde(default)]
@@ -33,6 +38,10 @@ pub struct PhaseOverride {
     pub backend: Option<String>,
     pub provider: Option<String>,
     pub model_id: Option<String>,
+    /// Reasoning effort: `low`, `medium` or `high`.
+    pub thinking: Option<ThinkingLevel>,
+    /// Cap on agent turns per session (claude `--max-turns`).
+    pub max_turns: Option<u32>,
 }
 
 /// Workflow phase that resolves an [`AgentSelection`] from config.
@@ -69,34 +78,170 @@ impl Phase {
 /// [`AgentKind::Claude`]. Pi has no analog (no host-side permission flow).
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ClaudeSettings {
-    /// Tool names denied at host-side `control_request` time. Sourced from
-    /// `[security] denied_tools`.
-    pub denied_tools: Vec<String>,
+    /// Rules applied at host-side `control_request` time. Sourced from
+    /// `[security]` for the selected phase.
+    pub tool_policy: ToolPolicy,
     /// Seconds to wait for clean exit after `result` before SIGTERM. Sourced
     /// from `[claude] post_result_grace_secs`.
     pub post_result_grace_secs: u32,
 }
 
+/// A `[backends.<name>]` command agent surfaced through
+/// [`AgentSelection::command`] when the resolved backend is
+/// [`AgentKind::Command`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandSettings {
+    /// The `[backends.<name>]` key — also its `[prompt.backends]` key.
+    pub name: String,
+    pub argv: Vec<String>,
+    pub protocol: CommandProtocol,
+    /// The phase's `[security]` rules. Only `claude-stream-json` agents ask
+    /// permission, so only they are held to it.
+    pub tool_policy: ToolPolicy,
+}
+
+/// The claude invocation [`AgentSelection::apply_to`] puts at the head of
+/// `agent_args`, which `wrapix run-bead` runs as the container command:
+/// non-interactive, stream-json both ways, permission prompts over the same
+/// pipe. Claude refuses `--output-format stream-json` under `--print`
+/// without `--verbose`.
+pub const CLAUDE_ARGV: &[&str] = &[
+    "claude",
+    "--print",
+    "--verbose",
+    "--input-format",
+    "stream-json",
+    "--output-format",
+    "stream-json",
+    "--permission-prompt-tool",
+    "stdio",
+];
+
 /// Backend + per-phase model selection resolved by [`super::LoomConfig::agent_for`].
 ///
 /// `kind` carries the selected backend (after applying any phase override on
 /// top of `[agent] default`). `provider` / `model_id` hold the per-phase
-/// model override for the pi backend (`set_model { provider, modelId }`).
-/// `claude_settings` is populated only when `kind == Claude` so call sites
-/// can wire the post-result grace period and denied-tools list without a
-/// second config lookup.
+/// model override (pi `set_model { provider, modelId }`, claude `--model`);
+/// `thinking` / `max_turns` the per-phase model parameters. All four reach
+/// the session through [`Self::apply_to`]. `claude_settings` is populated
+/// only when `kind == Claude` so call sites can wire the post-result grace
+/// period and tool policy without a second config lookup; `command`
+/// only when `kind == Command`, for the same reason.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AgentSelection {
     pub kind: AgentKind,
     pub provider: Option<String>,
     pub model_id: Option<String>,
+    pub thinking: Option<ThinkingLevel>,
+    pub max_turns: Option<u32>,
     pub claude_settings: Option<ClaudeSettings>,
+    pub command: Option<CommandSettings>,
+}
+
+impl AgentSelection {
+    /// Switch to `model` — a `[loop] retry_models` rung or a bead's
+    /// `model:` label. Pi reads `provider/model_id`, keeping the phase's
+    /// provider when only a model is named; claude takes the model part as
+    /// its `--model`, since it has no provider to pick.
+    pub fn escalate(&mut self, model: &str) {
+        match (self.kind, model.split_once('/')) {
+            (AgentKind::Pi, Some((provider, model_id))) => {
+                self.provider = Some(provider.to_string());
+                self.model_id = Some(model_id.to_string());
+            }
+            (AgentKind::Claude, Some((_, model_id))) => self.model_id = Some(model_id.to_string()),
+            _ => self.model_id = Some(model.to_string()),
+        }
+    }
+
+    /// Carry the selected model parameters into `spawn` the way the
+    /// backend takes them:
+    ///
+    /// - claude: [`CLAUDE_ARGV`] ahead of any `agent_args`, followed by
+    ///   `--model <model_id>` and `--max-turns <n>`, the thinking level as a
+    ///   `MAX_THINKING_TOKENS` budget in `env`, and the phase's
+    ///   `tool_policy`;
+    /// - pi: `model` (when both `provider` and `model_id` are set) and
+    ///   `thinking` for the host-side RPCs. Pi has no turn cap, so
+    ///   `max_turns` is dropped with a warning rather than failing the
+    ///   session;
+    /// - command: the backend's argv ahead of any `agent_args`,
+    ///   `WRAPIX_AGENT=command`, the protocol for the host-side parser and
+    ///   the phase's `tool_policy`. Model parameters belong in the argv, so
+    ///   any set here are dropped with a warning.
+    pub fn apply_to(&self, spawn: &mut SpawnConfig) {
+        match self.kind {
+            AgentKind::Claude => {
+                spawn
+                    .agent_args
+                    .splice(0..0, CLAUDE_ARGV.iter().map(|arg| arg.to_string()));
+                if let Some(model_id) = &self.model_id {
+                    spawn
+                        .agent_args
+                        .extend(["--model".to_string(), model_id.clone()]);
+                }
+                if let Some(max_turns) = self.max_turns {
+                    spawn
+                        .agent_args
+                        .extend(["--max-turns".to_string(), max_turns.to_string()]);
+                }
+                if let Some(thinking) = self.thinking {
+                    spawn.env.push((
+                        "MAX_THINKING_TOKENS".to_string(),
+                        thinking.claude_budget_tokens().to_string(),
+                    ));
+                }
+                if let Some(settings) = &self.claude_settings {
+                    spawn.tool_policy = settings.tool_policy.clone();
+                }
+            }
+            AgentKind::Pi => {
+                match (&self.provider, &self.model_id) {
+                    (Some(provider), Some(model_id)) => {
+                        spawn.model = Some(ModelSelection {
+                            provider: provider.clone(),
+                            model_id: model_id.clone(),
+                        });
+                    }
+                    (None, Some(model_id)) => {
+                        warn!(%model_id, "pi needs a provider with model_id; keeping pi's model");
+                    }
+                    _ => {}
+                }
+                spawn.thinking = self.thinking;
+                if let Some(max_turns) = self.max_turns {
+                    warn!(max_turns, "pi has no turn cap; ignoring max_turns");
+                }
+            }
+            AgentKind::Command => {
+                if let Some(command) = &self.command {
+                    spawn.agent_args.splice(0..0, command.argv.iter().cloned());
+                    spawn
+                        .env
+                        .push(("WRAPIX_AGENT".to_string(), "command".to_string()));
+                    spawn.command_protocol = Some(command.protocol);
+                    spawn.tool_policy = command.tool_policy.clone();
+                }
+                if self.model_id.is_some() || self.thinking.is_some() || self.max_turns.is_some() {
+                    warn!("command backends take model parameters in their argv; ignoring them");
+                }
+            }
+            #[cfg(feature = "mock")]
+            AgentKind::Mock => {}
+        }
+    }
 }
 
 #[derive(Debug, Display, Error, PartialEq, Eq)]
 pub enum AgentSelectionError {
-    /// unknown agent backend `{name}` in config (expected `claude` or `pi`)
+    /// unknown agent backend `{name}` in config (expected `claude`, `pi` or a `[backends.<name>]` entry)
     UnknownBackend { name: String },
+
+    /// command backend `{name}` has an empty argv
+    EmptyArgv { name: String },
+
+    /// invalid `[[security.rules]]` entry: {0}
+    ToolPolicy(#[from] ToolPolicyError),
 }
 
 /// Convert a backend name string (from TOML `default` or `[agent.<phase>] backend`)
@@ -105,6 +250,8 @@ pub fn parse_backend_name(name: &str) -> Result<AgentKind, AgentSelectionError>
     match name {
         "claude" => Ok(AgentKind::Claude),
         "pi" => Ok(AgentKind::Pi),
+        #[cfg(feature = "mock")]
+        "mock" => Ok(AgentKind::Mock),
         other => Err(AgentSelectionError::UnknownBackend {
             name: other.to_string(),
         }),
@@ -119,6 +266,132 @@ pub fn parse_backend_name(name: &str) -> Result<AgentKind, AgentSelectionError>
 )]
 mod tests {
     use super::*;
+    use crate::agent::RePinContent;
+    use std::path::PathBuf;
+
+    fn selection(kind: AgentKind) -> AgentSelection {
+        AgentSelection {
+            kind,
+            provider: Some("deepseek".into()),
+            model_id: Some("deepseek-v3".into()),
+            thinking: Some(ThinkingLevel::High),
+            max_turns: Some(40),
+            claude_settings: None,
+            command: None,
+        }
+    }
+
+    fn spawn() -> SpawnConfig {
+        SpawnConfig {
+            image: "wrapix-base:latest".into(),
+            workspace: PathBuf::from("/workspace"),
+            env: vec![],
+            initial_prompt: String::new(),
+            agent_args: vec![],
+            repin: RePinContent {
+                orientation: String::new(),
+                pinned_context: String::new(),
+                partial_bodies: vec![],
+            },
+            model: None,
+            thinking: None,
+            command_protocol: None,
+            tool_policy: ToolPolicy::default(),
+        }
+    }
+
+    #[test]
+    fn apply_to_claude_sets_flags_and_thinking_budget() {
+        let mut cfg = spawn();
+        cfg.agent_args.push("--debug".into());
+        selection(AgentKind::Claude).apply_to(&mut cfg);
+        let (claude, rest) = cfg.agent_args.split_at(CLAUDE_ARGV.len());
+        assert_eq!(claude, CLAUDE_ARGV);
+        assert_eq!(
+            rest,
+            ["--debug", "--model", "deepseek-v3", "--max-turns", "40"]
+        );
+        assert_eq!(
+            cfg.env,
+            [("MAX_THINKING_TOKENS".to_string(), "31999".to_string())]
+        );
+        assert!(cfg.model.is_none() && cfg.thinking.is_none());
+        assert!(cfg.tool_policy.is_empty());
+    }
+
+    #[test]
+    fn apply_to_claude_carries_the_tool_policy() {
+        let policy = ToolPolicy::deny_tools(["WebFetch"]);
+        let mut sel = selection(AgentKind::Claude);
+        sel.claude_settings = Some(ClaudeSettings {
+            tool_policy: policy.clone(),
+            post_result_grace_secs: 5,
+        });
+        let mut cfg = spawn();
+        sel.apply_to(&mut cfg);
+        assert_eq!(cfg.tool_policy, policy);
+    }
+
+    #[test]
+    fn escalate_splits_pi_provider_and_drops_it_for_claude() {
+        let mut pi = selection(AgentKind::Pi);
+        pi.escalate("anthropic/claude-sonnet");
+        assert_eq!(pi.provider.as_deref(), Some("anthropic"));
+        assert_eq!(pi.model_id.as_deref(), Some("claude-sonnet"));
+        pi.escalate("deepseek-r1");
+        assert_eq!(pi.provider.as_deref(), Some("anthropic"));
+        assert_eq!(pi.model_id.as_deref(), Some("deepseek-r1"));
+
+        let mut claude = selection(AgentKind::Claude);
+        claude.escalate("opus");
+        assert_eq!(claude.model_id.as_deref(), Some("opus"));
+        claude.escalate("anthropic/claude-opus");
+        assert_eq!(claude.model_id.as_deref(), Some("claude-opus"));
+    }
+
+    #[test]
+    fn apply_to_pi_sets_model_and_thinking_rpcs_only() {
+        let mut cfg = spawn();
+        selection(AgentKind::Pi).apply_to(&mut cfg);
+        let model = cfg.model.unwrap();
+        assert_eq!(
+            (model.provider.as_str(), model.model_id.as_str()),
+            ("deepseek", "deepseek-v3")
+        );
+        assert_eq!(cfg.thinking, Some(ThinkingLevel::High));
+        assert!(cfg.agent_args.is_empty() && cfg.env.is_empty());
+
+        // Without a provider pi keeps its own model.
+        let mut cfg = spawn();
+        AgentSelection {
+            provider: None,
+            ..selection(AgentKind::Pi)
+        }
+        .apply_to(&mut cfg);
+        assert!(cfg.model.is_none());
+    }
+
+    #[test]
+    fn apply_to_command_prepends_argv_and_sets_protocol() {
+        let mut cfg = spawn();
+        cfg.agent_args.push("--verbose".into());
+        let mut command = selection(AgentKind::Command);
+        command.command = Some(CommandSettings {
+            name: "aider".into(),
+            argv: vec!["aider".into(), "--yes".into()],
+            protocol: CommandProtocol::PlainText,
+            tool_policy: ToolPolicy::deny_tools(["WebFetch"]),
+        });
+        command.apply_to(&mut cfg);
+        assert_eq!(cfg.agent_args, ["aider", "--yes", "--verbose"]);
+        assert_eq!(
+            cfg.env,
+            vec![("WRAPIX_AGENT".to_string(), "command".to_string())]
+        );
+        assert_eq!(cfg.command_protocol, Some(CommandProtocol::PlainText));
+        assert_eq!(cfg.tool_policy, ToolPolicy::deny_tools(["WebFetch"]));
+        assert!(cfg.model.is_none() && cfg.thinking.is_none());
+    }
 
     #[test]
     fn phase_round_trips_through_serde() {
@@ -0,0 +1,17 @@
+use serde::Deserialize;
+
+use crate::agent::CommandProtocol;
+
+/// One `[backends.<name>]` entry: a third-party coding agent that
+/// `wrapix run-bead` runs as the container command, selected by naming it
+/// wherever `claude` or `pi` would go (`[agent] default`,
+/// `[agent.<phase>] backend`).
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct CommandBackendConfig {
+    /// Program and arguments run inside the container.
+    pub argv: Vec<String>,
+    /// How the agent talks on stdin/stdout: `claude-stream-json`, `pi-rpc`
+    /// or `plain-text` (the default).
+    #[serde(default)]
+    pub protocol: CommandProtocol,
+}
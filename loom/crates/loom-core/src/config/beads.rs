@@ -5,6 +5,12 @@ use serde::Deserialize;
 pub struct BeadsConfig {
     pub priority: u8,
     pub default_type: String,
+    /// How loom reads beads. Writes always go through the `bd` CLI.
+    pub backend: BeadsBackend,
+    /// Dolt server socket for `backend = "sql"`, relative to the workspace.
+    pub socket: String,
+    /// Database on that server holding the beads tables.
+    pub database: String,
 }
 
 impl Default for BeadsConfig {
@@ -12,6 +18,21 @@ impl Default for BeadsConfig {
         Self {
             priority: 2,
             default_type: "task".to_string(),
+            backend: BeadsBackend::default(),
+            socket: ".wrapix/dolt.sock".to_string(),
+            database: "beads".to_string(),
         }
     }
 }
+
+/// `[beads] backend`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BeadsBackend {
+    /// Every read is a `bd ... --json` subprocess.
+    #[default]
+    Cli,
+    /// `show`, `list`, `ready` and `mol progress` query the Dolt server over
+    /// its socket instead.
+    Sql,
+}
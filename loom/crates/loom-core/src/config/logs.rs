@@ -5,10 +5,16 @@ use serde::Deserialize;
 pub struct LogsConfig {
     /// Days to retain `.wrapix/loom/logs/` files. `0` disables sweeping.
     pub retention_days: u32,
+    /// Also capture the raw agent stdio traffic of every session to
+    /// `<stem>.wire.jsonl` next to its `<stem>.ndjson` event log.
+    pub raw_wire: bool,
 }
 
 impl Default for LogsConfig {
     fn default() -> Self {
-        Self { retention_days: 14 }
+        Self {
+            retention_days: 14,
+            raw_wire: false,
+        }
     }
 }
@@ -6,6 +6,10 @@ pub struct LoopConfig {
     pub max_iterations: u32,
     pub max_retries: u32,
     pub max_reviews: u32,
+    /// Model escalation ladder for `loom run` retries: attempt `n` runs
+    /// `retry_models[n]`, staying on the last rung once the ladder runs
+    /// out. Empty keeps `[agent.run]`'s model for every attempt.
+    pub retry_models: Vec<String>,
 }
 
 impl Default for LoopConfig {
@@ -14,6 +18,7 @@ impl Default for LoopConfig {
             max_iterations: 3,
             max_retries: 2,
             max_reviews: 2,
+            retry_models: Vec::new(),
         }
     }
 }
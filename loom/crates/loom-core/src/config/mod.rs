@@ -6,26 +6,31 @@
 //! transition without writing a Loom config.
 
 mod agent;
+mod backends;
 mod beads;
 mod claude;
 mod error;
 mod exit_signals;
 mod logs;
 mod loop_config;
+mod prompt;
 mod security;
 
 pub use agent::{
-    AgentConfig, AgentSelection, AgentSelectionError, ClaudeSettings, Phase, PhaseOverride,
-    parse_backend_name,
+    AgentConfig, AgentSelection, AgentSelectionError, CLAUDE_ARGV, ClaudeSettings, CommandSettings,
+    Phase, PhaseOverride, parse_backend_name,
 };
-pub use beads::BeadsConfig;
+pub use backends::CommandBackendConfig;
+pub use beads::{BeadsBackend, BeadsConfig};
 pub use claude::ClaudeConfig;
 pub use error::LoomConfigError;
 pub use exit_signals::ExitSignalsConfig;
 pub use logs::LogsConfig;
 pub use loop_config::LoopConfig;
-pub use security::SecurityConfig;
+pub use prompt::PromptConfig;
+pub use security::{SecurityConfig, SecurityRule};
 
+use std::collections::BTreeMap;
 use std::path::Path;
 
 use serde::Deserialize;
@@ -40,6 +45,9 @@ pub struct LoomConfig {
     pub logs: LogsConfig,
     pub exit_signals: ExitSignalsConfig,
     pub agent: AgentConfig,
+    /// Command agents by name (`[backends.<name>]`).
+    pub backends: BTreeMap<String, CommandBackendConfig>,
+    pub prompt: PromptConfig,
     pub claude: ClaudeConfig,
     pub security: SecurityConfig,
 }
@@ -53,6 +61,8 @@ impl Default for LoomConfig {
             logs: LogsConfig::default(),
             exit_signals: ExitSignalsConfig::default(),
             agent: AgentConfig::default(),
+            backends: BTreeMap::new(),
+            prompt: PromptConfig::default(),
             claude: ClaudeConfig::default(),
             security: SecurityConfig::default(),
         }
@@ -69,36 +79,84 @@ impl LoomConfig {
     /// Resolve the [`AgentSelection`] for `phase`. The lookup applies the
     /// per-phase override on top of `[agent] default`; when the resolved
     /// backend is [`crate::agent::AgentKind::Claude`] the claude-specific
-    /// settings are pulled from `[claude]` and `[security]` so call sites
-    /// receive everything in one struct.
+    /// settings are pulled from `[claude]` and `[security]`, and when it
+    /// names a `[backends.<name>]` entry its argv and protocol land in
+    /// [`AgentSelection::command`], so call sites receive everything in one
+    /// struct.
     ///
     /// Returns [`AgentSelectionError::UnknownBackend`] when the backend name
-    /// (default or override) does not match `claude` or `pi` — surfacing the
-    /// validation lazily lets the TOML parser stay schema-free for unknown
-    /// `[agent.<phase>]` keys.
+    /// (default or override) is neither `claude`, `pi` nor a configured
+    /// command backend, and [`AgentSelectionError::EmptyArgv`] for a command
+    /// backend with nothing to run — surfacing the validation lazily lets
+    /// the TOML parser stay schema-free for unknown `[agent.<phase>]` keys.
     pub fn agent_for(&self, phase: Phase) -> Result<AgentSelection, AgentSelectionError> {
         let override_ = self.agent.overrides.get(phase.as_str());
         let backend_name = override_
             .and_then(|o| o.backend.as_deref())
             .unwrap_or(&self.agent.default);
-        let kind = parse_backend_name(backend_name)?;
+        let (kind, command) = match parse_backend_name(backend_name) {
+            Ok(kind) => (kind, None),
+            Err(unknown) => {
+                let backend = self.backends.get(backend_name).ok_or(unknown)?;
+                if backend.argv.is_empty() {
+                    return Err(AgentSelectionError::EmptyArgv {
+                        name: backend_name.to_string(),
+                    });
+                }
+                let tool_policy = self.security.policy_for(phase);
+                tool_policy.compile()?;
+                let command = CommandSettings {
+                    name: backend_name.to_string(),
+                    argv: backend.argv.clone(),
+                    protocol: backend.protocol,
+                    tool_policy,
+                };
+                (crate::agent::AgentKind::Command, Some(command))
+            }
+        };
         let provider = override_.and_then(|o| o.provider.clone());
         let model_id = override_.and_then(|o| o.model_id.clone());
+        let thinking = override_.and_then(|o| o.thinking);
+        let max_turns = override_.and_then(|o| o.max_turns);
         let claude_settings = match kind {
-            crate::agent::AgentKind::Claude => Some(ClaudeSettings {
-                denied_tools: self.security.denied_tools.clone(),
-                post_result_grace_secs: self.claude.post_result_grace_secs,
-            }),
-            crate::agent::AgentKind::Pi => None,
+            crate::agent::AgentKind::Claude => Some(self.claude_settings(phase)?),
+            crate::agent::AgentKind::Pi | crate::agent::AgentKind::Command => None,
+            #[cfg(feature = "mock")]
+            crate::agent::AgentKind::Mock => None,
         };
         Ok(AgentSelection {
             kind,
             provider,
             model_id,
+            thinking,
+            max_turns,
             claude_settings,
+            command,
+        })
+    }
+
+    /// The [`ClaudeSettings`] for a `phase` session, from `[claude]` and
+    /// `[security]`. Returns [`AgentSelectionError::ToolPolicy`] when a
+    /// `[[security.rules]]` glob or regex does not compile.
+    pub fn claude_settings(&self, phase: Phase) -> Result<ClaudeSettings, AgentSelectionError> {
+        let tool_policy = self.security.policy_for(phase);
+        tool_policy.compile()?;
+        Ok(ClaudeSettings {
+            tool_policy,
+            post_result_grace_secs: self.claude.post_result_grace_secs,
         })
     }
 
+    /// Estimated-token budget for one `phase` prompt under the backend and
+    /// model `phase` resolves to. An unknown backend name falls back to
+    /// `[prompt] max_tokens`; [`Self::agent_for`] reports that error where
+    /// the session is actually spawned.
+    pub fn prompt_budget(&self, phase: Phase) -> usize {
+        self.agent_for(phase)
+            .map(|agent| self.prompt.max_tokens_for(&agent))
+            .unwrap_or(self.prompt.max_tokens)
+    }
+
     /// Load a config from disk. A missing file yields the default config so
     /// `.wrapix/loom/config.toml` is optional.
     pub fn load(path: impl AsRef<Path>) -> Result<Self, LoomConfigError> {
@@ -122,6 +180,7 @@ impl LoomConfig {
 )]
 mod tests {
     use super::*;
+    use crate::agent::ToolAction;
     use anyhow::Result;
 
     /// The example TOML reproduced verbatim from the Configuration section of
@@ -132,16 +191,30 @@ mod tests {
 [beads]
 priority = 2
 default_type = "task"
+# How loom reads beads: "cli" runs `bd` per read; "sql" queries the Dolt
+# server on `socket` directly for show/list/ready/mol progress. Writes always
+# go through `bd`.
+backend = "cli"
+socket = ".wrapix/dolt.sock"
+database = "beads"
 
 [loop]
 max_iterations = 3
 max_retries = 2
 max_reviews = 2
+# Model escalation for `loom run` retries: attempt n runs retry_models[n],
+# staying on the last entry. Pi entries may be "provider/model_id".
+# retry_models = ["haiku", "sonnet", "opus"]
 
 [logs]
 # Delete log files under .wrapix/loom/logs/ older than this many days on
 # `loom run` startup. 0 disables sweeping (keep forever).
 retention_days = 14
+# Also write the raw agent stdin/stdout lines (claude stream-json / pi RPC,
+# including control_response replies) to <stem>.wire.jsonl next to each
+# session's <stem>.ndjson event log. Off by default; enable to diagnose
+# protocol drift.
+raw_wire = false
 
 [exit_signals]
 complete = "LOOM_COMPLETE"
@@ -159,6 +232,29 @@ default = "claude"
 #
 # [agent.check]
 # backend = "claude"
+# model_id = "opus"             # claude --model
+# thinking = "high"             # low | medium | high
+# max_turns = 40                # claude --max-turns (pi ignores it)
+
+# Command backends: other coding agents run inside `wrapix run-bead`, picked
+# by name wherever "claude" or "pi" would go (e.g. [agent.run] backend =
+# "aider"). protocol = "claude-stream-json" | "pi-rpc" | "plain-text";
+# plain-text sends the prompt on stdin, then closes it, and reads stdout
+# lines as the agent's message until it exits. Model flags belong in argv.
+# [backends.aider]
+# argv = ["aider", "--yes-always", "--no-pretty", "--message-file", "/dev/stdin"]
+# protocol = "plain-text"
+
+[prompt]
+# Estimated-token budget (~4 chars per token) for one rendered prompt. Over
+# budget, loom truncates the injected fields each template ranks lowest
+# (spec diffs, task lists, pinned context, ...) and marks the cut.
+max_tokens = 50000
+# Per-backend and per-model budgets; a model_id entry wins over its backend.
+# [prompt.backends]
+# pi = 32000
+# [prompt.models]
+# "deepseek-v3" = 24000
 
 [claude]
 # Seconds to wait for clean exit after `result` before SIGTERM.
@@ -170,6 +266,23 @@ post_result_grace_secs = 5
 # control_request analog). Empty by default — the container sandbox is the
 # trust boundary.
 # denied_tools = ["SomeNewHostTool"]
+#
+# Allow/deny rules, tried in order after denied_tools; the first match
+# decides and anything unmatched is approved. A rule matches when every
+# matcher it sets does: `tools` (names; empty = any), `path` (glob over the
+# file the call touches; relative globs match at any depth), `command`
+# (Bash command prefix), `command_regex`. `phases` limits it to those
+# phases; `reason` is returned to the agent on deny.
+# [[security.rules]]
+# action = "deny"
+# tools = ["Edit", "Write", "MultiEdit", "NotebookEdit"]
+# phases = ["check"]
+# reason = "check is read-only"
+#
+# [[security.rules]]
+# action = "deny"
+# path = ".github/**"
+# reason = "never touch .github/"
 "#;
 
     #[test]
@@ -200,10 +313,12 @@ max_retries = 5
         // Other [loop] fields fall back to defaults.
         assert_eq!(cfg.loop_.max_iterations, 3);
         assert_eq!(cfg.loop_.max_reviews, 2);
+        assert!(cfg.loop_.retry_models.is_empty());
         // Whole sections that are absent stay at defaults.
         assert_eq!(cfg.beads, BeadsConfig::default());
         assert_eq!(cfg.exit_signals, ExitSignalsConfig::default());
         assert_eq!(cfg.agent, AgentConfig::default());
+        assert_eq!(cfg.prompt, PromptConfig::default());
         assert_eq!(cfg.claude, ClaudeConfig::default());
         assert_eq!(cfg.security, SecurityConfig::default());
         Ok(())
@@ -236,6 +351,48 @@ backend = "claude"
         Ok(())
     }
 
+    #[test]
+    fn beads_backend_parses_and_rejects_unknown_values() -> Result<()> {
+        let cfg = LoomConfig::from_toml_str("[beads]\nbackend = \"sql\"\n")?;
+        assert_eq!(cfg.beads.backend, BeadsBackend::Sql);
+        assert_eq!(cfg.beads.socket, ".wrapix/dolt.sock");
+        assert!(matches!(
+            LoomConfig::from_toml_str("[beads]\nbackend = \"grpc\"\n"),
+            Err(LoomConfigError::Parse(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn prompt_budget_prefers_model_then_backend_then_default() -> Result<()> {
+        let src = r#"
+[agent]
+default = "claude"
+
+[agent.todo]
+backend = "pi"
+model_id = "deepseek-v3"
+
+[agent.check]
+backend = "pi"
+
+[prompt]
+max_tokens = 40000
+
+[prompt.backends]
+pi = 30000
+
+[prompt.models]
+"deepseek-v3" = 20000
+"#;
+        let cfg = LoomConfig::from_toml_str(src)?;
+        assert_eq!(cfg.prompt_budget(Phase::Todo), 20_000);
+        assert_eq!(cfg.prompt_budget(Phase::Check), 30_000);
+        assert_eq!(cfg.prompt_budget(Phase::Run), 40_000);
+        assert_eq!(LoomConfig::default().prompt_budget(Phase::Run), 50_000);
+        Ok(())
+    }
+
     #[test]
     fn security_denied_tools_parses_list() -> Result<()> {
         let src = r#"
@@ -283,6 +440,8 @@ default = "claude"
 backend = "pi"
 provider = "deepseek"
 model_id = "deepseek-v3"
+thinking = "medium"
+max_turns = 30
 "#;
         let cfg = LoomConfig::from_toml_str(src)?;
 
@@ -290,14 +449,17 @@ model_id = "deepseek-v3"
         assert_eq!(todo.kind, crate::agent::AgentKind::Pi);
         assert_eq!(todo.provider.as_deref(), Some("deepseek"));
         assert_eq!(todo.model_id.as_deref(), Some("deepseek-v3"));
+        assert_eq!(todo.thinking, Some(crate::agent::ThinkingLevel::Medium));
+        assert_eq!(todo.max_turns, Some(30));
         assert!(todo.claude_settings.is_none());
 
         let run = cfg.agent_for(Phase::Run).expect("agent_for run");
         assert_eq!(run.kind, crate::agent::AgentKind::Claude);
         assert!(run.provider.is_none());
+        assert!(run.thinking.is_none() && run.max_turns.is_none());
         let claude = run.claude_settings.expect("claude_settings");
         assert_eq!(claude.post_result_grace_secs, 5);
-        assert!(claude.denied_tools.is_empty());
+        assert!(claude.tool_policy.is_empty());
 
         Ok(())
     }
@@ -359,6 +521,50 @@ backend = "ollama"
         Ok(())
     }
 
+    /// A backend name that matches `[backends.<name>]` resolves to a command
+    /// agent carrying its argv, protocol and the phase's tool policy; an
+    /// empty argv is rejected.
+    #[test]
+    fn agent_for_resolves_command_backends_by_name() -> Result<()> {
+        let src = r#"
+[agent]
+default = "claude"
+
+[agent.run]
+backend = "aider"
+
+[agent.check]
+backend = "broken"
+
+[security]
+denied_tools = ["WebFetch"]
+
+[backends.aider]
+argv = ["aider", "--yes-always"]
+protocol = "plain-text"
+
+[backends.broken]
+argv = []
+protocol = "claude-stream-json"
+"#;
+        let cfg = LoomConfig::from_toml_str(src)?;
+        let run = cfg.agent_for(Phase::Run).expect("agent_for run");
+        assert_eq!(run.kind, crate::agent::AgentKind::Command);
+        assert!(run.claude_settings.is_none());
+        let command = run.command.expect("command settings");
+        assert_eq!(command.name, "aider");
+        assert_eq!(command.argv, ["aider", "--yes-always"]);
+        assert_eq!(command.protocol, crate::agent::CommandProtocol::PlainText);
+        assert_eq!(command.tool_policy, cfg.security.policy_for(Phase::Run));
+        assert!(!command.tool_policy.is_empty());
+        match cfg.agent_for(Phase::Check) {
+            Err(AgentSelectionError::EmptyArgv { name }) => assert_eq!(name, "broken"),
+            other => panic!("expected EmptyArgv, got {other:?}"),
+        }
+        assert!(cfg.agent_for(Phase::Todo)?.command.is_none());
+        Ok(())
+    }
+
     /// Claude-specific settings (`[claude]` + `[security]`) flow through
     /// `agent_for` when the resolved backend is claude.
     #[test]
@@ -377,7 +583,69 @@ denied_tools = ["WebFetch", "Other"]
         let sel = cfg.agent_for(Phase::Run).expect("agent_for");
         let claude = sel.claude_settings.expect("claude_settings present");
         assert_eq!(claude.post_result_grace_secs, 12);
-        assert_eq!(claude.denied_tools, vec!["WebFetch", "Other"]);
+        let denied: Vec<_> = claude
+            .tool_policy
+            .rules
+            .iter()
+            .map(|r| (r.action, r.tools.clone()))
+            .collect();
+        assert_eq!(
+            denied,
+            [
+                (ToolAction::Deny, vec!["WebFetch".to_string()]),
+                (ToolAction::Deny, vec!["Other".to_string()]),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn security_rules_apply_only_in_their_phases() -> Result<()> {
+        let src = r#"
+[agent]
+default = "claude"
+
+[security]
+denied_tools = ["WebFetch"]
+
+[[security.rules]]
+action = "deny"
+tools = ["Edit", "Write"]
+phases = ["check"]
+reason = "check is read-only"
+
+[[security.rules]]
+action = "deny"
+path = ".github/**"
+"#;
+        let cfg = LoomConfig::from_toml_str(src)?;
+        let check = cfg.claude_settings(Phase::Check)?.tool_policy;
+        assert_eq!(check.rules.len(), 3);
+        assert_eq!(check.rules[1].reason.as_deref(), Some("check is read-only"));
+        assert_eq!(check.rules[2].path.as_deref(), Some(".github/**"));
+
+        let run = cfg.claude_settings(Phase::Run)?.tool_policy;
+        assert_eq!(run.rules.len(), 2);
+        assert_eq!(run.rules[1].path.as_deref(), Some(".github/**"));
+        Ok(())
+    }
+
+    #[test]
+    fn agent_for_rejects_a_malformed_security_rule() -> Result<()> {
+        let src = r#"
+[agent]
+default = "claude"
+
+[[security.rules]]
+action = "deny"
+tools = ["Bash"]
+command_regex = "(unclosed"
+"#;
+        let cfg = LoomConfig::from_toml_str(src)?;
+        assert!(matches!(
+            cfg.agent_for(Phase::Run),
+            Err(AgentSelectionError::ToolPolicy(_))
+        ));
         Ok(())
     }
 }
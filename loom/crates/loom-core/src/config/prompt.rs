@@ -0,0 +1,51 @@
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+
+use super::agent::AgentSelection;
+use crate::agent::AgentKind;
+
+/// `[prompt]` — how large a rendered prompt may grow before its injected
+/// fields (descriptions, diffs, summaries, pinned context) are truncated.
+/// Sizes are estimated tokens, at roughly four characters per token.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct PromptConfig {
+    /// Budget when neither the model nor the backend has its own.
+    pub max_tokens: usize,
+    /// `[prompt.backends]` — budget per backend name (`claude`, `pi`).
+    pub backends: BTreeMap<String, usize>,
+    /// `[prompt.models]` — budget per `model_id`; wins over the backend's.
+    pub models: BTreeMap<String, usize>,
+}
+
+impl Default for PromptConfig {
+    fn default() -> Self {
+        Self {
+            max_tokens: 50_000,
+            backends: BTreeMap::new(),
+            models: BTreeMap::new(),
+        }
+    }
+}
+
+impl PromptConfig {
+    /// Token budget for a session run by `agent`: its model's entry, else
+    /// its backend's, else [`Self::max_tokens`].
+    pub fn max_tokens_for(&self, agent: &AgentSelection) -> usize {
+        let backend = match agent.kind {
+            AgentKind::Claude => "claude",
+            AgentKind::Pi => "pi",
+            AgentKind::Command => agent.command.as_ref().map_or("", |c| c.name.as_str()),
+            #[cfg(feature = "mock")]
+            AgentKind::Mock => "mock",
+        };
+        agent
+            .model_id
+            .as_deref()
+            .and_then(|model| self.models.get(model))
+            .or_else(|| self.backends.get(backend))
+            .copied()
+            .unwrap_or(self.max_tokens)
+    }
+}
@@ -1,8 +1,47 @@
 use serde::Deserialize;
 
+use super::agent::Phase;
+use crate::agent::{ToolPolicy, ToolRule};
+
 #[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
 #[serde(default)]
 pub struct SecurityConfig {
     /// Tool names denied at host-side `control_request` time. Claude only.
     pub denied_tools: Vec<String>,
+    /// `[[security.rules]]`: allow/deny rules tried in order after
+    /// `denied_tools`. Claude only.
+    pub rules: Vec<SecurityRule>,
+}
+
+/// One `[[security.rules]]` entry: a [`ToolRule`] plus the phases it
+/// applies in.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct SecurityRule {
+    /// Phases the rule applies in; empty applies in every phase.
+    #[serde(default)]
+    pub phases: Vec<Phase>,
+    #[serde(flatten)]
+    pub rule: ToolRule,
+}
+
+impl SecurityConfig {
+    /// The [`ToolPolicy`] for a `phase` session: a deny rule per
+    /// `denied_tools` entry, then the rules that apply in `phase`, in file
+    /// order.
+    pub fn policy_for(&self, phase: Phase) -> ToolPolicy {
+        let mut policy = ToolPolicy::deny_tools(self.denied_tools.iter().cloned());
+        for rule in &mut policy.rules {
+            rule.reason = Some(format!(
+                "`{}` is in [security] denied_tools",
+                rule.tools.join(", ")
+            ));
+        }
+        policy.rules.extend(
+            self.rules
+                .iter()
+                .filter(|r| r.phases.is_empty() || r.phases.contains(&phase))
+                .map(|r| r.rule.clone()),
+        );
+        policy
+    }
 }
@@ -105,12 +105,44 @@ impl LockManager {
         self.locks_dir.join(format!("{}.lock", label.as_str()))
     }
 
+    /// Labels of every spec lock currently held, sorted.
+    ///
+    /// Probes each `*.lock` file (except `workspace.lock`) with a
+    /// non-blocking exclusive `flock(2)`; a probe that succeeds is released
+    /// immediately. The probe opens its own file description, so a lock the
+    /// calling process holds through a [`LockGuard`] is reported too.
+    /// Read-only callers (`loom status`) hold none, so for them every label
+    /// is an in-flight `loom run` / `loom check` elsewhere.
+    pub fn held_spec_locks(&self) -> Result<Vec<SpecLabel>, LockError> {
+        let mut held = Vec::new();
+        self.probe_spec_locks(|stem| {
+            held.push(SpecLabel::new(stem));
+            true
+        })?;
+        held.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        Ok(held)
+    }
+
     /// Probe every `*.lock` file (except `workspace.lock`) for a current
     /// holder. Returns the label of the first held lock, or `None`.
     fn find_held_spec_lock(&self) -> Result<Option<String>, LockError> {
+        let mut found = None;
+        self.probe_spec_locks(|stem| {
+            found = Some(stem.to_string());
+            false
+        })?;
+        Ok(found)
+    }
+
+    /// Walk `locks_dir` and call `on_held` with the stem of each held spec
+    /// lock. Stops early when `on_held` returns `false`.
+    fn probe_spec_locks<F>(&self, mut on_held: F) -> Result<(), LockError>
+    where
+        F: FnMut(&str) -> bool,
+    {
         let entries = match fs::read_dir(&self.locks_dir) {
             Ok(it) => it,
-            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
             Err(e) => return Err(LockError::Io(e)),
         };
         for entry in entries {
@@ -128,12 +160,12 @@ impl LockManager {
             }
             let file = open_lock_file(&path)?;
             let mut probe = RwLock::new(file);
-            if probe.try_write().is_err() {
-                return Ok(Some(stem.to_string()));
+            if probe.try_write().is_err() && !on_held(stem) {
+                return Ok(());
             }
             // probe drops here, releasing the lock the kernel granted.
         }
-        Ok(None)
+        Ok(())
     }
 }
 
@@ -222,4 +254,19 @@ mod tests {
         let _g2 = mgr.acquire_spec(&label)?;
         Ok(())
     }
+
+    #[test]
+    fn held_spec_locks_lists_only_live_holders() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let mgr = LockManager::new(dir.path())?;
+        let _beta = mgr.acquire_spec(&SpecLabel::new("beta"))?;
+        let _alpha = mgr.acquire_spec(&SpecLabel::new("alpha"))?;
+        {
+            let _released = mgr.acquire_spec(&SpecLabel::new("gamma"))?;
+        }
+        let held = mgr.held_spec_locks()?;
+        let labels: Vec<&str> = held.iter().map(SpecLabel::as_str).collect();
+        assert_eq!(labels, ["alpha", "beta"]);
+        Ok(())
+    }
 }
@@ -17,6 +17,12 @@
 //! `[REDACTED]` regardless of underlying content; variable *names* may still
 //! appear in tracing fields.
 //!
+//! [`WireSink`] is the opt-in (`[logs] raw_wire`) raw-protocol counterpart
+//! of the event log: one [`WireRecord`] per stdin/stdout line, written to
+//! `<stem>.wire.jsonl` beside the session's `<stem>.ndjson` log and read
+//! back by the replay backend to feed a recorded session through the
+//! parsers again.
+//!
 //! [`sweep_retention`] is invoked once per `loom run` startup to delete log
 //! files older than the configured retention window. Failures (permission
 //! denied, in-use file) are logged at `debug!` and do not abort the run.
@@ -28,10 +34,15 @@ mod renderer;
 mod retention;
 mod sink;
 mod time;
+mod wire;
 
 pub use error::LogError;
-pub use path::{bead_log_path, format_utc_timestamp};
+pub use path::{
+    bead_log_path, bead_wire_path, format_rfc3339, format_utc_timestamp, parse_rfc3339,
+    parse_utc_timestamp, phase_log_path, wire_path_for,
+};
 pub use redacted::Redacted;
 pub use renderer::{BeadOutcome, RenderMode, TerminalRenderer};
 pub use retention::{RetentionReport, sweep_retention, sweep_retention_at};
 pub use sink::LogSink;
+pub use wire::{WireDirection, WireRecord, WireSink};
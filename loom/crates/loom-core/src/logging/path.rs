@@ -3,7 +3,7 @@ use std::time::SystemTime;
 
 use crate::identifier::{BeadId, SpecLabel};
 
-pub use super::time::format_utc_timestamp;
+pub use super::time::{format_rfc3339, format_utc_timestamp, parse_rfc3339, parse_utc_timestamp};
 
 /// Resolve the per-bead NDJSON log path under
 /// `<logs_root>/<spec-label>/<bead-id>-<utc-timestamp>.ndjson`.
@@ -44,6 +44,44 @@ pub fn bead_log_path(
         .join(format!("{}-{}.ndjson", bead_id.as_str(), stamp))
 }
 
+/// Resolve the per-session NDJSON log path for a session that has no bead
+/// of its own (`loom todo`, a `loom spec --judge` criterion):
+/// `<logs_root>/<spec-label>/<phase>-<utc-timestamp>.ndjson`.
+///
+/// `phase` never parses as a bead id, so `loom logs` — which reads bead
+/// logs — passes these files over.
+pub fn phase_log_path(
+    logs_root: &Path,
+    spec_label: &SpecLabel,
+    phase: &str,
+    when: SystemTime,
+) -> PathBuf {
+    let stamp = format_utc_timestamp(when);
+    logs_root
+        .join(spec_label.as_str())
+        .join(format!("{phase}-{stamp}.ndjson"))
+}
+
+/// Resolve the raw wire capture that sits next to a session's event log:
+/// `<stem>.ndjson` becomes `<stem>.wire.jsonl`, so the two files of one
+/// spawn always share a stamp.
+///
+/// Written only when `[logs] raw_wire = true`.
+pub fn wire_path_for(log_path: &Path) -> PathBuf {
+    log_path.with_extension("wire.jsonl")
+}
+
+/// [`wire_path_for`] the bead log at [`bead_log_path`]:
+/// `<logs_root>/<spec-label>/<bead-id>-<utc-timestamp>.wire.jsonl`.
+pub fn bead_wire_path(
+    logs_root: &Path,
+    spec_label: &SpecLabel,
+    bead_id: &BeadId,
+    when: SystemTime,
+) -> PathBuf {
+    wire_path_for(&bead_log_path(logs_root, spec_label, bead_id, when))
+}
+
 #[cfg(test)]
 #[expect(clippy::expect_used, reason = "tests use panicking helpers")]
 mod tests {
@@ -86,4 +124,27 @@ mod tests {
         assert_eq!(p_a.parent(), p_b.parent());
         assert_ne!(p_a.file_name(), p_b.file_name());
     }
+
+    #[test]
+    fn wire_capture_shares_the_event_log_stem() {
+        let root = Path::new("/r");
+        let when = UNIX_EPOCH + Duration::from_secs(1777811445);
+        let label = SpecLabel::new("a");
+        let bead = BeadId::new("wx-3hhwq.9").expect("valid bead id");
+        assert_eq!(
+            bead_wire_path(root, &label, &bead, when),
+            Path::new("/r/a/wx-3hhwq.9-20260503T123045Z.wire.jsonl"),
+        );
+    }
+
+    #[test]
+    fn phase_sessions_log_under_the_spec_with_a_paired_wire_file() {
+        let when = UNIX_EPOCH + Duration::from_secs(1777811445);
+        let log = phase_log_path(Path::new("/r"), &SpecLabel::new("a"), "judge", when);
+        assert_eq!(log, Path::new("/r/a/judge-20260503T123045Z.ndjson"));
+        assert_eq!(
+            wire_path_for(&log),
+            Path::new("/r/a/judge-20260503T123045Z.wire.jsonl"),
+        );
+    }
 }
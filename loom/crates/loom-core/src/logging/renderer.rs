@@ -150,6 +150,17 @@ impl TerminalRenderer {
         self.write_finish(outcome, elapsed)
     }
 
+    /// Like [`finish`](Self::finish), but reports `elapsed` instead of the
+    /// time since construction. Used when replaying a recorded log, where
+    /// the renderer's own lifetime says nothing about the original run.
+    pub fn finish_with_elapsed(
+        mut self,
+        outcome: BeadOutcome,
+        elapsed: Duration,
+    ) -> io::Result<()> {
+        self.write_finish(outcome, elapsed)
+    }
+
     fn write_finish(&mut self, outcome: BeadOutcome, elapsed: Duration) -> io::Result<()> {
         let (glyph, word, color) = match outcome {
             BeadOutcome::Done => ("✓", "done", ANSI_GREEN),
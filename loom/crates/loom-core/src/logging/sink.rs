@@ -3,6 +3,7 @@ use std::io::{BufWriter, Write};
 use std::path::{Path, PathBuf};
 use std::time::SystemTime;
 
+use serde::Serialize;
 use tracing::info;
 
 use crate::agent::AgentEvent;
@@ -50,7 +51,16 @@ impl LogSink {
         renderer: TerminalRenderer,
         when: SystemTime,
     ) -> Result<Self, LogError> {
-        let log_path = bead_log_path(logs_root, spec_label, bead_id, when);
+        Self::open(
+            bead_log_path(logs_root, spec_label, bead_id, when),
+            Some(renderer),
+        )
+    }
+
+    /// Open the NDJSON log at `log_path` — any session's, not only a
+    /// bead's — creating missing parent directories. Without a `renderer`
+    /// the sink only writes the file.
+    pub fn open(log_path: PathBuf, renderer: Option<TerminalRenderer>) -> Result<Self, LogError> {
         if let Some(dir) = log_path.parent() {
             fs::create_dir_all(dir).map_err(|source| LogError::CreateDir {
                 path: dir.to_path_buf(),
@@ -67,14 +77,12 @@ impl LogSink {
             })?;
         info!(
             target: "loom_core::logging::sink",
-            spec_label = spec_label.as_str(),
-            bead_id = bead_id.as_str(),
             log_path = %log_path.display(),
             "spawn started — log path",
         );
         Ok(Self {
             file: BufWriter::new(file),
-            renderer: Some(renderer),
+            renderer,
             log_path,
             finished: false,
         })
@@ -87,22 +95,31 @@ impl LogSink {
     /// errors are surfaced with the same `LogError::Io` variant so the caller
     /// can decide whether one failure path should also fail the bead.
     pub fn emit(&mut self, event: &AgentEvent) -> Result<(), LogError> {
-        let line = serde_json::to_string(event)?;
-        let path = self.log_path.clone();
+        self.record(event)?;
+        if let Some(renderer) = self.renderer.as_mut() {
+            renderer
+                .render_event(event)
+                .map_err(|source| LogError::Write {
+                    path: self.log_path.clone(),
+                    source,
+                })?;
+        }
+        Ok(())
+    }
+
+    /// Append one NDJSON line to the log file only — for records loom adds
+    /// around the agent's events (a `kind` the [`AgentEvent`] enum does not
+    /// have), which readers skip unless they know them.
+    pub fn record<T: Serialize + ?Sized>(&mut self, record: &T) -> Result<(), LogError> {
+        let line = serde_json::to_string(record)?;
         self.file
             .write_all(line.as_bytes())
             .and_then(|()| self.file.write_all(b"\n"))
             .and_then(|()| self.file.flush())
             .map_err(|source| LogError::Write {
-                path: path.clone(),
+                path: self.log_path.clone(),
                 source,
-            })?;
-        if let Some(renderer) = self.renderer.as_mut() {
-            renderer
-                .render_event(event)
-                .map_err(|source| LogError::Write { path, source })?;
-        }
-        Ok(())
+            })
     }
 
     /// Print the renderer's closing line and flush the log file. Idempotent:
@@ -1,9 +1,11 @@
-//! Tiny UTC formatter for log filenames.
+//! Tiny UTC formatter and parsers for log filenames and `bd` timestamps.
 //!
-//! Loom does not depend on `chrono` or `time`. The only formatting need in
-//! this module is a sortable timestamp suffix for per-bead NDJSON log files
+//! Loom does not depend on `chrono` or `time`. The formatting need in this
+//! module is a sortable timestamp suffix for per-bead NDJSON log files
 //! (`<bead-id>-<utc>.ndjson`); the algorithm here is the classic Howard
-//! Hinnant `civil_from_days` plus a wall-clock seconds split.
+//! Hinnant `civil_from_days` plus a wall-clock seconds split. The parsers
+//! run the inverse (`days_from_civil`) so `loom status` can age `bd`
+//! timestamps and read a log's start time back out of its filename.
 //!
 //! Output shape: ISO 8601 *basic* format `YYYYMMDDTHHMMSSZ`. No separators,
 //! filename-safe on every filesystem, lexicographically sortable.
@@ -33,6 +35,143 @@ pub fn format_utc_timestamp(t: SystemTime) -> String {
     format!("{year:04}{month:02}{day:02}T{hour:02}{minute:02}{second:02}Z")
 }
 
+/// Format a `SystemTime` as an RFC 3339 UTC timestamp
+/// (`YYYY-MM-DDTHH:MM:SSZ`), the shape `bd --json` emits and
+/// [`parse_rfc3339`] reads back. Saturates at the epoch like
+/// [`format_utc_timestamp`].
+///
+/// ```
+/// use loom_core::logging::format_rfc3339;
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// let t = UNIX_EPOCH + Duration::from_secs(1777811445);
+/// assert_eq!(format_rfc3339(t), "2026-05-03T12:30:45Z");
+/// ```
+pub fn format_rfc3339(t: SystemTime) -> String {
+    let secs = t
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let (year, month, day, hour, minute, second) = utc_parts(secs);
+    format!("{year:04}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}Z")
+}
+
+/// Parse a `YYYYMMDDTHHMMSSZ` stamp produced by [`format_utc_timestamp`].
+///
+/// Returns `None` for any other shape, including stamps before the epoch
+/// and non-ASCII input.
+///
+/// ```
+/// use loom_core::logging::parse_utc_timestamp;
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// let t = parse_utc_timestamp("20260503T123045Z");
+/// assert_eq!(t, Some(UNIX_EPOCH + Duration::from_secs(1777811445)));
+/// ```
+pub fn parse_utc_timestamp(s: &str) -> Option<SystemTime> {
+    let b = s.as_bytes();
+    if !s.is_ascii() || b.len() != 16 || b[8] != b'T' || b[15] != b'Z' {
+        return None;
+    }
+    let year = digits(&s[0..4])?;
+    let month = digits(&s[4..6])?;
+    let day = digits(&s[6..8])?;
+    let hour = digits(&s[9..11])?;
+    let minute = digits(&s[11..13])?;
+    let second = digits(&s[13..15])?;
+    to_system_time(year, month, day, hour, minute, second, 0)
+}
+
+/// Parse an RFC 3339 timestamp as emitted by `bd --json`
+/// (`2026-05-03T12:30:45.123456789-07:00`, `2026-05-03T12:30:45Z`).
+///
+/// Fractional seconds are dropped. A missing offset is read as UTC. Returns
+/// `None` for anything else, non-ASCII input included, rather than guessing.
+///
+/// ```
+/// use loom_core::logging::parse_rfc3339;
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// let t = parse_rfc3339("2026-05-03T14:30:45.5+02:00");
+/// assert_eq!(t, Some(UNIX_EPOCH + Duration::from_secs(1777811445)));
+/// ```
+pub fn parse_rfc3339(s: &str) -> Option<SystemTime> {
+    let b = s.as_bytes();
+    if !s.is_ascii()
+        || b.len() < 19
+        || b[4] != b'-'
+        || b[7] != b'-'
+        || !matches!(b[10], b'T' | b't' | b' ')
+        || b[13] != b':'
+        || b[16] != b':'
+    {
+        return None;
+    }
+    let year = digits(&s[0..4])?;
+    let month = digits(&s[5..7])?;
+    let day = digits(&s[8..10])?;
+    let hour = digits(&s[11..13])?;
+    let minute = digits(&s[14..16])?;
+    let second = digits(&s[17..19])?;
+
+    let mut rest = &s[19..];
+    if let Some(frac) = rest.strip_prefix('.') {
+        let end = frac
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(frac.len());
+        if end == 0 {
+            return None;
+        }
+        rest = &frac[end..];
+    }
+    let offset_secs: i64 = match rest {
+        "" | "Z" | "z" => 0,
+        _ => {
+            let (sign, hhmm) = match rest.split_at(1) {
+                ("+", r) => (1, r),
+                ("-", r) => (-1, r),
+                _ => return None,
+            };
+            let (h, m) = hhmm.split_once(':')?;
+            if h.len() != 2 || m.len() != 2 {
+                return None;
+            }
+            sign * (digits(h)? * 3600 + digits(m)? * 60)
+        }
+    };
+    to_system_time(year, month, day, hour, minute, second, offset_secs)
+}
+
+fn digits(s: &str) -> Option<i64> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+fn to_system_time(
+    year: i64,
+    month: i64,
+    day: i64,
+    hour: i64,
+    minute: i64,
+    second: i64,
+    offset_secs: i64,
+) -> Option<SystemTime> {
+    if !(1..=12).contains(&month)
+        || !(1..=31).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 60
+    {
+        return None;
+    }
+    let days = days_from_civil(year, month as u32, day as u32);
+    let secs = days * 86_400 + hour * 3600 + minute * 60 + second - offset_secs;
+    let secs = u64::try_from(secs).ok()?;
+    UNIX_EPOCH.checked_add(std::time::Duration::from_secs(secs))
+}
+
 /// Decompose seconds-since-epoch into `(year, month, day, hour, minute,
 /// second)` UTC components.
 fn utc_parts(secs: u64) -> (i32, u32, u32, u32, u32, u32) {
@@ -68,6 +207,21 @@ fn civil_from_days(days_since_epoch: i64) -> (i32, u32, u32) {
     (year as i32, m, d)
 }
 
+/// Inverse of [`civil_from_days`]: days since 1970-01-01 for a proleptic
+/// Gregorian (year, month, day).
+///
+/// Algorithm by Howard Hinnant
+/// (<https://howardhinnant.github.io/date_algorithms.html#days_from_civil>).
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y.rem_euclid(400) as u64; // [0, 399]
+    let mp = u64::from((month + 9) % 12); // [0, 11]
+    let doy = (153 * mp + 2) / 5 + u64::from(day) - 1; // [0, 365]
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy; // [0, 146096]
+    era * 146_097 + doe as i64 - 719_468
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -112,4 +266,60 @@ mod tests {
             format_utc_timestamp(UNIX_EPOCH + std::time::Duration::from_secs(1_800_000_000));
         assert!(earlier < later);
     }
+
+    #[test]
+    fn parse_utc_timestamp_round_trips_format() {
+        for secs in [0, 1_709_164_800, 1_767_225_599, 1_777_811_445] {
+            let t = UNIX_EPOCH + std::time::Duration::from_secs(secs);
+            assert_eq!(parse_utc_timestamp(&format_utc_timestamp(t)), Some(t));
+        }
+    }
+
+    #[test]
+    fn parse_utc_timestamp_rejects_other_shapes() {
+        for s in [
+            "",
+            "20260503T123045",
+            "2026-05-03T12:30:45Z",
+            "20261303T000000Z",
+        ] {
+            assert_eq!(parse_utc_timestamp(s), None, "{s}");
+        }
+    }
+
+    #[test]
+    fn parse_rfc3339_handles_fraction_and_offsets() {
+        let want = Some(UNIX_EPOCH + std::time::Duration::from_secs(1_777_811_445));
+        assert_eq!(parse_rfc3339("2026-05-03T12:30:45Z"), want);
+        assert_eq!(parse_rfc3339("2026-05-03T12:30:45.123456789Z"), want);
+        assert_eq!(parse_rfc3339("2026-05-03T05:30:45-07:00"), want);
+        assert_eq!(parse_rfc3339("2026-05-03 12:30:45"), want);
+    }
+
+    #[test]
+    fn parse_rfc3339_rejects_garbage() {
+        for s in [
+            "",
+            "yesterday",
+            "2026-05-03",
+            "2026-05-03T12:30:45+0200",
+            "2026-05-03T12:30:45.",
+        ] {
+            assert_eq!(parse_rfc3339(s), None, "{s}");
+        }
+    }
+
+    #[test]
+    fn parsers_reject_multibyte_input_without_panicking() {
+        for s in ["20260503T123é5Z", "202605éT123045Z"] {
+            assert_eq!(parse_utc_timestamp(s), None, "{s}");
+        }
+        for s in [
+            "2026-05-03T12:30:4é",
+            "2026-05-03T12:30:45é",
+            "2026-05-03T12:30:45+é:00",
+        ] {
+            assert_eq!(parse_rfc3339(s), None, "{s}");
+        }
+    }
 }
@@ -0,0 +1,185 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use super::error::LogError;
+use super::path::{format_utc_timestamp, wire_path_for};
+
+/// Which way a raw protocol line travelled across the agent's stdio.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WireDirection {
+    /// Written by loom to the agent's stdin (prompt, steer, abort,
+    /// `control_response`, extension UI replies).
+    In,
+    /// Read by loom from the agent's stdout.
+    Out,
+}
+
+/// One raw line of agent wire traffic — a record of a
+/// `<stem>.wire.jsonl` capture.
+///
+/// `line` is the protocol line verbatim (trailing newline stripped), before
+/// any parsing, so a capture reproduces exactly what the parser saw.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WireRecord {
+    pub dir: WireDirection,
+    /// UTC `YYYYMMDDTHHMMSSZ` stamp of when the line crossed the pipe.
+    pub ts: String,
+    pub line: String,
+}
+
+impl WireRecord {
+    /// Read a wire capture back. Each non-blank line is either a
+    /// [`WireRecord`] or — for hand-captured agent stdout pasted from a
+    /// terminal — a bare protocol line, taken as [`WireDirection::Out`]
+    /// with an empty `ts`.
+    pub fn parse_log(text: &str) -> Vec<Self> {
+        text.lines()
+            .map(|l| l.trim_end_matches('\r'))
+            .filter(|l| !l.trim().is_empty())
+            .map(|l| {
+                serde_json::from_str::<Self>(l).unwrap_or_else(|_| Self {
+                    dir: WireDirection::Out,
+                    ts: String::new(),
+                    line: l.to_string(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Append-only writer for a session's raw wire capture.
+///
+/// Opt-in via `[logs] raw_wire = true`. Attached to an
+/// [`AgentSession`](crate::agent::AgentSession), which records every line
+/// it reads from the agent's stdout and every line it writes to stdin —
+/// including `control_response` payloads the parser generates — before
+/// any parsing, so parser bugs and protocol drift can be diagnosed (or
+/// replayed) after the fact.
+pub struct WireSink {
+    file: BufWriter<File>,
+    path: PathBuf,
+}
+
+impl WireSink {
+    /// Open the capture beside the session's NDJSON log at `log_path`
+    /// ([`wire_path_for`]), creating missing parent directories.
+    pub fn beside(log_path: &Path) -> Result<Self, LogError> {
+        let path = wire_path_for(log_path);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|source| LogError::CreateDir {
+                path: dir.to_path_buf(),
+                source,
+            })?;
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|source| LogError::OpenFile {
+                path: path.clone(),
+                source,
+            })?;
+        info!(
+            target: "loom_core::logging::wire",
+            wire_path = %path.display(),
+            "raw wire capture enabled",
+        );
+        Ok(Self {
+            file: BufWriter::new(file),
+            path,
+        })
+    }
+
+    /// Append one line as a [`WireRecord`] stamped with the current time.
+    /// A trailing newline on `line` is stripped. Flushed per record so a
+    /// crashed session still leaves everything up to the crash on disk.
+    pub fn record(&mut self, dir: WireDirection, line: &str) -> Result<(), LogError> {
+        let record = WireRecord {
+            dir,
+            ts: format_utc_timestamp(SystemTime::now()),
+            line: line.trim_end_matches(['\n', '\r']).to_string(),
+        };
+        let mut json = serde_json::to_string(&record)?;
+        json.push('\n');
+        self.file
+            .write_all(json.as_bytes())
+            .and_then(|()| self.file.flush())
+            .map_err(|source| LogError::Write {
+                path: self.path.clone(),
+                source,
+            })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::identifier::{BeadId, SpecLabel};
+    use crate::logging::bead_log_path;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn parse_log_accepts_records_and_bare_stdout_lines() {
+        let text = concat!(
+            r#"{"dir":"in","ts":"20260503T120000Z","line":"{\"type\":\"user\"}"}"#,
+            "\n\n",
+            r#"{"type":"result","subtype":"success"}"#,
+            "\r\n",
+        );
+        assert_eq!(
+            WireRecord::parse_log(text),
+            [
+                WireRecord {
+                    dir: WireDirection::In,
+                    ts: "20260503T120000Z".into(),
+                    line: r#"{"type":"user"}"#.into(),
+                },
+                WireRecord {
+                    dir: WireDirection::Out,
+                    ts: String::new(),
+                    line: r#"{"type":"result","subtype":"success"}"#.into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn sink_records_round_trip_through_parse_log() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let log_path = bead_log_path(
+            dir.path(),
+            &SpecLabel::new("alpha"),
+            &BeadId::new("wx-1")?,
+            UNIX_EPOCH + Duration::from_secs(1777811445),
+        );
+        let mut sink = WireSink::beside(&log_path)?;
+        sink.record(WireDirection::In, "{\"type\":\"user\"}\n")?;
+        sink.record(WireDirection::Out, "{\"type\":\"result\"}")?;
+        assert!(
+            sink.path()
+                .ends_with("alpha/wx-1-20260503T123045Z.wire.jsonl")
+        );
+
+        let records = WireRecord::parse_log(&fs::read_to_string(sink.path())?);
+        let lines: Vec<(WireDirection, &str)> =
+            records.iter().map(|r| (r.dir, r.line.as_str())).collect();
+        assert_eq!(
+            lines,
+            [
+                (WireDirection::In, "{\"type\":\"user\"}"),
+                (WireDirection::Out, "{\"type\":\"result\"}"),
+            ]
+        );
+        Ok(())
+    }
+}
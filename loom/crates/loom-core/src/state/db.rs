@@ -3,7 +3,7 @@ use std::sync::Mutex;
 
 use rusqlite::{Connection, OptionalExtension, params};
 
-use crate::identifier::{MoleculeId, SpecLabel};
+use crate::identifier::{BeadId, MoleculeId, SpecLabel};
 
 use super::error::StateError;
 
@@ -30,9 +30,20 @@ CREATE TABLE IF NOT EXISTS meta (
     key   TEXT PRIMARY KEY,
     value TEXT NOT NULL
 );
+CREATE TABLE IF NOT EXISTS attempts (
+    id         INTEGER PRIMARY KEY,
+    spec_label TEXT NOT NULL,
+    bead_id    TEXT NOT NULL,
+    attempt    INTEGER NOT NULL,
+    model      TEXT,
+    exit_code  INTEGER NOT NULL,
+    cost_usd   REAL,
+    log_path   TEXT NOT NULL
+);
 ";
 
 const DROP_AND_RECREATE: &str = "
+DROP TABLE IF EXISTS attempts;
 DROP TABLE IF EXISTS companions;
 DROP TABLE IF EXISTS molecules;
 DROP TABLE IF EXISTS specs;
@@ -63,6 +74,24 @@ pub struct MoleculeRow {
     pub iteration_count: u32,
 }
 
+/// One row of the `attempts` table: an agent session `loom run` drove
+/// against a bead, with the model it ran on and what it cost.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttemptRow {
+    pub spec_label: SpecLabel,
+    pub bead: BeadId,
+    /// 0 for the first run, then 1, 2, ... per retry.
+    pub attempt: u32,
+    /// The `[loop] retry_models` rung or `model:` label the attempt ran on;
+    /// `None` when the phase's configured model was used.
+    pub model: Option<String>,
+    pub exit_code: i32,
+    /// As reported by the backend; `None` when it reports no cost.
+    pub cost_usd: Option<f64>,
+    /// The session's NDJSON log.
+    pub log_path: PathBuf,
+}
+
 impl StateDb {
     /// Open or create a state DB at `path`, applying schema migrations.
     pub fn open(path: impl AsRef<Path>) -> Result<Self, StateError> {
@@ -217,6 +246,40 @@ impl StateDb {
         Ok(count.max(0) as u32)
     }
 
+    /// Append one finished attempt to the `attempts` table.
+    pub fn record_attempt(&self, row: &AttemptRow) -> Result<(), StateError> {
+        let conn = self.lock_conn()?;
+        conn.execute(
+            "INSERT INTO attempts(spec_label, bead_id, attempt, model, exit_code, cost_usd, log_path)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![
+                row.spec_label.as_str(),
+                row.bead.as_str(),
+                row.attempt,
+                row.model,
+                row.exit_code,
+                row.cost_usd,
+                row.log_path.to_string_lossy(),
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Every recorded attempt for `label`, in the order they were recorded.
+    pub fn attempts(&self, label: &SpecLabel) -> Result<Vec<AttemptRow>, StateError> {
+        let conn = self.lock_conn()?;
+        let mut stmt = conn.prepare(
+            "SELECT spec_label, bead_id, attempt, model, exit_code, cost_usd, log_path
+             FROM attempts WHERE spec_label = ?1 ORDER BY id",
+        )?;
+        let rows = stmt.query_map(params![label.as_str()], row_to_attempt)?;
+        let mut out = Vec::new();
+        for row in rows {
+            out.push(row??);
+        }
+        Ok(out)
+    }
+
     /// Borrow the underlying connection for code inside `state/` only.
     pub(super) fn with_conn<R>(
         &self,
@@ -276,3 +339,23 @@ fn row_to_molecule(row: &rusqlite::Row<'_>) -> rusqlite::Result<Result<MoleculeR
         iteration_count: iteration_count.max(0) as u32,
     }))
 }
+
+fn row_to_attempt(row: &rusqlite::Row<'_>) -> rusqlite::Result<Result<AttemptRow, StateError>> {
+    let spec_label: String = row.get(0)?;
+    let bead: String = row.get(1)?;
+    let attempt: i64 = row.get(2)?;
+    let log_path: String = row.get(6)?;
+    let bead = match BeadId::new(&bead) {
+        Ok(bead) => bead,
+        Err(source) => return Ok(Err(source.into())),
+    };
+    Ok(Ok(AttemptRow {
+        spec_label: SpecLabel::new(spec_label),
+        bead,
+        attempt: attempt.max(0) as u32,
+        model: row.get(3)?,
+        exit_code: row.get(4)?,
+        cost_usd: row.get(5)?,
+        log_path: PathBuf::from(log_path),
+    }))
+}
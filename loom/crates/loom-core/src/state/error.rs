@@ -4,6 +4,8 @@ use std::path::PathBuf;
 use displaydoc::Display;
 use thiserror::Error;
 
+use crate::identifier::ParseBeadIdError;
+
 #[derive(Debug, Display, Error)]
 pub enum StateError {
     /// failed to open SQLite database at {path}
@@ -29,6 +31,9 @@ pub enum StateError {
     /// no spec found with label {label}
     SpecNotFound { label: String },
 
+    /// invalid bead id in the attempts table
+    BeadId(#[from] ParseBeadIdError),
+
     /// io failure
     Io(#[from] io::Error),
 }
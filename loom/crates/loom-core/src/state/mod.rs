@@ -5,9 +5,9 @@
 //! (`StateDb` plus the row structs returned by its accessors).
 //!
 //! The state DB is reconstructable from spec files on disk and active beads
-//! via [`StateDb::rebuild`]; iteration counters reset to 0 and implementation
-//! notes are lost, since notes are written by `loom plan` and have no
-//! external source of truth.
+//! via [`StateDb::rebuild`]; iteration counters reset to 0, and implementation
+//! notes and the `attempts` history are lost, since `loom plan` and `loom
+//! run` write them and they have no external source of truth.
 
 mod companions;
 mod db;
@@ -15,6 +15,6 @@ mod error;
 mod rebuild;
 
 pub use companions::parse_companions;
-pub use db::{MoleculeRow, SpecRow, StateDb};
+pub use db::{AttemptRow, MoleculeRow, SpecRow, StateDb};
 pub use error::StateError;
 pub use rebuild::{ActiveMolecule, RebuildReport};
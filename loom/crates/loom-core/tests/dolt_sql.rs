@@ -0,0 +1,138 @@
+//! Integration test for `loom_core::bd::SqlReader` against a live Dolt
+//! server.
+//!
+//! Needs a scratch database on a running `dolt sql-server`, named by
+//! `LOOM_DOLT_TEST_URL` (e.g. `mysql://root@127.0.0.1:3306/loom_test`). The
+//! test (re)creates a minimal `issues` / `labels` / `dependencies` schema
+//! there, seeds a small molecule and reads it back through a
+//! [`BdClient`] whose reads go over SQL. `#[ignore]`d so plain `cargo test`
+//! does not need a server:
+//!
+//! ```sh
+//! LOOM_DOLT_TEST_URL=mysql://root@127.0.0.1:3306/loom_test \
+//!     cargo test -p loom-core --test dolt_sql -- --ignored
+//! ```
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use anyhow::{Context, Result};
+use loom_core::bd::{BdClient, ListOpts, ReadyOpts, SqlReader};
+use loom_core::identifier::{BeadId, MoleculeId};
+use mysql_async::prelude::Queryable;
+
+const URL_ENV: &str = "LOOM_DOLT_TEST_URL";
+
+/// The slice of the `bd` schema [`SqlReader`] queries.
+const SCHEMA: &[&str] = &[
+    "DROP TABLE IF EXISTS dependencies",
+    "DROP TABLE IF EXISTS labels",
+    "DROP TABLE IF EXISTS issues",
+    "CREATE TABLE issues (
+        id VARCHAR(255) PRIMARY KEY,
+        title VARCHAR(500) NOT NULL,
+        description TEXT,
+        status VARCHAR(32) NOT NULL DEFAULT 'open',
+        priority INT NOT NULL DEFAULT 2,
+        issue_type VARCHAR(32) DEFAULT 'task',
+        assignee VARCHAR(255),
+        notes TEXT,
+        created_at DATETIME NOT NULL,
+        updated_at DATETIME NOT NULL,
+        closed_at DATETIME
+    )",
+    "CREATE TABLE labels (
+        issue_id VARCHAR(255) NOT NULL,
+        label VARCHAR(255) NOT NULL,
+        PRIMARY KEY (issue_id, label)
+    )",
+    "CREATE TABLE dependencies (
+        issue_id VARCHAR(255) NOT NULL,
+        depends_on_id VARCHAR(255) NOT NULL,
+        type VARCHAR(32) NOT NULL DEFAULT 'blocks',
+        created_at DATETIME NOT NULL,
+        PRIMARY KEY (issue_id, depends_on_id)
+    )",
+];
+
+/// An epic `wx-1` with three children: `wx-1.1` closed, `wx-1.2` open and
+/// ready, `wx-1.3` open but blocked on `wx-1.2`.
+const SEED: &[&str] = &[
+    "INSERT INTO issues (id, title, status, priority, issue_type, created_at, updated_at) VALUES
+        ('wx-1', 'Molecule', 'open', 1, 'epic', '2026-01-01 00:00:00', '2026-01-01 00:00:00'),
+        ('wx-1.1', 'First', 'closed', 2, 'task', '2026-01-01 00:00:01', '2026-01-01 00:00:01'),
+        ('wx-1.2', 'Second', 'open', 2, 'task', '2026-01-01 00:00:02', '2026-01-01 00:00:02'),
+        ('wx-1.3', 'Third', 'open', 1, 'task', '2026-01-01 00:00:03', '2026-01-01 00:00:03')",
+    "INSERT INTO labels (issue_id, label) VALUES
+        ('wx-1', 'spec:alpha'), ('wx-1.1', 'spec:alpha'),
+        ('wx-1.2', 'spec:alpha'), ('wx-1.2', 'profile:rust'), ('wx-1.3', 'spec:alpha')",
+    "INSERT INTO dependencies (issue_id, depends_on_id, type, created_at) VALUES
+        ('wx-1.1', 'wx-1', 'parent-child', '2026-01-01 00:00:01'),
+        ('wx-1.2', 'wx-1', 'parent-child', '2026-01-01 00:00:02'),
+        ('wx-1.3', 'wx-1', 'parent-child', '2026-01-01 00:00:03'),
+        ('wx-1.3', 'wx-1.2', 'blocks', '2026-01-01 00:00:04')",
+];
+
+async fn seed(url: &str) -> Result<()> {
+    let pool = mysql_async::Pool::new(mysql_async::Opts::from_url(url)?);
+    let mut conn = pool.get_conn().await?;
+    for stmt in SCHEMA.iter().chain(SEED) {
+        conn.query_drop(*stmt)
+            .await
+            .with_context(|| format!("seeding: {stmt}"))?;
+    }
+    drop(conn);
+    pool.disconnect().await?;
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore = "needs a dolt sql-server; set LOOM_DOLT_TEST_URL"]
+async fn reads_match_the_cli_shapes() -> Result<()> {
+    let url = std::env::var(URL_ENV).with_context(|| format!("{URL_ENV} is not set"))?;
+    seed(&url).await?;
+    let bd = BdClient::new().with_sql_reads(SqlReader::from_url(&url)?);
+
+    let second = bd.show(&BeadId::new("wx-1.2")?).await?;
+    assert_eq!(second.title, "Second");
+    assert_eq!(second.parent.as_ref().map(BeadId::as_str), Some("wx-1"));
+    assert_eq!(
+        second.labels.iter().map(|l| l.as_str()).collect::<Vec<_>>(),
+        ["profile:rust", "spec:alpha"]
+    );
+    assert_eq!(second.created_at.as_deref(), Some("2026-01-01T00:00:02Z"));
+    let third = bd.show(&BeadId::new("wx-1.3")?).await?;
+    let blocks: Vec<_> = third
+        .dependencies
+        .iter()
+        .filter(|d| d.kind == "blocks")
+        .map(|d| (d.id.as_str(), d.status.as_str()))
+        .collect();
+    assert_eq!(blocks, [("wx-1.2", "open")]);
+
+    let open = bd
+        .list(ListOpts {
+            status: None,
+            label: Some("spec:alpha".into()),
+        })
+        .await?;
+    let ids: Vec<_> = open.iter().map(|b| b.id.as_str()).collect();
+    assert_eq!(ids, ["wx-1", "wx-1.2", "wx-1.3"]);
+
+    // The epic is never ready and `wx-1.3` waits on `wx-1.2`.
+    let ready = bd
+        .ready(ReadyOpts {
+            label: Some("spec:alpha".into()),
+            limit: Some(5),
+        })
+        .await?;
+    let ids: Vec<_> = ready.iter().map(|b| b.id.as_str()).collect();
+    assert_eq!(ids, ["wx-1.2"]);
+
+    let progress = bd.mol_progress(&MoleculeId::new("wx-1")).await?;
+    assert_eq!((progress.completed, progress.total), (1, 3));
+    assert_eq!(progress.molecule_title, "Molecule");
+
+    let stats = bd.stats();
+    assert_eq!((stats.invocations, stats.sql_reads), (0, 5));
+    Ok(())
+}
@@ -10,8 +10,8 @@
 use std::path::Path;
 
 use anyhow::{Context, Result, anyhow};
-use loom_core::identifier::{MoleculeId, SpecLabel};
-use loom_core::state::{ActiveMolecule, StateDb};
+use loom_core::identifier::{BeadId, MoleculeId, SpecLabel};
+use loom_core::state::{ActiveMolecule, AttemptRow, StateDb};
 
 fn write_spec(workspace: &Path, label: &str, body: &str) -> Result<()> {
     let specs = workspace.join("specs");
@@ -55,7 +55,7 @@ fn state_db_init_creates_tables() -> Result<()> {
         "SELECT name FROM sqlite_master WHERE type='table' ORDER BY name",
     )?;
     let names: Vec<&str> = tables.iter().map(|r| r[0].as_str()).collect();
-    for expected in ["companions", "meta", "molecules", "specs"] {
+    for expected in ["attempts", "companions", "meta", "molecules", "specs"] {
         assert!(
             names.contains(&expected),
             "expected table {expected}: {names:?}"
@@ -165,6 +165,31 @@ fn state_db_rebuild_resets_counters() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn state_attempts_record_model_and_cost_per_spec() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let db = StateDb::open(dir.path().join("state.db"))?;
+    let alpha = SpecLabel::new("alpha");
+    let row = |label: &SpecLabel, attempt: u32, model: Option<&str>, exit_code| -> Result<_> {
+        Ok(AttemptRow {
+            spec_label: label.clone(),
+            bead: BeadId::new("wx-1")?,
+            attempt,
+            model: model.map(str::to_string),
+            exit_code,
+            cost_usd: model.map(|_| 0.25),
+            log_path: format!("logs/{}/wx-1-{attempt}.ndjson", label.as_str()).into(),
+        })
+    };
+    let first = row(&alpha, 0, None, 1)?;
+    let retry = row(&alpha, 1, Some("opus"), 0)?;
+    db.record_attempt(&first)?;
+    db.record_attempt(&row(&SpecLabel::new("beta"), 0, None, 0)?)?;
+    db.record_attempt(&retry)?;
+    assert_eq!(db.attempts(&alpha)?, [first, retry]);
+    Ok(())
+}
+
 #[test]
 fn state_current_spec_round_trips() -> Result<()> {
     let dir = tempfile::tempdir()?;
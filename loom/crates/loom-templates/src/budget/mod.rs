@@ -0,0 +1,83 @@
+//! Prompt size budgeting: fit a context's free-form fields into an
+//! estimated-token budget before it is rendered.
+//!
+//! Tokens are estimated from characters ([`CHARS_PER_TOKEN`]) — close enough
+//! to keep a first turn inside the context window without a tokenizer per
+//! backend. Each context lists its trimmable fields lowest priority first;
+//! [`Budgeted::fit`] cuts them in that order, keeping the head of each and
+//! appending a marker that says how much was dropped, until the render fits
+//! or every field is down to [`MIN_KEPT_CHARS`].
+
+use askama::Template;
+
+/// Characters per estimated token.
+pub const CHARS_PER_TOKEN: usize = 4;
+
+/// A trimmed field keeps at least this many characters of its head.
+pub const MIN_KEPT_CHARS: usize = 1000;
+
+/// Estimated token count of `text`.
+pub fn estimate_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(CHARS_PER_TOKEN)
+}
+
+/// One field cut by [`Budgeted::fit`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Truncation {
+    pub field: &'static str,
+    pub original_chars: usize,
+    /// Characters of the original kept, not counting the marker.
+    pub kept_chars: usize,
+}
+
+/// A template context whose free-form fields can be cut to fit a budget.
+pub trait Budgeted: Template {
+    /// The trimmable fields in cut order — lowest priority first. Absent
+    /// optional fields are left out.
+    fn trimmable(&mut self) -> Vec<(&'static str, &mut String)>;
+
+    /// Cut [`Self::trimmable`] fields until the render is estimated at or
+    /// under `max_tokens`, returning what was cut. An empty result means the
+    /// context already fit and was left untouched.
+    fn fit(&mut self, max_tokens: usize) -> Result<Vec<Truncation>, askama::Error> {
+        let rendered = estimate_tokens(&self.render()?);
+        let mut excess = rendered.saturating_sub(max_tokens) * CHARS_PER_TOKEN;
+        let mut cuts = Vec::new();
+        for (field, text) in self.trimmable() {
+            if excess == 0 {
+                break;
+            }
+            let original = text.chars().count();
+            let marker_len = marker(original, original).chars().count();
+            let room = original.saturating_sub(MIN_KEPT_CHARS);
+            if room <= marker_len {
+                continue;
+            }
+            let kept = original - (excess + marker_len).min(room);
+            if let Some((end, _)) = text.char_indices().nth(kept) {
+                text.truncate(end);
+            }
+            text.push_str(&marker(original, kept));
+            excess = excess.saturating_sub(original - kept - marker_len);
+            cuts.push(Truncation {
+                field,
+                original_chars: original,
+                kept_chars: kept,
+            });
+        }
+        Ok(cuts)
+    }
+}
+
+/// The note left where a field was cut.
+fn marker(original: usize, kept: usize) -> String {
+    format!("\n\n[… truncated by loom to fit the prompt budget: kept {kept} of {original} chars]")
+}
+
+/// `field` as a trimmable entry when present.
+pub(crate) fn optional<'a>(
+    name: &'static str,
+    field: &'a mut Option<String>,
+) -> Option<(&'static str, &'a mut String)> {
+    field.as_mut().map(|text| (name, text))
+}
@@ -3,6 +3,9 @@
 use askama::Template;
 use loom_core::identifier::{MoleculeId, SpecLabel};
 
+use crate::budget::{self, Budgeted};
+use crate::overlay::ProjectOverlay;
+
 /// Context for `loom check` reviewing a completed molecule.
 #[derive(Template)]
 #[template(path = "check.md", escape = "none")]
@@ -15,4 +18,15 @@ pub struct CheckContext {
     pub base_commit: Option<String>,
     pub molecule_id: Option<MoleculeId>,
     pub exit_signals: String,
+    pub overlay: ProjectOverlay,
+}
+
+/// The review needs the spec whole; the bead summary goes first.
+impl Budgeted for CheckContext {
+    fn trimmable(&mut self) -> Vec<(&'static str, &mut String)> {
+        budget::optional("beads_summary", &mut self.beads_summary)
+            .into_iter()
+            .chain([("pinned_context", &mut self.pinned_context)])
+            .collect()
+    }
 }
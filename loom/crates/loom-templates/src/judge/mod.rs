@@ -0,0 +1,19 @@
+//! `loom spec --judge` template: one success criterion put to a reviewer.
+
+use askama::Template;
+
+/// Context for judging one `[judge]` criterion against its rubric's files.
+#[derive(Template)]
+#[template(path = "judge.md", escape = "none")]
+pub struct JudgeContext {
+    pub criterion: String,
+    pub files: Vec<JudgeFile>,
+}
+
+/// A source file named by the rubric's `judge_files`, inlined verbatim.
+#[derive(Debug, Clone)]
+pub struct JudgeFile {
+    /// Workspace-relative path, as written in the rubric.
+    pub path: String,
+    pub body: String,
+}
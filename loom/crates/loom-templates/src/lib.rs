@@ -4,12 +4,19 @@
 //! generated by Askama (`#[derive(Template)]`) and validated at build time —
 //! a missing context field is a compile error.
 //!
+//! Projects steer the compiled prompts through [`overlay::ProjectOverlay`]
+//! fragments rather than by editing template source, and each context fits
+//! itself to a token budget through [`budget::Budgeted`].
+//!
 //! Agent-generated content (`previous_failure`, `title`, `description`,
 //! `existing_tasks`) is wrapped in `<agent-output>...</agent-output>` markers
 //! in the template bodies as a best-effort prompt-injection mitigation.
 
+pub mod budget;
 pub mod check;
+pub mod judge;
 pub mod msg;
+pub mod overlay;
 pub mod plan;
 pub mod run;
 pub mod todo;
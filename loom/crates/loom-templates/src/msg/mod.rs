@@ -3,6 +3,8 @@
 use askama::Template;
 use loom_core::identifier::{BeadId, SpecLabel};
 
+use crate::budget::Budgeted;
+
 /// Context for `loom msg` rendering the cross-spec clarify queue.
 #[derive(Template)]
 #[template(path = "msg.md", escape = "none")]
@@ -33,3 +35,9 @@ pub struct ClarifyOption {
     pub title: Option<String>,
     pub body: Option<String>,
 }
+
+impl Budgeted for MsgContext {
+    fn trimmable(&mut self) -> Vec<(&'static str, &mut String)> {
+        vec![("pinned_context", &mut self.pinned_context)]
+    }
+}
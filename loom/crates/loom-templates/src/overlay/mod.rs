@@ -0,0 +1,31 @@
+//! Project prompt overlays: Markdown fragments a workspace layers onto the
+//! compiled templates without editing them.
+//!
+//! Each overlaid template has two slots filled through
+//! `partial/project_overlay.md` — `pre` right after context pinning and
+//! `post` after the phase's own instructions, ahead of the exit signals.
+//! Loading the fragments from `.wrapix/loom/prompts/` is the workflow
+//! crate's job; this module only carries them into the render.
+
+/// One fragment rendered into an overlay slot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverlayFragment {
+    /// Workspace-relative path the fragment was read from; rendered as an
+    /// HTML comment above the body so a prompt can be traced to its files.
+    pub source: String,
+    pub body: String,
+}
+
+/// The fragments for one render. Both slots empty (the default) renders
+/// byte-identically to a template without overlays.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProjectOverlay {
+    pub pre: Vec<OverlayFragment>,
+    pub post: Vec<OverlayFragment>,
+}
+
+impl ProjectOverlay {
+    pub fn is_empty(&self) -> bool {
+        self.pre.is_empty() && self.post.is_empty()
+    }
+}
@@ -1,6 +1,9 @@
 use askama::Template;
 use loom_core::identifier::SpecLabel;
 
+use crate::budget::Budgeted;
+use crate::overlay::ProjectOverlay;
+
 /// Context for `loom plan -n <label>` (new-spec interview).
 #[derive(Template)]
 #[template(path = "plan_new.md", escape = "none")]
@@ -9,4 +12,11 @@ pub struct PlanNewContext {
     pub label: SpecLabel,
     pub spec_path: String,
     pub exit_signals: String,
+    pub overlay: ProjectOverlay,
+}
+
+impl Budgeted for PlanNewContext {
+    fn trimmable(&mut self) -> Vec<(&'static str, &mut String)> {
+        vec![("pinned_context", &mut self.pinned_context)]
+    }
 }
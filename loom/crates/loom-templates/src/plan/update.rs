@@ -1,6 +1,9 @@
 use askama::Template;
 use loom_core::identifier::SpecLabel;
 
+use crate::budget::Budgeted;
+use crate::overlay::ProjectOverlay;
+
 /// Context for `loom plan -u <label>` (update-spec interview).
 #[derive(Template)]
 #[template(path = "plan_update.md", escape = "none")]
@@ -10,4 +13,11 @@ pub struct PlanUpdateContext {
     pub spec_path: String,
     pub companion_paths: Vec<String>,
     pub exit_signals: String,
+    pub overlay: ProjectOverlay,
+}
+
+impl Budgeted for PlanUpdateContext {
+    fn trimmable(&mut self) -> Vec<(&'static str, &mut String)> {
+        vec![("pinned_context", &mut self.pinned_context)]
+    }
 }
@@ -3,6 +3,9 @@
 use askama::Template;
 use loom_core::identifier::{BeadId, MoleculeId, SpecLabel};
 
+use crate::budget::{self, Budgeted};
+use crate::overlay::ProjectOverlay;
+
 /// Maximum length of the agent-supplied previous-failure body before truncation.
 ///
 /// Keeps the retry prompt below the agent's effective context limit and matches
@@ -57,4 +60,20 @@ pub struct RunContext {
     pub description: Option<String>,
     pub previous_failure: Option<PreviousFailure>,
     pub exit_signals: String,
+    pub overlay: ProjectOverlay,
+}
+
+/// Pinned context goes before the bead's own description; the previous
+/// failure is what a retry is about and is cut last.
+impl Budgeted for RunContext {
+    fn trimmable(&mut self) -> Vec<(&'static str, &mut String)> {
+        std::iter::once(("pinned_context", &mut self.pinned_context))
+            .chain(budget::optional("description", &mut self.description))
+            .chain(
+                self.previous_failure
+                    .as_mut()
+                    .map(|failure| ("previous_failure", &mut failure.0)),
+            )
+            .collect()
+    }
 }
@@ -1,6 +1,9 @@
 use askama::Template;
 use loom_core::identifier::SpecLabel;
 
+use crate::budget::Budgeted;
+use crate::overlay::ProjectOverlay;
+
 /// Context for `loom todo` decomposing a fresh spec into a new molecule.
 #[derive(Template)]
 #[template(path = "todo_new.md", escape = "none")]
@@ -11,4 +14,11 @@ pub struct TodoNewContext {
     pub companion_paths: Vec<String>,
     pub implementation_notes: Vec<String>,
     pub exit_signals: String,
+    pub overlay: ProjectOverlay,
+}
+
+impl Budgeted for TodoNewContext {
+    fn trimmable(&mut self) -> Vec<(&'static str, &mut String)> {
+        vec![("pinned_context", &mut self.pinned_context)]
+    }
 }
@@ -1,6 +1,9 @@
 use askama::Template;
 use loom_core::identifier::{MoleculeId, SpecLabel};
 
+use crate::budget::{self, Budgeted};
+use crate::overlay::ProjectOverlay;
+
 /// Context for `loom todo` adding tasks to an existing molecule (anchor + siblings).
 #[derive(Template)]
 #[template(path = "todo_update.md", escape = "none")]
@@ -14,4 +17,20 @@ pub struct TodoUpdateContext {
     pub existing_tasks: Option<String>,
     pub molecule_id: Option<MoleculeId>,
     pub exit_signals: String,
+    pub overlay: ProjectOverlay,
+}
+
+/// The spec diff is the usual culprit and goes first, then the molecule's
+/// existing tasks; pinned context is cut last.
+impl Budgeted for TodoUpdateContext {
+    fn trimmable(&mut self) -> Vec<(&'static str, &mut String)> {
+        [
+            budget::optional("spec_diff", &mut self.spec_diff),
+            budget::optional("existing_tasks", &mut self.existing_tasks),
+        ]
+        .into_iter()
+        .flatten()
+        .chain([("pinned_context", &mut self.pinned_context)])
+        .collect()
+    }
 }
@@ -11,8 +11,10 @@
 use anyhow::Result;
 use askama::Template;
 use loom_core::identifier::{BeadId, MoleculeId, SpecLabel};
+use loom_templates::budget::{Budgeted, MIN_KEPT_CHARS, Truncation, estimate_tokens};
 use loom_templates::check::CheckContext;
 use loom_templates::msg::{ClarifyBead, ClarifyOption, MsgContext};
+use loom_templates::overlay::{OverlayFragment, ProjectOverlay};
 use loom_templates::plan::{PlanNewContext, PlanUpdateContext};
 use loom_templates::run::{PREVIOUS_FAILURE_MAX_LEN, PreviousFailure, RunContext};
 use loom_templates::todo::{TodoNewContext, TodoUpdateContext};
@@ -28,6 +30,7 @@ fn plan_new_renders_partials_and_inputs() -> Result<()> {
         label: SpecLabel::new("loom-harness"),
         spec_path: "specs/loom-harness.md".to_string(),
         exit_signals: EXIT_SIGNALS_BODY.to_string(),
+        overlay: ProjectOverlay::default(),
     };
     let out = ctx.render()?;
 
@@ -49,6 +52,7 @@ fn plan_update_renders_partials_and_companions() -> Result<()> {
         spec_path: "specs/loom-harness.md".to_string(),
         companion_paths: vec!["lib/sandbox/".into(), "lib/ralph/template/".into()],
         exit_signals: EXIT_SIGNALS_BODY.to_string(),
+        overlay: ProjectOverlay::default(),
     };
     let out = ctx.render()?;
 
@@ -73,6 +77,7 @@ fn todo_new_renders_implementation_notes_when_present() -> Result<()> {
             "Use fenix fromToolchainFile".to_string(),
         ],
         exit_signals: EXIT_SIGNALS_BODY.to_string(),
+        overlay: ProjectOverlay::default(),
     };
     let out = ctx.render()?;
 
@@ -93,6 +98,7 @@ fn todo_new_omits_implementation_notes_section_when_empty() -> Result<()> {
         companion_paths: vec![],
         implementation_notes: vec![],
         exit_signals: EXIT_SIGNALS_BODY.to_string(),
+        overlay: ProjectOverlay::default(),
     };
     let out = ctx.render()?;
 
@@ -112,6 +118,7 @@ fn todo_update_wraps_existing_tasks_in_agent_output() -> Result<()> {
         existing_tasks: Some("- wx-3hhwq.1: scaffold workspace".into()),
         molecule_id: Some(MoleculeId::new("wx-3hhwq")),
         exit_signals: EXIT_SIGNALS_BODY.to_string(),
+        overlay: ProjectOverlay::default(),
     };
     let out = ctx.render()?;
 
@@ -141,6 +148,7 @@ fn run_wraps_agent_supplied_fields_in_agent_output() -> Result<()> {
         description: Some("Port templates to Askama.".into()),
         previous_failure: Some(PreviousFailure::new("error: cargo test failed".to_string())),
         exit_signals: EXIT_SIGNALS_BODY.to_string(),
+        overlay: ProjectOverlay::default(),
     };
     let out = ctx.render()?;
 
@@ -159,6 +167,65 @@ fn run_wraps_agent_supplied_fields_in_agent_output() -> Result<()> {
     Ok(())
 }
 
+fn fragment(source: &str, body: &str) -> OverlayFragment {
+    OverlayFragment {
+        source: source.into(),
+        body: body.into(),
+    }
+}
+
+#[test]
+fn overlay_fragments_render_into_pre_and_post_slots() -> Result<()> {
+    let overlay = ProjectOverlay {
+        pre: vec![fragment(".wrapix/loom/prompts/run.pre.md", "PRE-RUN")],
+        post: vec![
+            fragment(".wrapix/loom/prompts/run.post.md", "POST-RUN"),
+            fragment(".wrapix/loom/prompts/profile/rust.md", "- [ ] cargo clippy"),
+        ],
+    };
+    let run = RunContext {
+        pinned_context: PINNED_CONTEXT_BODY.to_string(),
+        label: SpecLabel::new("loom-harness"),
+        spec_path: "specs/loom-harness.md".to_string(),
+        companion_paths: vec![],
+        molecule_id: None,
+        issue_id: Some(BeadId::new("wx-3hhwq.10")?),
+        title: None,
+        description: None,
+        previous_failure: None,
+        exit_signals: EXIT_SIGNALS_BODY.to_string(),
+        overlay: overlay.clone(),
+    }
+    .render()?;
+    let at = |needle: &str| {
+        run.find(needle)
+            .unwrap_or_else(|| panic!("{needle} missing"))
+    };
+    assert!(run.contains("<!-- .wrapix/loom/prompts/run.pre.md -->\nPRE-RUN\n\n"));
+    assert!(at(PINNED_CONTEXT_BODY) < at("PRE-RUN"));
+    assert!(at("PRE-RUN") < at("## Current Feature"));
+    assert!(at("## Quality Gates") < at("POST-RUN"));
+    assert!(at("POST-RUN") < at("- [ ] cargo clippy"));
+    assert!(at("- [ ] cargo clippy") < at("## Land the Plane"));
+
+    let check = CheckContext {
+        pinned_context: PINNED_CONTEXT_BODY.to_string(),
+        label: SpecLabel::new("loom-harness"),
+        spec_path: "specs/loom-harness.md".to_string(),
+        companion_paths: vec![],
+        beads_summary: None,
+        base_commit: None,
+        molecule_id: None,
+        exit_signals: EXIT_SIGNALS_BODY.to_string(),
+        overlay,
+    }
+    .render()?;
+    let post = check.find("POST-RUN").unwrap_or_default();
+    assert!(post > check.find("## Completion").unwrap_or_default());
+    assert!(post < check.find("## Exit Signals").unwrap_or_default());
+    Ok(())
+}
+
 #[test]
 fn previous_failure_truncates_at_max_len() {
     let huge = "x".repeat(PREVIOUS_FAILURE_MAX_LEN * 2);
@@ -184,6 +251,7 @@ fn check_renders_review_context_fields() -> Result<()> {
         base_commit: Some("abc1234".into()),
         molecule_id: Some(MoleculeId::new("wx-3hhwq")),
         exit_signals: EXIT_SIGNALS_BODY.to_string(),
+        overlay: ProjectOverlay::default(),
     };
     let out = ctx.render()?;
 
@@ -261,6 +329,7 @@ fn run_renders_expected_sections_for_shared_inputs() -> Result<()> {
         description: Some("the description".into()),
         previous_failure: None,
         exit_signals: "- `LOOM_COMPLETE`".into(),
+        overlay: ProjectOverlay::default(),
     };
     let out = ctx.render()?;
 
@@ -285,3 +354,65 @@ fn run_renders_expected_sections_for_shared_inputs() -> Result<()> {
     }
     Ok(())
 }
+
+fn todo_update_with_diff(spec_diff: String) -> TodoUpdateContext {
+    TodoUpdateContext {
+        pinned_context: PINNED_CONTEXT_BODY.to_string(),
+        label: SpecLabel::new("loom-harness"),
+        spec_path: "specs/loom-harness.md".to_string(),
+        companion_paths: vec![],
+        implementation_notes: vec![],
+        spec_diff: Some(spec_diff),
+        existing_tasks: Some("- wx-3hhwq.1: scaffold workspace".into()),
+        molecule_id: Some(MoleculeId::new("wx-3hhwq")),
+        exit_signals: EXIT_SIGNALS_BODY.to_string(),
+        overlay: ProjectOverlay::default(),
+    }
+}
+
+#[test]
+fn fit_leaves_a_context_within_budget_untouched() -> Result<()> {
+    let mut ctx = todo_update_with_diff("+ one line".into());
+    let before = ctx.render()?;
+    assert!(ctx.fit(estimate_tokens(&before))?.is_empty());
+    assert_eq!(ctx.render()?, before);
+    Ok(())
+}
+
+#[test]
+fn fit_cuts_the_spec_diff_first_and_marks_the_cut() -> Result<()> {
+    let diff = "+ requirement ñ\n".repeat(5_000);
+    let original = diff.chars().count();
+    let mut ctx = todo_update_with_diff(diff);
+    let cuts = ctx.fit(2_000)?;
+
+    assert_eq!(cuts.len(), 1);
+    let Truncation {
+        field,
+        original_chars,
+        kept_chars,
+    } = cuts[0];
+    assert_eq!((field, original_chars), ("spec_diff", original));
+    assert!(kept_chars < original);
+    let out = ctx.render()?;
+    assert!(estimate_tokens(&out) <= 2_000);
+    assert!(out.contains(&format!(
+        "[… truncated by loom to fit the prompt budget: kept {kept_chars} of {original} chars]"
+    )));
+    assert!(out.contains("- wx-3hhwq.1: scaffold workspace"));
+    assert!(out.contains(PINNED_CONTEXT_BODY));
+    Ok(())
+}
+
+#[test]
+fn fit_moves_down_the_priorities_and_keeps_a_floor() -> Result<()> {
+    let mut ctx = todo_update_with_diff("d".repeat(4 * MIN_KEPT_CHARS));
+    ctx.existing_tasks = Some("t".repeat(4 * MIN_KEPT_CHARS));
+    ctx.pinned_context = "p".repeat(4 * MIN_KEPT_CHARS);
+    let cuts = ctx.fit(1)?;
+
+    let fields: Vec<_> = cuts.iter().map(|c| c.field).collect();
+    assert_eq!(fields, ["spec_diff", "existing_tasks", "pinned_context"]);
+    assert!(cuts.iter().all(|c| c.kept_chars >= MIN_KEPT_CHARS));
+    Ok(())
+}
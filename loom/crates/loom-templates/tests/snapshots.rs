@@ -13,7 +13,9 @@
 use askama::Template;
 use loom_core::identifier::{BeadId, MoleculeId, SpecLabel};
 use loom_templates::check::CheckContext;
+use loom_templates::judge::{JudgeContext, JudgeFile};
 use loom_templates::msg::{ClarifyBead, ClarifyOption, MsgContext};
+use loom_templates::overlay::ProjectOverlay;
 use loom_templates::plan::{PlanNewContext, PlanUpdateContext};
 use loom_templates::run::{PreviousFailure, RunContext};
 use loom_templates::todo::{TodoNewContext, TodoUpdateContext};
@@ -29,6 +31,7 @@ fn plan_new_snapshot() {
         label: SpecLabel::new("loom-harness"),
         spec_path: "specs/loom-harness.md".to_string(),
         exit_signals: EXIT_SIGNALS_BODY.to_string(),
+        overlay: ProjectOverlay::default(),
     };
     insta::assert_snapshot!(ctx.render().unwrap());
 }
@@ -41,6 +44,7 @@ fn plan_update_snapshot() {
         spec_path: "specs/loom-harness.md".to_string(),
         companion_paths: vec!["lib/sandbox/".into(), "lib/ralph/template/".into()],
         exit_signals: EXIT_SIGNALS_BODY.to_string(),
+        overlay: ProjectOverlay::default(),
     };
     insta::assert_snapshot!(ctx.render().unwrap());
 }
@@ -57,6 +61,7 @@ fn todo_new_snapshot() {
             "Use fenix fromToolchainFile".to_string(),
         ],
         exit_signals: EXIT_SIGNALS_BODY.to_string(),
+        overlay: ProjectOverlay::default(),
     };
     insta::assert_snapshot!(ctx.render().unwrap());
 }
@@ -73,6 +78,7 @@ fn todo_update_snapshot() {
         existing_tasks: Some("- wx-3hhwq.1: scaffold workspace".into()),
         molecule_id: Some(MoleculeId::new("wx-3hhwq")),
         exit_signals: EXIT_SIGNALS_BODY.to_string(),
+        overlay: ProjectOverlay::default(),
     };
     insta::assert_snapshot!(ctx.render().unwrap());
 }
@@ -90,6 +96,7 @@ fn run_snapshot() {
         description: Some("Port templates to Askama.".into()),
         previous_failure: Some(PreviousFailure::new("error: cargo test failed".to_string())),
         exit_signals: EXIT_SIGNALS_BODY.to_string(),
+        overlay: ProjectOverlay::default(),
     };
     insta::assert_snapshot!(ctx.render().unwrap());
 }
@@ -105,6 +112,25 @@ fn check_snapshot() {
         base_commit: Some("abc1234".into()),
         molecule_id: Some(MoleculeId::new("wx-3hhwq")),
         exit_signals: EXIT_SIGNALS_BODY.to_string(),
+        overlay: ProjectOverlay::default(),
+    };
+    insta::assert_snapshot!(ctx.render().unwrap());
+}
+
+#[test]
+fn judge_snapshot() {
+    let ctx = JudgeContext {
+        criterion: "loom spec --verify reports PASS/FAIL/SKIP per criterion".into(),
+        files: vec![
+            JudgeFile {
+                path: "crates/loom-workflow/src/spec/checks.rs".into(),
+                body: "pub fn render() {}".into(),
+            },
+            JudgeFile {
+                path: "crates/loom/src/main.rs".into(),
+                body: "fn main() {}".into(),
+            },
+        ],
     };
     insta::assert_snapshot!(ctx.render().unwrap());
 }
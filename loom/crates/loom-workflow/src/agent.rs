@@ -9,39 +9,201 @@
 //! is generic over the backend.
 //!
 //! The function consumes events from the typestate session until it observes
-//! `AgentEvent::SessionComplete`, draining and (currently) discarding
-//! intermediate events. Subsequent issues will tee the event stream into the
-//! per-bead NDJSON log + terminal renderer; the surface here stays fixed so
-//! that wiring is local.
+//! `AgentEvent::SessionComplete`, folding them into a [`FailureDigest`] for
+//! the retry prompt. When the caller opened a [`SessionLog`], the same
+//! event stream is teed into the session's NDJSON log and — with `[logs]
+//! raw_wire` — its raw stdio into the wire capture beside it.
 
-use loom_core::agent::{AgentBackend, AgentEvent, ProtocolError, SessionOutcome, SpawnConfig};
-use tracing::trace;
+use std::path::PathBuf;
+
+use loom_core::agent::{
+    AgentBackend, AgentEvent, AgentSession, Idle, ProtocolError, SessionOutcome, SpawnConfig,
+};
+use loom_core::logging::{LogError, LogSink, WireSink};
+use serde::Serialize;
+use tracing::{trace, warn};
+
+use crate::run::FailureDigest;
+
+/// The on-disk record of one session: its NDJSON event log and, when
+/// `[logs] raw_wire` is on, the raw wire capture named after it.
+pub struct SessionLog {
+    events: LogSink,
+    wire: Option<WireSink>,
+}
+
+impl SessionLog {
+    /// Open the event log at `log_path` and, with `raw_wire`, the capture
+    /// beside it.
+    pub fn open(log_path: PathBuf, raw_wire: bool) -> Result<Self, LogError> {
+        let wire = raw_wire.then(|| WireSink::beside(&log_path)).transpose()?;
+        Ok(Self {
+            events: LogSink::open(log_path, None)?,
+            wire,
+        })
+    }
+
+    /// Write a loom record — not an agent event — to the event log, ahead
+    /// of the session's events.
+    pub fn record<T: Serialize + ?Sized>(&mut self, record: &T) -> Result<(), LogError> {
+        self.events.record(record)
+    }
+
+    /// Attach the wire capture, if any, to `session`; the event log stays
+    /// with the caller.
+    fn attach(self, session: AgentSession<Idle>) -> (AgentSession<Idle>, LogSink) {
+        let session = match self.wire {
+            Some(sink) => session.with_wire_capture(sink),
+            None => session,
+        };
+        (session, self.events)
+    }
+}
+
+/// `{"kind":"attempt","attempt":…,"model":…}`: the first line of a `loom
+/// run` bead session's log, naming the retry it is and the model it runs
+/// on (`null` for the phase's model).
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind", rename = "attempt")]
+pub struct AttemptHeader<'a> {
+    pub attempt: u32,
+    pub model: Option<&'a str>,
+}
+
+/// `{"kind":"failure_digest","text":…}`: the [`FailureDigest`] of a failed
+/// session, written to its log just before the closing `session_complete`.
+#[derive(Serialize)]
+#[serde(tag = "kind", rename = "failure_digest")]
+struct DigestRecord<'a> {
+    text: &'a str,
+}
+
+/// Write to the session log. A log that stops accepting writes is reported
+/// once and then left alone — losing the log never fails the session.
+fn append(log: &mut Option<LogSink>, write: impl FnOnce(&mut LogSink) -> Result<(), LogError>) {
+    if let Some(sink) = log
+        && let Err(e) = write(sink)
+    {
+        warn!(log_path = %sink.log_path().display(), error = %e, "session log write failed");
+        *log = None;
+    }
+}
+
+/// A finished session and the digest of its events, rendered into the next
+/// attempt's `previous_failure` when it failed.
+#[derive(Debug, Clone)]
+pub struct AgentRun {
+    pub outcome: SessionOutcome,
+    pub digest: FailureDigest,
+}
+
+impl AgentRun {
+    /// The rendered digest when the session failed — a non-zero exit or a
+    /// `LOOM_BLOCKED` final message — else `None`.
+    pub fn previous_failure(&self) -> Option<String> {
+        self.digest.failure(self.outcome.exit_code)
+    }
+}
 
 /// Drive `B` through one full session: spawn, prompt, then consume events
 /// until `SessionComplete` arrives. Returns the resulting [`SessionOutcome`]
-/// (exit code + cost, when surfaced by the backend).
+/// (exit code + cost, when surfaced by the backend) with the session's
+/// [`FailureDigest`]. When `log` is set, every event is written to it as
+/// well, and a failed session's rendered digest is recorded ahead of its
+/// `session_complete`.
 ///
 /// `UnexpectedEof` is returned if the agent process closes its stdout
 /// without emitting a terminal event — this signals the caller that the
 /// session ended abnormally and the outcome is not trustworthy.
 pub async fn run_agent<B: AgentBackend>(
     config: &SpawnConfig,
-) -> Result<SessionOutcome, ProtocolError> {
+    log: Option<SessionLog>,
+) -> Result<AgentRun, ProtocolError> {
+    let (session, mut events) = spawn_logged::<B>(config, log).await?;
+    let mut digest = FailureDigest::default();
+    let outcome = drive_session(session, &config.initial_prompt, |event| {
+        trace!(?event, "agent event");
+        digest.observe(event);
+        if let AgentEvent::SessionComplete { exit_code, .. } = event
+            && let Some(text) = digest.failure(*exit_code)
+        {
+            append(&mut events, |sink| {
+                sink.record(&DigestRecord { text: &text })
+            });
+        }
+        append(&mut events, |sink| sink.emit(event));
+    })
+    .await?;
+    Ok(AgentRun { outcome, digest })
+}
+
+/// A finished session and everything the agent said in it.
+#[derive(Debug, Clone)]
+pub struct AgentReply {
+    pub outcome: SessionOutcome,
+    /// Concatenated `MessageDelta` text.
+    pub text: String,
+}
+
+/// [`run_agent`] for one-shot questions whose answer is the agent's text
+/// (`loom spec --judge`): the same session, with message deltas collected
+/// instead of discarded.
+pub async fn ask_agent<B: AgentBackend>(
+    config: &SpawnConfig,
+    log: Option<SessionLog>,
+) -> Result<AgentReply, ProtocolError> {
+    let (session, mut events) = spawn_logged::<B>(config, log).await?;
+    let mut text = String::new();
+    let outcome = drive_session(session, &config.initial_prompt, |event| {
+        trace!(?event, "agent event");
+        append(&mut events, |sink| sink.emit(event));
+        if let AgentEvent::MessageDelta { text: delta } = event {
+            text.push_str(delta);
+        }
+    })
+    .await?;
+    Ok(AgentReply { outcome, text })
+}
+
+/// Spawn `B` and attach `log`'s wire capture, handing back the event log.
+async fn spawn_logged<B: AgentBackend>(
+    config: &SpawnConfig,
+    log: Option<SessionLog>,
+) -> Result<(AgentSession<Idle>, Option<LogSink>), ProtocolError> {
     let session = B::spawn(config).await?;
-    let mut session = session.prompt(&config.initial_prompt).await?;
+    Ok(match log {
+        Some(log) => {
+            let (session, events) = log.attach(session);
+            (session, Some(events))
+        }
+        None => (session, None),
+    })
+}
+
+/// The driver half of [`run_agent`]: prompt an already-spawned session and
+/// hand every event to `on_event` until `SessionComplete` arrives. Split out
+/// so a session built outside `AgentBackend::spawn` — a replayed wire
+/// capture in `loom replay` or a workflow test — runs the same loop.
+pub async fn drive_session(
+    session: AgentSession<Idle>,
+    prompt: &str,
+    mut on_event: impl FnMut(&AgentEvent),
+) -> Result<SessionOutcome, ProtocolError> {
+    let mut session = session.prompt(prompt).await?;
     loop {
         match session.next_event().await? {
-            Some(AgentEvent::SessionComplete {
-                exit_code,
-                cost_usd,
-            }) => {
-                return Ok(SessionOutcome {
+            Some(event) => {
+                on_event(&event);
+                if let AgentEvent::SessionComplete {
                     exit_code,
                     cost_usd,
-                });
-            }
-            Some(event) => {
-                trace!(?event, "agent event");
+                } = event
+                {
+                    return Ok(SessionOutcome {
+                        exit_code,
+                        cost_usd,
+                    });
+                }
             }
             None => return Err(ProtocolError::UnexpectedEof),
         }
@@ -1,6 +1,7 @@
 use loom_core::bd::Bead;
 use loom_core::identifier::{MoleculeId, SpecLabel};
 use loom_templates::check::CheckContext;
+use loom_templates::overlay::ProjectOverlay;
 
 /// Inputs for [`build_check_context`]. Constructed once per `loom check`
 /// invocation; the reviewer only runs once per molecule per gate pass.
@@ -13,6 +14,8 @@ pub struct CheckContextInputs {
     pub base_commit: Option<String>,
     pub beads_summary: Option<String>,
     pub exit_signals: String,
+    /// Project prompt fragments for the template's overlay slots.
+    pub overlay: ProjectOverlay,
 }
 
 /// Render the typed [`CheckContext`] used by the `check.md` Askama template.
@@ -26,6 +29,7 @@ pub fn build_check_context(inputs: CheckContextInputs) -> CheckContext {
         base_commit: inputs.base_commit,
         molecule_id: inputs.molecule_id,
         exit_signals: inputs.exit_signals,
+        overlay: inputs.overlay,
     }
 }
 
@@ -66,6 +70,13 @@ mod tests {
             priority: 2,
             issue_type: "task".into(),
             labels: vec![],
+            created_at: None,
+            updated_at: None,
+            closed_at: None,
+            notes: String::new(),
+            assignee: None,
+            parent: None,
+            dependencies: Vec::new(),
         }
     }
 
@@ -79,6 +90,7 @@ mod tests {
             base_commit: Some("abc123".into()),
             beads_summary: Some("- wx-1: First [open]".into()),
             exit_signals: String::new(),
+            overlay: ProjectOverlay::default(),
         }
     }
 
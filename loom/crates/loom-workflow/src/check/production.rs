@@ -15,7 +15,7 @@
 
 use std::path::PathBuf;
 
-use loom_core::bd::{BdClient, Bead, ListOpts, UpdateOpts};
+use loom_core::bd::{BdBackend, BdClient, BdStats, Bead, ListOpts, UpdateOpts};
 use loom_core::identifier::{BeadId, SpecLabel};
 use tokio::process::Command;
 use tracing::warn;
@@ -45,6 +45,11 @@ impl ProductionCheckController {
         }
     }
 
+    /// `bd` usage by this controller so far, for the end-of-loop summary.
+    pub fn bd_stats(&self) -> BdStats {
+        self.bd.stats()
+    }
+
     fn spec_label_filter(&self) -> String {
         format!("spec:{}", self.label.as_str())
     }
@@ -60,6 +65,9 @@ impl ProductionCheckController {
 
 impl CheckController for ProductionCheckController {
     async fn run_review(&mut self) -> Result<ReviewOutcome, CheckError> {
+        // The reviewer files follow-up beads through bd itself, so the
+        // post-review snapshot must not come from the cache.
+        self.bd.invalidate_reads();
         warn!(
             label = %self.label,
             "loom check: agent backend stub — returning Incomplete (loom-agent crate is empty)",
@@ -132,6 +140,7 @@ impl CheckController for ProductionCheckController {
     async fn exec_run(&mut self) -> Result<(), CheckError> {
         let status = Command::new(&self.loom_bin)
             .current_dir(&self.workspace)
+            .env(BdBackend::ENV, self.bd.runner().name())
             .arg("run")
             .arg("-s")
             .arg(self.label.as_str())
@@ -8,6 +8,8 @@ use loom_core::bd::BdError;
 use loom_core::lock::LockError;
 use loom_core::state::StateError;
 
+use crate::prompt::PromptError;
+
 /// Failures raised by [`super::run`] and [`super::fetch_active_molecules`].
 #[derive(Debug, Display, Error)]
 pub enum InitError {
@@ -31,6 +33,9 @@ pub enum InitError {
     /// state-db operation failed
     State(#[from] StateError),
 
+    /// scaffolding prompt overlays failed
+    Prompt(#[from] PromptError),
+
     /// `bd` CLI invocation failed while gathering active molecules
     Bd(#[from] BdError),
 
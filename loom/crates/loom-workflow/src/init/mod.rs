@@ -21,6 +21,8 @@ use loom_core::identifier::MoleculeId;
 use loom_core::lock::LockManager;
 use loom_core::state::{ActiveMolecule, RebuildReport, StateDb};
 
+use crate::prompt;
+
 pub use error::InitError;
 
 /// Default body for `.wrapix/loom/config.toml`. Mirrors the Configuration
@@ -35,6 +37,8 @@ const ACTIVE_LABEL: &str = "loom:active";
 pub struct InitOpts {
     /// Drop and repopulate the state DB from on-disk specs + active beads.
     pub rebuild: bool,
+    /// Scaffold the `.wrapix/loom/prompts/` overlay stubs.
+    pub prompts: bool,
 }
 
 /// Files touched by [`run`] and (optionally) the rebuild report.
@@ -44,6 +48,9 @@ pub struct InitReport {
     pub state_db_path: PathBuf,
     pub config_created: bool,
     pub rebuild: Option<RebuildReport>,
+    /// Overlay files written by `opts.prompts`; empty when none were asked
+    /// for or all already existed.
+    pub prompts_created: Vec<PathBuf>,
 }
 
 /// Run `loom init` against `workspace`.
@@ -55,6 +62,8 @@ pub struct InitReport {
 /// 3. Opens `state.db` (creating the schema on first open). When
 ///    `opts.rebuild` is true, the file is dropped and recreated, and the
 ///    schema is repopulated from `specs/*.md` plus `molecules`.
+/// 4. When `opts.prompts` is true, scaffolds the prompt-overlay stubs via
+///    [`crate::prompt::scaffold`], keeping any that already exist.
 pub fn run(
     workspace: &Path,
     opts: InitOpts,
@@ -88,11 +97,18 @@ pub fn run(
         None
     };
 
+    let prompts_created = if opts.prompts {
+        prompt::scaffold(workspace)?
+    } else {
+        Vec::new()
+    };
+
     Ok(InitReport {
         config_path,
         state_db_path,
         config_created,
         rebuild: rebuild_report,
+        prompts_created,
     })
 }
 
@@ -176,6 +192,28 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn prompts_opt_scaffolds_overlay_stubs() -> Result<()> {
+        let dir = temp_workspace()?;
+        let plain = run(dir.path(), InitOpts::default(), &[])?;
+        assert!(plain.prompts_created.is_empty());
+        assert!(!dir.path().join(prompt::PROMPTS_DIR).exists());
+
+        let opts = InitOpts {
+            prompts: true,
+            ..InitOpts::default()
+        };
+        let report = run(dir.path(), opts, &[])?;
+        assert!(
+            report
+                .prompts_created
+                .contains(&dir.path().join(prompt::PROMPTS_DIR).join("run.post.md")),
+            "{:?}",
+            report.prompts_created
+        );
+        Ok(())
+    }
+
     #[test]
     fn rebuild_drops_and_repopulates_state_db() -> Result<()> {
         let dir = temp_workspace()?;
@@ -200,7 +238,10 @@ mod tests {
 
         let report = run(
             dir.path(),
-            InitOpts { rebuild: true },
+            InitOpts {
+                rebuild: true,
+                ..InitOpts::default()
+            },
             &[ActiveMolecule {
                 id: MoleculeId::new("wx-mol.1"),
                 spec_label: SpecLabel::new("alpha"),
@@ -16,13 +16,16 @@ pub mod init;
 pub mod logs_cmd;
 pub mod msg;
 pub mod plan;
+pub mod prompt;
 pub mod run;
 pub mod spec;
 pub mod status;
 pub mod todo;
 pub mod use_spec;
 
-pub use agent::run_agent;
+pub use agent::{
+    AgentReply, AgentRun, AttemptHeader, SessionLog, ask_agent, drive_session, run_agent,
+};
 pub use loom_core::agent::{
     Active, AgentBackend, AgentEvent, AgentKind, AgentSession, CompactionReason, Idle, LineParse,
     MAX_LINE_BYTES, NdjsonReader, ParsedLine, ProtocolError, RePinContent, SessionOutcome,
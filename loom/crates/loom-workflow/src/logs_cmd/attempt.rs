@@ -0,0 +1,291 @@
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use serde_json::Value;
+
+use loom_core::identifier::{BeadId, SpecLabel};
+use loom_core::logging::parse_utc_timestamp;
+
+use super::LOG_EXTENSION;
+use super::error::LogsError;
+use super::select::{LogsOpts, matching_logs};
+
+/// How one recorded attempt ended, inferred from its NDJSON events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttemptOutcome {
+    /// Last `session_complete` carried `exit_code = 0`.
+    Done,
+    /// Non-zero `session_complete`, or an `error` event with no completion.
+    Failed,
+    /// No terminal event yet — still running, or the process was killed.
+    Incomplete,
+}
+
+impl AttemptOutcome {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Done => "done",
+            Self::Failed => "failed",
+            Self::Incomplete => "incomplete",
+        }
+    }
+}
+
+/// Folds NDJSON events into an [`AttemptOutcome`] as they are read. Shared
+/// by [`read_attempt`] (whole file) and incremental tailers.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OutcomeTracker {
+    exit_code: Option<i64>,
+    errored: bool,
+}
+
+impl OutcomeTracker {
+    /// Record one decoded log line. Only `session_complete` and `error`
+    /// events affect the outcome.
+    pub fn observe(&mut self, event: &Value) {
+        match event.get("kind").and_then(Value::as_str) {
+            Some("session_complete") => {
+                self.exit_code = event.get("exit_code").and_then(Value::as_i64);
+            }
+            Some("error") => self.errored = true,
+            _ => {}
+        }
+    }
+
+    pub fn outcome(&self) -> AttemptOutcome {
+        match self.exit_code {
+            Some(0) => AttemptOutcome::Done,
+            Some(_) => AttemptOutcome::Failed,
+            None if self.errored => AttemptOutcome::Failed,
+            None => AttemptOutcome::Incomplete,
+        }
+    }
+
+    /// `true` once a `session_complete` has been observed — nothing more
+    /// will be appended to the log.
+    pub fn completed(&self) -> bool {
+        self.exit_code.is_some()
+    }
+}
+
+/// One per-bead log file read back as an attempt.
+#[derive(Debug, Clone)]
+pub struct Attempt {
+    pub bead: BeadId,
+    pub path: PathBuf,
+    /// Parsed from the `<bead>-<utc>` filename stamp.
+    pub started: SystemTime,
+    pub outcome: AttemptOutcome,
+    /// Last write (file mtime) minus `started`. `None` when the mtime is
+    /// unavailable or precedes the stamp.
+    pub duration: Option<Duration>,
+}
+
+/// One `<bead>-<utc>.ndjson` file located by [`list_logs`]; the body has
+/// not been read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogFile {
+    pub bead: BeadId,
+    pub started: SystemTime,
+    pub path: PathBuf,
+}
+
+/// Every per-bead log under `<logs_root>/<spec>/`, oldest first by the
+/// filename stamp. Files that do not follow the `bead_log_path` layout are
+/// skipped. A missing spec directory yields an empty list.
+pub fn list_logs(logs_root: &Path, spec: &SpecLabel) -> Result<Vec<LogFile>, LogsError> {
+    let dir = logs_root.join(spec.as_str());
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut logs = Vec::new();
+    for entry in std::fs::read_dir(&dir)? {
+        let path = entry?.path();
+        if path.extension().and_then(|e| e.to_str()) != Some(LOG_EXTENSION) {
+            continue;
+        }
+        if let Some((bead, started)) = parse_log_stem(&path) {
+            logs.push(LogFile {
+                bead,
+                started,
+                path,
+            });
+        }
+    }
+    logs.sort_by(|a, b| a.started.cmp(&b.started).then_with(|| a.path.cmp(&b.path)));
+    Ok(logs)
+}
+
+/// Read every log passing `opts` as an [`Attempt`], oldest first — the
+/// `loom logs --list` table.
+pub fn list_attempts(logs_root: &Path, opts: LogsOpts<'_>) -> Result<Vec<Attempt>, LogsError> {
+    let mut attempts = Vec::new();
+    for path in matching_logs(logs_root, &opts)? {
+        if let Some(attempt) = read_attempt(&path)? {
+            attempts.push(attempt);
+        }
+    }
+    attempts.sort_by(|a, b| a.started.cmp(&b.started).then_with(|| a.path.cmp(&b.path)));
+    Ok(attempts)
+}
+
+/// Read `path` (a `<bead>-<utc>.ndjson` log) as an [`Attempt`]. Returns
+/// `Ok(None)` when the filename does not follow the `bead_log_path`
+/// layout. Lines that fail to parse as JSON are skipped — a partially
+/// written trailing line must not hide the rest of the log.
+pub fn read_attempt(path: &Path) -> Result<Option<Attempt>, LogsError> {
+    let Some((bead, started)) = parse_log_stem(path) else {
+        return Ok(None);
+    };
+    let file = std::fs::File::open(path)?;
+    let duration = file
+        .metadata()?
+        .modified()
+        .ok()
+        .and_then(|m| m.duration_since(started).ok());
+    let mut tracker = OutcomeTracker::default();
+    for line in std::io::BufReader::new(file).lines() {
+        if let Ok(event) = serde_json::from_str::<Value>(&line?) {
+            tracker.observe(&event);
+        }
+    }
+    Ok(Some(Attempt {
+        bead,
+        path: path.to_path_buf(),
+        started,
+        outcome: tracker.outcome(),
+        duration,
+    }))
+}
+
+/// The most recently started attempt under `<logs_root>/<spec>/`, or
+/// `None` when the spec has no logs yet.
+pub fn latest_attempt(logs_root: &Path, spec: &SpecLabel) -> Result<Option<Attempt>, LogsError> {
+    match list_logs(logs_root, spec)?.pop() {
+        Some(log) => read_attempt(&log.path),
+        None => Ok(None),
+    }
+}
+
+/// Split a `<bead>-<utc>` stem into its bead id and start time.
+pub(super) fn parse_log_stem(path: &Path) -> Option<(BeadId, SystemTime)> {
+    let stem = path.file_stem().and_then(|s| s.to_str())?;
+    let (bead, stamp) = stem.rsplit_once('-')?;
+    Some((BeadId::new(bead).ok()?, parse_utc_timestamp(stamp)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    fn touch(path: &Path, mtime: SystemTime) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, b"event\n")?;
+        let f = std::fs::File::options().write(true).open(path)?;
+        f.set_modified(mtime)?;
+        Ok(())
+    }
+
+    #[test]
+    fn read_attempt_infers_outcome_from_terminal_events() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let cases = [
+            (
+                "wx-1-20260503T123045Z.ndjson",
+                "{\"kind\":\"turn_end\"}\n{\"kind\":\"session_complete\",\"exit_code\":0,\"cost_usd\":null}\n",
+                AttemptOutcome::Done,
+            ),
+            (
+                "wx-2-20260503T123045Z.ndjson",
+                "{\"kind\":\"session_complete\",\"exit_code\":1,\"cost_usd\":null}\n",
+                AttemptOutcome::Failed,
+            ),
+            (
+                "wx-3-20260503T123045Z.ndjson",
+                "{\"kind\":\"error\",\"message\":\"boom\"}\n{\"kind\":\"tool_c",
+                AttemptOutcome::Failed,
+            ),
+            (
+                "wx-4-20260503T123045Z.ndjson",
+                "{\"kind\":\"turn_end\"}\n",
+                AttemptOutcome::Incomplete,
+            ),
+        ];
+        for (name, body, want) in cases {
+            let path = dir.path().join(name);
+            std::fs::write(&path, body)?;
+            let attempt = read_attempt(&path)?.ok_or_else(|| anyhow::anyhow!("{name}"))?;
+            assert_eq!(attempt.outcome, want, "{name}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn latest_attempt_orders_by_filename_stamp() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let root = dir.path().join(".wrapix/loom/logs");
+        let now = SystemTime::now();
+        // mtime deliberately disagrees with the stamp: the stamp wins.
+        touch(&root.join("alpha/wx-9-20260503T120000Z.ndjson"), now)?;
+        touch(
+            &root.join("alpha/wx-3.1-20260503T130000Z.ndjson"),
+            now - Duration::from_secs(600),
+        )?;
+        touch(&root.join("alpha/notes.txt"), now)?;
+        let attempt = latest_attempt(&root, &SpecLabel::new("alpha"))?
+            .ok_or_else(|| anyhow::anyhow!("expected an attempt"))?;
+        assert_eq!(attempt.bead, BeadId::new("wx-3.1")?);
+        assert_eq!(attempt.outcome, AttemptOutcome::Incomplete);
+        assert!(latest_attempt(&root, &SpecLabel::new("beta"))?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn list_attempts_reports_outcome_and_duration_per_bead() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let root = dir.path().join(".wrapix/loom/logs");
+        let first = root.join("alpha/wx-1-20260503T120000Z.ndjson");
+        let retry = root.join("alpha/wx-1-20260503T121000Z.ndjson");
+        touch(&first, SystemTime::now())?;
+        std::fs::write(
+            &retry,
+            "{\"kind\":\"session_complete\",\"exit_code\":0,\"cost_usd\":null}\n",
+        )?;
+        let started =
+            parse_utc_timestamp("20260503T121000Z").ok_or_else(|| anyhow::anyhow!("stamp"))?;
+        std::fs::File::options()
+            .write(true)
+            .open(&retry)?
+            .set_modified(started + Duration::from_secs(47))?;
+        touch(
+            &root.join("alpha/wx-2-20260503T130000Z.ndjson"),
+            SystemTime::now(),
+        )?;
+
+        let bead = BeadId::new("wx-1")?;
+        let attempts = list_attempts(
+            &root,
+            LogsOpts {
+                bead: Some(&bead),
+                ..LogsOpts::default()
+            },
+        )?;
+        let rows: Vec<(&Path, AttemptOutcome)> = attempts
+            .iter()
+            .map(|a| (a.path.as_path(), a.outcome))
+            .collect();
+        assert_eq!(
+            rows,
+            [
+                (first.as_path(), AttemptOutcome::Incomplete),
+                (retry.as_path(), AttemptOutcome::Done),
+            ]
+        );
+        assert_eq!(attempts[1].duration, Some(Duration::from_secs(47)));
+        Ok(())
+    }
+}
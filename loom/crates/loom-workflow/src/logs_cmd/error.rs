@@ -0,0 +1,23 @@
+use std::path::PathBuf;
+
+use displaydoc::Display;
+use thiserror::Error;
+
+/// Failures raised by the `loom logs` helpers.
+#[derive(Debug, Display, Error)]
+pub enum LogsError {
+    /// io failure while walking logs directory
+    Io(#[from] std::io::Error),
+
+    /// no logs found under {root}
+    NoLogs { root: PathBuf },
+
+    /// no logs found for bead {bead} under {root}
+    NoLogsForBead { bead: String, root: PathBuf },
+
+    /// {path} is not a per-bead log (expected `<bead-id>-<utc>.ndjson`)
+    NotABeadLog { path: PathBuf },
+
+    /// invalid --since '{value}': expected a duration (`30m`, `2h`, `1d`) or a UTC timestamp
+    InvalidSince { value: String },
+}
@@ -0,0 +1,38 @@
+//! `loom logs` — locate, list, render, and follow per-bead NDJSON logs.
+//!
+//! Read-only, no lock acquired (per the lock matrix in
+//! `specs/loom-harness.md`). Logs live at
+//! `<workspace>/.wrapix/loom/logs/<spec-label>/<bead-id>-<utc>.ndjson`.
+//!
+//! - [`select_log`] returns the most recent log, narrowed by [`LogsOpts`]
+//!   (`--bead`, `--spec`, `--since`).
+//! - [`list_attempts`] reads every matching log back as an [`Attempt`]
+//!   (`--list`): the bead id and start time come from the filename, the
+//!   outcome from the terminal events in the body, the duration from the
+//!   last write. [`latest_attempt`] is the single-spec shortcut `loom
+//!   status` uses for its last-run line.
+//! - [`stream_log`] prints one log's events — raw NDJSON or replayed
+//!   through [`TerminalRenderer`](loom_core::logging::TerminalRenderer) so a
+//!   past run reads like live output — filtered by [`EventFilter`]
+//!   (`--tool`, `--errors`), optionally following appended lines until the
+//!   attempt completes (`--follow`).
+//!
+//! Everything here is blocking `std::fs` I/O; following polls on a short
+//! sleep rather than pulling in tokio just to print bytes.
+
+mod attempt;
+mod error;
+mod select;
+mod since;
+mod stream;
+
+pub use attempt::{
+    Attempt, AttemptOutcome, LogFile, OutcomeTracker, latest_attempt, list_attempts, list_logs,
+    read_attempt,
+};
+pub use error::LogsError;
+pub use select::{LogsOpts, select_log};
+pub use since::parse_since;
+pub use stream::{EventFilter, LogReader, StreamOpts, StreamStyle, stream_log};
+
+const LOG_EXTENSION: &str = "ndjson";
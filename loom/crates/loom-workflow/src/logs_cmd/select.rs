@@ -1,44 +1,25 @@
-//! `loom logs` — locate the most recent per-bead NDJSON log on disk.
-//!
-//! Read-only, no lock acquired (per the lock matrix in
-//! `specs/loom-harness.md`). Walks `<workspace>/.wrapix/loom/logs/` for
-//! `*.ndjson` files and returns the path with the largest mtime; with
-//! `--bead <id>` set, only files whose stem starts with `<id>-` are
-//! considered.
-//!
-//! The selection is split out from any I/O so the binary can use the path
-//! to stream contents (e.g. via `tail -f`) without this module pulling in
-//! tokio just to print bytes.
-
 use std::path::{Path, PathBuf};
 use std::time::SystemTime;
 
-use displaydoc::Display;
-use thiserror::Error;
-
-use loom_core::identifier::BeadId;
+use loom_core::identifier::{BeadId, SpecLabel};
 
-const LOG_EXTENSION: &str = "ndjson";
+use super::LOG_EXTENSION;
+use super::attempt::parse_log_stem;
+use super::error::LogsError;
 
-/// Options for [`select_log`].
+/// Filters shared by [`select_log`] and
+/// [`list_attempts`](super::list_attempts). Every field is optional; the
+/// default matches every log under the root.
 #[derive(Debug, Clone, Default)]
 pub struct LogsOpts<'a> {
     /// Restrict the search to files belonging to this bead. When `None`,
     /// the most recent log across every bead in every spec is returned.
     pub bead: Option<&'a BeadId>,
-}
-
-/// Failures raised by [`select_log`].
-#[derive(Debug, Display, Error)]
-pub enum LogsError {
-    /// io failure while walking logs directory
-    Io(#[from] std::io::Error),
-
-    /// no logs found under {root}
-    NoLogs { root: PathBuf },
-
-    /// no logs found for bead {bead} under {root}
-    NoLogsForBead { bead: String, root: PathBuf },
+    /// Restrict the search to `<root>/<spec>/`.
+    pub spec: Option<&'a SpecLabel>,
+    /// Drop logs whose filename stamp is older than this instant. Logs
+    /// without a parseable stamp are dropped too while the filter is set.
+    pub since: Option<SystemTime>,
 }
 
 /// Walk `logs_root` (typically `<workspace>/.wrapix/loom/logs/`) and return
@@ -46,43 +27,63 @@ pub enum LogsError {
 /// `<root>/<spec-label>/<bead-id>-<utc>.ndjson` per the path layout in
 /// `specs/loom-harness.md` *Run UX & Logging*.
 pub fn select_log(logs_root: &Path, opts: LogsOpts<'_>) -> Result<PathBuf, LogsError> {
-    let bead_filter = opts.bead.map(|b| b.as_str().to_string());
     let mut candidates: Vec<(SystemTime, PathBuf)> = Vec::new();
+    for path in matching_logs(logs_root, &opts)? {
+        let mtime = std::fs::metadata(&path)?
+            .modified()
+            .unwrap_or(SystemTime::UNIX_EPOCH);
+        candidates.push((mtime, path));
+    }
+    candidates.sort_by_key(|c| std::cmp::Reverse(c.0));
+    match candidates.into_iter().next() {
+        Some((_, path)) => Ok(path),
+        None => missing(logs_root, opts.bead),
+    }
+}
+
+/// Every `*.ndjson` file under `logs_root` that passes `opts`, in directory
+/// order. A missing root yields an empty list.
+pub(super) fn matching_logs(
+    logs_root: &Path,
+    opts: &LogsOpts<'_>,
+) -> Result<Vec<PathBuf>, LogsError> {
+    let mut out = Vec::new();
     if !logs_root.exists() {
-        return missing(logs_root, opts.bead);
+        return Ok(out);
     }
-    for spec_entry in std::fs::read_dir(logs_root)? {
-        let spec_entry = spec_entry?;
-        let spec_path = spec_entry.path();
+    let spec_dirs: Vec<PathBuf> = match opts.spec {
+        Some(spec) => vec![logs_root.join(spec.as_str())],
+        None => std::fs::read_dir(logs_root)?
+            .map(|e| e.map(|e| e.path()))
+            .collect::<Result<_, _>>()?,
+    };
+    for spec_path in spec_dirs {
         if !spec_path.is_dir() {
             continue;
         }
         for bead_entry in std::fs::read_dir(&spec_path)? {
-            let bead_entry = bead_entry?;
-            let path = bead_entry.path();
+            let path = bead_entry?.path();
             if !path.is_file() {
                 continue;
             }
             if path.extension().and_then(|e| e.to_str()) != Some(LOG_EXTENSION) {
                 continue;
             }
-            if let Some(prefix) = &bead_filter {
-                if !file_stem_belongs_to(&path, prefix) {
-                    continue;
+            if let Some(bead) = opts.bead
+                && !file_stem_belongs_to(&path, bead.as_str())
+            {
+                continue;
+            }
+            if let Some(since) = opts.since {
+                match parse_log_stem(&path) {
+                    Some((_, started)) if started >= since => {}
+                    _ => continue,
                 }
             }
-            let mtime = bead_entry
-                .metadata()?
-                .modified()
-                .unwrap_or(SystemTime::UNIX_EPOCH);
-            candidates.push((mtime, path));
+            out.push(path);
         }
     }
-    candidates.sort_by_key(|c| std::cmp::Reverse(c.0));
-    match candidates.into_iter().next() {
-        Some((_, path)) => Ok(path),
-        None => missing(logs_root, opts.bead),
-    }
+    Ok(out)
 }
 
 fn file_stem_belongs_to(path: &Path, bead: &str) -> bool {
@@ -94,7 +95,7 @@ fn file_stem_belongs_to(path: &Path, bead: &str) -> bool {
     stem == bead || stem.starts_with(&format!("{bead}-"))
 }
 
-fn missing(root: &Path, bead: Option<&BeadId>) -> Result<PathBuf, LogsError> {
+pub(super) fn missing<T>(root: &Path, bead: Option<&BeadId>) -> Result<T, LogsError> {
     match bead {
         Some(b) => Err(LogsError::NoLogsForBead {
             bead: b.to_string(),
@@ -161,6 +162,7 @@ mod tests {
             &root,
             LogsOpts {
                 bead: Some(&BeadId::new("wx-1")?),
+                ..LogsOpts::default()
             },
         )?;
         assert!(path.ends_with("wx-1-older.ndjson"), "{path:?}");
@@ -176,6 +178,7 @@ mod tests {
             &root,
             LogsOpts {
                 bead: Some(&BeadId::new("wx-2")?),
+                ..LogsOpts::default()
             },
         )
         .err()
@@ -195,4 +198,42 @@ mod tests {
         assert!(matches!(err, LogsError::NoLogs { .. }));
         Ok(())
     }
+    #[test]
+    fn spec_and_since_narrow_the_search() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let root = dir.path().join(".wrapix/loom/logs");
+        let now = SystemTime::now();
+        touch(&root.join("alpha/wx-1-20260503T120000Z.ndjson"), now)?;
+        touch(
+            &root.join("alpha/wx-2-20260503T090000Z.ndjson"),
+            now + Duration::from_secs(60),
+        )?;
+        touch(
+            &root.join("beta/wx-3-20260503T130000Z.ndjson"),
+            now + Duration::from_secs(120),
+        )?;
+
+        let alpha = SpecLabel::new("alpha");
+        let path = select_log(
+            &root,
+            LogsOpts {
+                spec: Some(&alpha),
+                ..LogsOpts::default()
+            },
+        )?;
+        assert!(path.ends_with("wx-2-20260503T090000Z.ndjson"), "{path:?}");
+
+        let since = loom_core::logging::parse_utc_timestamp("20260503T100000Z")
+            .ok_or_else(|| anyhow::anyhow!("stamp"))?;
+        let path = select_log(
+            &root,
+            LogsOpts {
+                spec: Some(&alpha),
+                since: Some(since),
+                ..LogsOpts::default()
+            },
+        )?;
+        assert!(path.ends_with("wx-1-20260503T120000Z.ndjson"), "{path:?}");
+        Ok(())
+    }
 }
@@ -0,0 +1,77 @@
+use std::time::{Duration, SystemTime};
+
+use loom_core::logging::{parse_rfc3339, parse_utc_timestamp};
+
+use super::error::LogsError;
+
+/// Parse a `--since` value into the earliest start time to keep.
+///
+/// Accepts a relative age — `<n>s`, `<n>m`, `<n>h`, or `<n>d` before
+/// `now` — an RFC 3339 timestamp, or the `YYYYMMDDTHHMMSSZ` stamp used in
+/// log filenames.
+pub fn parse_since(value: &str, now: SystemTime) -> Result<SystemTime, LogsError> {
+    let invalid = || LogsError::InvalidSince {
+        value: value.to_string(),
+    };
+    let trimmed = value.trim();
+    if let Some(age) = parse_age(trimmed) {
+        return now.checked_sub(age).ok_or_else(invalid);
+    }
+    parse_utc_timestamp(trimmed)
+        .or_else(|| parse_rfc3339(trimmed))
+        .ok_or_else(invalid)
+}
+
+fn parse_age(value: &str) -> Option<Duration> {
+    let unit = value.chars().last()?;
+    let scale = match unit {
+        's' => 1,
+        'm' => 60,
+        'h' => 60 * 60,
+        'd' => 24 * 60 * 60,
+        _ => return None,
+    };
+    let count: u64 = value[..value.len() - 1].parse().ok()?;
+    Some(Duration::from_secs(count.checked_mul(scale)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn relative_ages_count_back_from_now() -> Result<()> {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        for (value, secs) in [("45s", 45), ("30m", 1_800), ("2h", 7_200), ("1d", 86_400)] {
+            assert_eq!(
+                parse_since(value, now)?,
+                now - Duration::from_secs(secs),
+                "{value}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn absolute_timestamps_ignore_now() -> Result<()> {
+        let now = SystemTime::now();
+        let stamp = parse_since("20260503T120000Z", now)?;
+        assert_eq!(parse_since("2026-05-03T12:00:00Z", now)?, stamp);
+        assert_eq!(parse_since("2026-05-03T14:00:00+02:00", now)?, stamp);
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_unknown_forms() {
+        for value in ["", "m", "10w", "yesterday", "-5m"] {
+            assert!(
+                matches!(
+                    parse_since(value, SystemTime::now()),
+                    Err(LogsError::InvalidSince { .. })
+                ),
+                "{value}"
+            );
+        }
+    }
+}
@@ -0,0 +1,411 @@
+use std::collections::HashSet;
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde_json::Value;
+
+use loom_core::agent::AgentEvent;
+use loom_core::identifier::BeadId;
+use loom_core::logging::{BeadOutcome, RenderMode, TerminalRenderer};
+
+use super::attempt::{AttemptOutcome, OutcomeTracker, parse_log_stem};
+use super::error::LogsError;
+
+/// How often `--follow` re-reads the log for appended lines.
+const FOLLOW_POLL: Duration = Duration::from_millis(250);
+
+/// Incremental reader over an append-only NDJSON log.
+///
+/// Each [`read_new`](Self::read_new) returns the complete lines appended
+/// since the previous call. A trailing partial line is held until its
+/// newline arrives, so a reader racing the writer never sees half an event.
+#[derive(Debug)]
+pub struct LogReader {
+    path: PathBuf,
+    offset: u64,
+    partial: Vec<u8>,
+}
+
+impl LogReader {
+    pub fn open(path: PathBuf) -> Self {
+        Self {
+            path,
+            offset: 0,
+            partial: Vec::new(),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Read everything appended since the previous call and return the
+    /// complete lines, newline stripped. Blank lines are dropped.
+    pub fn read_new(&mut self) -> io::Result<Vec<String>> {
+        let mut file = std::fs::File::open(&self.path)?;
+        file.seek(SeekFrom::Start(self.offset))?;
+        let mut chunk = Vec::new();
+        file.read_to_end(&mut chunk)?;
+        self.offset += chunk.len() as u64;
+        self.partial.extend_from_slice(&chunk);
+
+        let Some(end) = self.partial.iter().rposition(|b| *b == b'\n') else {
+            return Ok(Vec::new());
+        };
+        let complete: Vec<u8> = self.partial.drain(..=end).collect();
+        Ok(String::from_utf8_lossy(&complete)
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .map(str::to_string)
+            .collect())
+    }
+}
+
+/// `--tool` / `--errors` filter over decoded log events.
+///
+/// With `tool` set, only that tool's `tool_call` events pass, plus the
+/// `tool_result` events answering them (matched by call id). With `errors`
+/// set, only failures pass: `error` events, `tool_result` with `is_error`,
+/// the session's `failure_digest` and a non-zero `session_complete`. When both are set an event must
+/// satisfy both. The default filter passes everything.
+#[derive(Debug, Clone, Default)]
+pub struct EventFilter {
+    tool: Option<String>,
+    errors: bool,
+    calls: HashSet<String>,
+}
+
+impl EventFilter {
+    pub fn new(tool: Option<String>, errors: bool) -> Self {
+        Self {
+            tool,
+            errors,
+            calls: HashSet::new(),
+        }
+    }
+
+    /// Whether `event` should be shown. Stateful: a matching `tool_call`
+    /// must be seen before its `tool_result` is let through.
+    pub fn matches(&mut self, event: &Value) -> bool {
+        let kind = event.get("kind").and_then(Value::as_str);
+        let id = || event.get("id").and_then(Value::as_str);
+        if let Some(tool) = &self.tool {
+            let passes = match kind {
+                Some("tool_call") => {
+                    let named = event
+                        .get("tool")
+                        .and_then(Value::as_str)
+                        .is_some_and(|t| t.eq_ignore_ascii_case(tool));
+                    if named && let Some(id) = id() {
+                        self.calls.insert(id.to_string());
+                    }
+                    named
+                }
+                Some("tool_result") => id().is_some_and(|id| self.calls.contains(id)),
+                _ => false,
+            };
+            if !passes {
+                return false;
+            }
+        }
+        if self.errors {
+            return match kind {
+                Some("error" | "failure_digest") => true,
+                Some("tool_result") => event
+                    .get("is_error")
+                    .and_then(Value::as_bool)
+                    .unwrap_or(false),
+                Some("session_complete") => event
+                    .get("exit_code")
+                    .and_then(Value::as_i64)
+                    .is_some_and(|code| code != 0),
+                _ => false,
+            };
+        }
+        true
+    }
+}
+
+/// How [`stream_log`] prints events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamStyle {
+    /// The NDJSON lines verbatim.
+    Raw,
+    /// Replayed through [`TerminalRenderer`], as `loom run` printed them.
+    Rendered(RenderMode),
+}
+
+/// Knobs for [`stream_log`].
+#[derive(Debug, Clone)]
+pub struct StreamOpts {
+    pub filter: EventFilter,
+    pub style: StreamStyle,
+    /// Keep polling for appended lines until the attempt completes.
+    pub follow: bool,
+}
+
+/// Print the events in the log at `path` to `out`.
+///
+/// Without `follow`, prints what is on disk and returns. With `follow`,
+/// keeps polling until a `session_complete` event is read; a log whose
+/// writer died keeps the command waiting until it is interrupted, like
+/// `tail -f`. In [`StreamStyle::Rendered`] the output opens with a `▸`
+/// header and, once the attempt has ended, closes with the renderer's
+/// outcome line timed from the filename stamp to the last write.
+pub fn stream_log<W: Write + Send + 'static>(
+    path: &Path,
+    opts: StreamOpts,
+    mut out: W,
+) -> Result<(), LogsError> {
+    let StreamOpts {
+        mut filter,
+        style,
+        follow,
+    } = opts;
+    let mut printer = match style {
+        StreamStyle::Raw => Printer::Raw(Box::new(out)),
+        StreamStyle::Rendered(mode) => {
+            let bead = bead_of(path).ok_or_else(|| LogsError::NotABeadLog {
+                path: path.to_path_buf(),
+            })?;
+            let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+            writeln!(out, "▸ {bead}  {name}")?;
+            Printer::Rendered(TerminalRenderer::new(out, mode, bead, false, false))
+        }
+    };
+    let mut reader = LogReader::open(path.to_path_buf());
+    let mut tracker = OutcomeTracker::default();
+    loop {
+        for line in reader.read_new()? {
+            let Ok(value) = serde_json::from_str::<Value>(&line) else {
+                continue;
+            };
+            tracker.observe(&value);
+            if filter.matches(&value) {
+                printer.print(&line, value)?;
+            }
+        }
+        if !follow || tracker.completed() {
+            break;
+        }
+        std::thread::sleep(FOLLOW_POLL);
+    }
+    printer.finish(path, tracker.outcome())
+}
+
+enum Printer {
+    Raw(Box<dyn Write + Send>),
+    Rendered(TerminalRenderer),
+}
+
+impl Printer {
+    fn print(&mut self, line: &str, value: Value) -> io::Result<()> {
+        match self {
+            Self::Raw(out) => {
+                writeln!(out, "{line}")?;
+                out.flush()
+            }
+            Self::Rendered(renderer) => match serde_json::from_value::<AgentEvent>(value) {
+                Ok(event) => renderer.render_event(&event),
+                Err(_) => Ok(()),
+            },
+        }
+    }
+
+    fn finish(self, path: &Path, outcome: AttemptOutcome) -> Result<(), LogsError> {
+        let Self::Rendered(renderer) = self else {
+            return Ok(());
+        };
+        let outcome = match outcome {
+            AttemptOutcome::Done => BeadOutcome::Done,
+            AttemptOutcome::Failed => BeadOutcome::Failed,
+            AttemptOutcome::Incomplete => return Ok(()),
+        };
+        renderer.finish_with_elapsed(outcome, elapsed(path))?;
+        Ok(())
+    }
+}
+
+/// The bead a log belongs to. Falls back to the part before the final `-`
+/// when the stamp does not parse, so hand-named copies still replay.
+fn bead_of(path: &Path) -> Option<BeadId> {
+    if let Some((bead, _)) = parse_log_stem(path) {
+        return Some(bead);
+    }
+    let stem = path.file_stem().and_then(|s| s.to_str())?;
+    BeadId::new(stem)
+        .ok()
+        .or_else(|| BeadId::new(stem.rsplit_once('-')?.0).ok())
+}
+
+fn elapsed(path: &Path) -> Duration {
+    let Some((_, started)) = parse_log_stem(path) else {
+        return Duration::ZERO;
+    };
+    std::fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|mtime| mtime.duration_since(started).ok())
+        .unwrap_or(Duration::ZERO)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use std::sync::{Arc, Mutex};
+
+    const LOG: &str = concat!(
+        r#"{"kind":"tool_call","id":"t1","tool":"Bash","params":{"command":"cargo test"}}"#,
+        "\n",
+        r#"{"kind":"tool_result","id":"t1","output":"boom","is_error":true}"#,
+        "\n",
+        r#"{"kind":"tool_call","id":"t2","tool":"Read","params":{"file_path":"src/lib.rs"}}"#,
+        "\n",
+        r#"{"kind":"tool_result","id":"t2","output":"ok","is_error":false}"#,
+        "\n",
+        r#"{"kind":"error","message":"rate limited"}"#,
+        "\n",
+        r#"{"kind":"failure_digest","text":"Agent error: rate limited\n"}"#,
+        "\n",
+        r#"{"kind":"session_complete","exit_code":0,"cost_usd":null}"#,
+        "\n",
+    );
+
+    #[derive(Clone, Default)]
+    struct Capture(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Capture {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0
+                .lock()
+                .unwrap_or_else(|p| p.into_inner())
+                .extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Capture {
+        fn text(&self) -> String {
+            String::from_utf8_lossy(&self.0.lock().unwrap_or_else(|p| p.into_inner())).into_owned()
+        }
+    }
+
+    fn write_log(dir: &Path) -> Result<PathBuf> {
+        let path = dir.join("alpha/wx-1-20260503T120000Z.ndjson");
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, LOG)?;
+        let started = loom_core::logging::parse_utc_timestamp("20260503T120000Z")
+            .ok_or_else(|| anyhow::anyhow!("stamp"))?;
+        std::fs::File::options()
+            .write(true)
+            .open(&path)?
+            .set_modified(started + Duration::from_secs(47))?;
+        Ok(path)
+    }
+
+    fn stream(path: &Path, filter: EventFilter, style: StreamStyle) -> Result<String> {
+        let out = Capture::default();
+        let opts = StreamOpts {
+            filter,
+            style,
+            follow: false,
+        };
+        stream_log(path, opts, out.clone())?;
+        Ok(out.text())
+    }
+
+    #[test]
+    fn reader_holds_partial_lines_until_newline() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("wx-1-20260503T120000Z.ndjson");
+        std::fs::write(&path, "{\"kind\":\"turn_end\"}\n{\"kind\":")?;
+        let mut reader = LogReader::open(path.clone());
+        assert_eq!(reader.read_new()?, ["{\"kind\":\"turn_end\"}"]);
+        assert!(reader.read_new()?.is_empty());
+
+        let mut file = std::fs::File::options().append(true).open(&path)?;
+        file.write_all(b"\"turn_end\"}\n")?;
+        assert_eq!(reader.read_new()?, ["{\"kind\":\"turn_end\"}"]);
+        Ok(())
+    }
+
+    #[test]
+    fn raw_stream_prints_every_line_verbatim() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = write_log(dir.path())?;
+        let text = stream(&path, EventFilter::default(), StreamStyle::Raw)?;
+        assert_eq!(text, LOG);
+        Ok(())
+    }
+
+    #[test]
+    fn tool_filter_keeps_matching_calls_and_their_results() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = write_log(dir.path())?;
+        let text = stream(
+            &path,
+            EventFilter::new(Some("bash".into()), false),
+            StreamStyle::Raw,
+        )?;
+        let ids: Vec<&str> = text
+            .lines()
+            .map(|l| {
+                if l.contains("tool_call") {
+                    "call"
+                } else {
+                    "result"
+                }
+            })
+            .collect();
+        assert_eq!(ids, ["call", "result"]);
+        assert!(text.lines().all(|l| l.contains("\"t1\"")), "{text}");
+        Ok(())
+    }
+
+    #[test]
+    fn errors_filter_keeps_only_failures() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = write_log(dir.path())?;
+        let text = stream(&path, EventFilter::new(None, true), StreamStyle::Raw)?;
+        let kinds: Vec<Value> = text
+            .lines()
+            .map(serde_json::from_str::<Value>)
+            .collect::<Result<_, _>>()?;
+        let kinds: Vec<&str> = kinds
+            .iter()
+            .filter_map(|v| v.get("kind").and_then(Value::as_str))
+            .collect();
+        assert_eq!(kinds, ["tool_result", "error", "failure_digest"]);
+        Ok(())
+    }
+
+    #[test]
+    fn rendered_stream_replays_like_a_live_run() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = write_log(dir.path())?;
+        let text = stream(
+            &path,
+            EventFilter::default(),
+            StreamStyle::Rendered(RenderMode::Default),
+        )?;
+        assert_eq!(
+            text,
+            concat!(
+                "▸ wx-1  wx-1-20260503T120000Z.ndjson\n",
+                "  Bash    cargo test\n",
+                "  Read    src/lib.rs\n",
+                "  error: rate limited\n",
+                "  ✓ done  (2 tool calls, 47s)\n",
+            )
+        );
+        Ok(())
+    }
+}
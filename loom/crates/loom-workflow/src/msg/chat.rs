@@ -0,0 +1,219 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use tokio::process::Command;
+use tracing::info;
+
+use loom_core::bd::{BdClient, CommandRunner, ListOpts};
+use loom_core::config::{LoomConfig, Phase};
+use loom_core::identifier::{BeadId, SpecLabel};
+
+use super::context::build_msg_context;
+use super::error::MsgError;
+use super::list::{filter_clarifies, spec_label_of};
+use crate::plan::{WRAPIX_BIN, build_wrapix_argv};
+use crate::prompt::{read_pinned_context, render_within};
+
+/// Options accepted by [`chat`].
+#[derive(Debug, Clone, Default)]
+pub struct ChatOpts {
+    /// Restrict the session to clarifies carrying `spec:<label>`. `None`
+    /// walks every outstanding clarify (cross-spec).
+    pub spec: Option<SpecLabel>,
+    /// Explicit path to the `wrapix` launcher. `None` falls back to
+    /// [`WRAPIX_BIN`] on `PATH`. Tests pass a stub here.
+    pub wrapix_bin: Option<PathBuf>,
+}
+
+/// A clarify that was outstanding when the session started and no longer
+/// is once it exited.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedClarify {
+    pub id: BeadId,
+    /// The bead's `spec:<label>` — the spec the resume hint points at.
+    pub spec: Option<SpecLabel>,
+}
+
+/// Outcome of [`chat`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChatReport {
+    /// `false` when there was nothing to triage and no session was started.
+    pub launched: bool,
+    /// Clarifies cleared during the session, in their original list order.
+    pub resolved: Vec<ResolvedClarify>,
+    /// Clarifies still carrying `loom:clarify` afterwards.
+    pub remaining: usize,
+}
+
+/// Run `loom msg -c`: the interactive Drafter session.
+///
+/// 1. List outstanding `loom:clarify` beads (optionally scoped to
+///    [`ChatOpts::spec`]); return early without launching when there are
+///    none.
+/// 2. Render `msg.md` over them via [`build_msg_context`].
+/// 3. Shell out to interactive `wrapix run` — the same TTY-attached launch
+///    `loom plan` uses — and wait for the user to finish.
+/// 4. List again and diff: every bead present before and absent after was
+///    resolved during the session (the Drafter clears the label itself).
+pub async fn chat<R: CommandRunner>(
+    workspace: &Path,
+    bd: &BdClient<R>,
+    opts: ChatOpts,
+) -> Result<ChatReport, MsgError> {
+    let before = list_clarifies(bd).await?;
+    let before = filter_clarifies(&before, opts.spec.as_ref());
+    if before.is_empty() {
+        return Ok(ChatReport::default());
+    }
+
+    let cfg = LoomConfig::load(workspace.join(".wrapix/loom/config.toml"))
+        .unwrap_or_else(|_| LoomConfig::default());
+    let pinned_context = read_pinned_context(workspace, &cfg.pinned_context).map_err(|source| {
+        MsgError::ReadPinnedContext {
+            path: workspace.join(&cfg.pinned_context),
+            source,
+        }
+    })?;
+    // The Drafter only ever completes: the user is in the room, so there is
+    // nobody to block on and no reason to raise a new clarify.
+    let exit_signals = format!("- `{}`", cfg.exit_signals.complete);
+    let prompt = render_within(
+        "msg.md",
+        build_msg_context(pinned_context, &before, exit_signals),
+        cfg.prompt_budget(Phase::Msg),
+    )?;
+
+    let argv = build_wrapix_argv(workspace, &prompt);
+    let bin = opts.wrapix_bin.unwrap_or_else(|| PathBuf::from(WRAPIX_BIN));
+    info!(
+        clarifies = before.len(),
+        wrapix_bin = %bin.display(),
+        "loom msg: shelling out to interactive wrapix run",
+    );
+    let status = Command::new(&bin)
+        .args(&argv)
+        .status()
+        .await
+        .map_err(|source| MsgError::Spawn { source })?;
+    if !status.success() {
+        return Err(MsgError::WrapixExit {
+            status: status.to_string(),
+        });
+    }
+
+    let after = list_clarifies(bd).await?;
+    let still_open: HashSet<&BeadId> = filter_clarifies(&after, opts.spec.as_ref())
+        .into_iter()
+        .map(|b| &b.id)
+        .collect();
+    let resolved = before
+        .iter()
+        .filter(|b| !still_open.contains(&b.id))
+        .map(|b| ResolvedClarify {
+            id: b.id.clone(),
+            spec: spec_label_of(b),
+        })
+        .collect();
+    Ok(ChatReport {
+        launched: true,
+        resolved,
+        remaining: still_open.len(),
+    })
+}
+
+async fn list_clarifies<R: CommandRunner>(
+    bd: &BdClient<R>,
+) -> Result<Vec<loom_core::bd::Bead>, MsgError> {
+    Ok(bd
+        .list(ListOpts {
+            status: None,
+            label: Some("loom:clarify".to_string()),
+        })
+        .await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use loom_core::bd::ScriptedRunner;
+    use std::os::unix::fs::PermissionsExt;
+
+    const TWO_CLARIFIES: &str = r#"[
+        {"id":"wx-1","title":"Pick a store","description":"","status":"open","priority":2,"issue_type":"task","labels":["loom:clarify","spec:alpha"]},
+        {"id":"wx-2","title":"Name the flag","description":"","status":"open","priority":2,"issue_type":"task","labels":["loom:clarify","spec:beta"]}
+    ]"#;
+
+    const ONE_LEFT: &str = r#"[
+        {"id":"wx-2","title":"Name the flag","description":"","status":"open","priority":2,"issue_type":"task","labels":["loom:clarify","spec:beta"]}
+    ]"#;
+
+    /// Stub `wrapix` that records its argv to `<dir>/argv.log` and exits 0.
+    fn install_wrapix_stub(dir: &Path) -> Result<PathBuf> {
+        let bin = dir.join("wrapix-stub");
+        let log = dir.join("argv.log");
+        std::fs::write(
+            &bin,
+            format!("#!/bin/sh\nfor a in \"$@\"; do printf '%s\\n' \"$a\" >> {log:?}; done\n"),
+        )?;
+        let mut perm = std::fs::metadata(&bin)?.permissions();
+        perm.set_mode(0o755);
+        std::fs::set_permissions(&bin, perm)?;
+        Ok(bin)
+    }
+
+    #[tokio::test]
+    async fn chat_reports_clarifies_cleared_during_the_session() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let bin = install_wrapix_stub(dir.path())?;
+        let bd = BdClient::with_runner(ScriptedRunner::new(&[TWO_CLARIFIES, ONE_LEFT]));
+
+        let report = chat(
+            dir.path(),
+            &bd,
+            ChatOpts {
+                spec: None,
+                wrapix_bin: Some(bin),
+            },
+        )
+        .await?;
+
+        assert!(report.launched);
+        assert_eq!(
+            report.resolved,
+            [ResolvedClarify {
+                id: BeadId::new("wx-1")?,
+                spec: Some(SpecLabel::new("alpha")),
+            }]
+        );
+        assert_eq!(report.remaining, 1);
+
+        let argv = std::fs::read_to_string(dir.path().join("argv.log"))?;
+        assert!(argv.starts_with("run\n"), "{argv}");
+        assert!(
+            argv.contains("### wx-1 — [spec:alpha] Pick a store"),
+            "{argv}"
+        );
+        assert!(argv.contains("- `LOOM_COMPLETE`"), "{argv}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn chat_skips_the_session_when_nothing_is_outstanding() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let bd = BdClient::with_runner(ScriptedRunner::new(&[TWO_CLARIFIES]));
+
+        let report = chat(
+            dir.path(),
+            &bd,
+            ChatOpts {
+                spec: Some(SpecLabel::new("gamma")),
+                wrapix_bin: Some(PathBuf::from("/nonexistent/wrapix")),
+            },
+        )
+        .await?;
+
+        assert_eq!(report, ChatReport::default());
+        Ok(())
+    }
+}
@@ -106,6 +106,13 @@ mod tests {
             priority: 2,
             issue_type: "task".into(),
             labels: labels.iter().map(|s| Label::new(*s)).collect(),
+            created_at: None,
+            updated_at: None,
+            closed_at: None,
+            notes: String::new(),
+            assignee: None,
+            parent: None,
+            dependencies: Vec::new(),
         }
     }
 
@@ -1,3 +1,6 @@
+use std::io;
+use std::path::PathBuf;
+
 use displaydoc::Display;
 use thiserror::Error;
 
@@ -36,4 +39,39 @@ pub enum MsgError {
 
     /// use either -a <choice> or -d, not both
     AnswerOrDismiss,
+
+    /// failed to read pinned-context file at {path}
+    ReadPinnedContext {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    /// failed to spawn `wrapix run`
+    Spawn {
+        #[source]
+        source: io::Error,
+    },
+
+    /// `wrapix run` exited with status {status}
+    WrapixExit { status: String },
+
+    /// loom msg --pick needs an interactive terminal on stdin and stdout
+    NotATerminal,
+
+    /// terminal i/o failed
+    Terminal {
+        #[source]
+        source: io::Error,
+    },
+
+    /// failed to spawn `loom {step}` for --resume
+    ResumeSpawn {
+        step: &'static str,
+        #[source]
+        source: io::Error,
+    },
+
+    /// `loom {step}` handoff exited with status {status}
+    ResumeHandoff { step: &'static str, status: String },
 }
@@ -1,5 +1,6 @@
 use loom_core::bd::{Bead, Label};
 use loom_core::identifier::SpecLabel;
+use loom_core::logging::parse_rfc3339;
 
 use super::options::parse_options;
 
@@ -21,10 +22,11 @@ pub struct ClarifyRow {
 
 /// Filter beads to those still labelled `loom:clarify`. The spec filter,
 /// when supplied, restricts the result to beads carrying `spec:<label>`.
-/// Order is preserved from the input slice (the caller sorts by creation
-/// time before calling).
+/// The result is ordered by `created_at`, oldest first, so `-n <N>` indices
+/// follow creation order whatever order `bd` listed them in; beads without
+/// a parseable timestamp keep their input order after the dated ones.
 pub fn filter_clarifies<'a>(beads: &'a [Bead], spec: Option<&SpecLabel>) -> Vec<&'a Bead> {
-    beads
+    let mut kept: Vec<&Bead> = beads
         .iter()
         .filter(|b| b.labels.iter().any(Label::is_clarify))
         .filter(|b| match spec {
@@ -34,7 +36,12 @@ pub fn filter_clarifies<'a>(beads: &'a [Bead], spec: Option<&SpecLabel>) -> Vec<
                 .iter()
                 .any(|l| l.spec_label().as_ref() == Some(label)),
         })
-        .collect()
+        .collect();
+    kept.sort_by_key(|b| {
+        let created = b.created_at.as_deref().and_then(parse_rfc3339);
+        (created.is_none(), created)
+    });
+    kept
 }
 
 /// Build the rendered [`ClarifyRow`] table. `spec_filter` controls whether
@@ -91,6 +98,13 @@ mod tests {
             priority: 2,
             issue_type: "task".into(),
             labels: labels.iter().map(|s| Label::new(*s)).collect(),
+            created_at: None,
+            updated_at: None,
+            closed_at: None,
+            notes: String::new(),
+            assignee: None,
+            parent: None,
+            dependencies: Vec::new(),
         }
     }
 
@@ -117,6 +131,22 @@ mod tests {
         assert_eq!(kept[1].id, BeadId::new("wx-3").expect("valid"));
     }
 
+    #[test]
+    fn filter_orders_by_creation_time_with_undated_last() {
+        let mut undated = bead("wx-1", "undated", "", &["loom:clarify"]);
+        undated.created_at = None;
+        let mut newer = bead("wx-2", "newer", "", &["loom:clarify"]);
+        newer.created_at = Some("2026-05-03T12:00:00Z".into());
+        let mut older = bead("wx-3", "older", "", &["loom:clarify"]);
+        older.created_at = Some("2026-05-02T12:00:00+02:00".into());
+        let beads = vec![undated, newer, older];
+        let ids: Vec<&str> = filter_clarifies(&beads, None)
+            .iter()
+            .map(|b| b.id.as_str())
+            .collect();
+        assert_eq!(ids, ["wx-3", "wx-2", "wx-1"]);
+    }
+
     #[test]
     fn filter_with_spec_label_keeps_only_matching() {
         let beads = vec![
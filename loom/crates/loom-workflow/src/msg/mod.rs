@@ -5,28 +5,53 @@
 //! `loom-core`'s typed `BdClient` and `loom-templates`' `msg.md` template.
 //! The command:
 //!
-//! 1. lists outstanding beads carrying `loom:clarify`, optionally filtered
-//!    to `spec:<label>` so the SPEC column collapses;
+//! 1. lists outstanding beads carrying `loom:clarify` oldest first,
+//!    optionally filtered to `spec:<label>` so the SPEC column collapses;
 //! 2. resolves an `-n <N>` / `-i <id>` selector to a [`BeadId`];
 //! 3. for `-a <choice>` either looks up `### Option <N>` per the Options
 //!    Format Contract and composes a `Chose option N — title: body` note
 //!    ([`reply::FastReply::Option`]), or stores the choice verbatim
-//!    ([`reply::FastReply::Verbatim`]);
+//!    ([`reply::FastReply::Verbatim`]), appends the note to the bead and
+//!    removes the `loom:clarify` label ([`clear_clarify`]);
 //! 4. for `-d` writes the canonical [`reply::DISMISS_NOTE`] and removes the
-//!    `loom:clarify` label so the bead drops off the list.
+//!    `loom:clarify` label so the bead drops off the list;
+//! 5. for `-c` hands the whole queue to an interactive Drafter session
+//!    ([`chat`]) and reports which clarifies it resolved;
+//! 6. for `--pick` draws a terminal [`Picker`] over the same list — the
+//!    parsed options of the selected clarify in a side pane — and resolves
+//!    each pick through the fast paths above ([`pick`]), so the notes match
+//!    `-a` / `-d` exactly. [`TtyTerminal`] is the raw-mode terminal it runs
+//!    on.
+//!
+//! Whichever way a clarify is cleared, [`unblocked_specs`] then reports the
+//! specs that just lost their last outstanding clarify. `loom msg` offers
+//! the [`ResumeStep`] handoff for each — or, with `--resume`, performs it
+//! via [`exec_resume`], the same child `loom` exec `loom run` uses to hand
+//! off to `loom check`.
 //!
 //! The render path uses [`build_msg_context`] to compose the typed
 //! [`MsgContext`](loom_templates::msg::MsgContext) the Askama template
 //! consumes.
 
+mod chat;
 mod context;
 mod error;
 mod list;
 mod options;
+mod picker;
 mod reply;
+mod resume;
+mod tty;
 
+pub use chat::{ChatOpts, ChatReport, ResolvedClarify, chat};
 pub use context::{build_msg_context, resolve_target};
 pub use error::MsgError;
 pub use list::{ClarifyRow, build_rows, filter_clarifies, spec_label_of};
 pub use options::{OptionEntry, OptionsParse, parse_options};
-pub use reply::{DISMISS_NOTE, FastReply, build_fast_reply};
+pub use picker::{
+    PickOpts, PickReport, PickedClarify, Picker, PickerCommand, PickerEntry, PickerKey,
+    PickerTerminal, answer_template, compose_picker_frame, parse_answer, parse_keys, pick,
+};
+pub use reply::{DISMISS_NOTE, FastReply, build_fast_reply, clear_clarify};
+pub use resume::{ResumeStep, exec_resume, resume_step, unblocked_specs};
+pub use tty::TtyTerminal;
@@ -0,0 +1,731 @@
+use std::io;
+
+use loom_core::bd::{BdClient, Bead, CommandRunner, ListOpts};
+use loom_core::identifier::{BeadId, SpecLabel};
+
+use super::error::MsgError;
+use super::list::{filter_clarifies, spec_label_of};
+use super::options::{OptionsParse, parse_options};
+use super::reply::{DISMISS_NOTE, build_fast_reply, clear_clarify};
+
+/// Widest the clarify list column grows before the side pane takes the rest.
+const LIST_WIDTH: usize = 36;
+
+/// Narrowest frame the picker lays out; smaller terminals are clipped.
+const MIN_WIDTH: usize = 40;
+
+/// Gutter drawn between the list and the side pane.
+const PANE_GUTTER: &str = " │ ";
+
+const KEY_HELP: &str = "↑↓ clarify  ←→ option  enter/1-9 choose  e answer  d dismiss  q quit";
+
+/// One decoded keypress. Only the keys the picker binds are distinguished.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PickerKey {
+    Up,
+    Down,
+    Left,
+    Right,
+    Enter,
+    Esc,
+    /// Ctrl-C, or end of input. Raw mode disables `ISIG`, so the picker
+    /// sees the byte instead of the process dying with the terminal raw.
+    Interrupt,
+    Char(char),
+}
+
+/// Decode one raw-mode read into keys. Arrow keys arrive as `ESC [ A`–`D`
+/// (or `ESC O A`–`D` in application mode); a lone `ESC` is [`PickerKey::Esc`].
+/// Undecodable bytes are dropped.
+pub fn parse_keys(bytes: &[u8]) -> Vec<PickerKey> {
+    let mut keys = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if b == 0x1b {
+            let arrow = match bytes.get(i + 1..i + 3) {
+                Some([b'[' | b'O', b'A']) => Some(PickerKey::Up),
+                Some([b'[' | b'O', b'B']) => Some(PickerKey::Down),
+                Some([b'[' | b'O', b'C']) => Some(PickerKey::Right),
+                Some([b'[' | b'O', b'D']) => Some(PickerKey::Left),
+                _ => None,
+            };
+            match arrow {
+                Some(key) => {
+                    keys.push(key);
+                    i += 3;
+                }
+                None => {
+                    keys.push(PickerKey::Esc);
+                    i += 1;
+                }
+            }
+            continue;
+        }
+        let len = match b {
+            0xc0..=0xdf => 2,
+            0xe0..=0xef => 3,
+            0xf0..=0xf7 => 4,
+            _ => 1,
+        };
+        let chunk = &bytes[i..bytes.len().min(i + len)];
+        i += len;
+        match b {
+            b'\r' | b'\n' => keys.push(PickerKey::Enter),
+            0x03 | 0x04 => keys.push(PickerKey::Interrupt),
+            _ => {
+                if let Some(c) = std::str::from_utf8(chunk)
+                    .ok()
+                    .and_then(|s| s.chars().next())
+                    && !c.is_control()
+                {
+                    keys.push(PickerKey::Char(c));
+                }
+            }
+        }
+    }
+    keys
+}
+
+/// What a keypress asked the picker's driver to do with the selected clarify.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PickerCommand {
+    /// Answer with option `n` — the `-a <n>` fast path.
+    Choose(u32),
+    /// Open the editor for a verbatim answer — the `-a <text>` fast path.
+    Answer,
+    /// Dismiss with [`DISMISS_NOTE`] — the `-d` fast path.
+    Dismiss,
+    Quit,
+}
+
+/// One outstanding clarify as the picker shows it.
+#[derive(Debug, Clone)]
+pub struct PickerEntry {
+    pub id: BeadId,
+    pub spec: Option<SpecLabel>,
+    pub title: String,
+    pub description: String,
+    pub options: OptionsParse,
+}
+
+impl PickerEntry {
+    fn from_bead(bead: &Bead) -> Self {
+        Self {
+            id: bead.id.clone(),
+            spec: spec_label_of(bead),
+            title: bead.title.clone(),
+            description: bead.description.clone(),
+            options: parse_options(&bead.description),
+        }
+    }
+
+    /// The `## Options` summary, or the bead title when there is none —
+    /// the same fallback the flat `loom msg` list uses.
+    fn summary(&self) -> &str {
+        if self.options.summary.is_empty() {
+            &self.title
+        } else {
+            &self.options.summary
+        }
+    }
+}
+
+/// Selection state for `loom msg --pick`. Pure: keys go in through
+/// [`handle`](Self::handle), [`PickerCommand`]s come out, and
+/// [`compose_picker_frame`] draws it. The driver ([`pick`]) owns the bd
+/// writes and calls [`resolve`](Self::resolve) once one lands.
+#[derive(Debug, Clone)]
+pub struct Picker {
+    entries: Vec<PickerEntry>,
+    selected: usize,
+    option: usize,
+    confirm_dismiss: bool,
+    status: Option<String>,
+}
+
+impl Picker {
+    pub fn new(beads: &[&Bead]) -> Self {
+        Self {
+            entries: beads.iter().map(|b| PickerEntry::from_bead(b)).collect(),
+            selected: 0,
+            option: 0,
+            confirm_dismiss: false,
+            status: None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The clarify under the cursor.
+    pub fn current(&self) -> Option<&PickerEntry> {
+        self.entries.get(self.selected)
+    }
+
+    /// Show `message` on the status line until the next keypress.
+    pub fn set_status(&mut self, message: impl Into<String>) {
+        self.status = Some(message.into());
+    }
+
+    /// Apply one key. Navigation is handled in place; keys that need a bd
+    /// write or the editor return a [`PickerCommand`] for the driver.
+    pub fn handle(&mut self, key: PickerKey) -> Option<PickerCommand> {
+        self.status = None;
+        if std::mem::take(&mut self.confirm_dismiss) {
+            if key == PickerKey::Char('y') {
+                return Some(PickerCommand::Dismiss);
+            }
+            self.status = Some("dismiss cancelled".to_string());
+            return None;
+        }
+        let option_count = self.current().map_or(0, |e| e.options.options.len());
+        match key {
+            PickerKey::Up | PickerKey::Char('k') => {
+                self.selected = self.selected.saturating_sub(1);
+                self.option = 0;
+            }
+            PickerKey::Down | PickerKey::Char('j') => {
+                self.selected = (self.selected + 1).min(self.entries.len().saturating_sub(1));
+                self.option = 0;
+            }
+            PickerKey::Left | PickerKey::Char('h') => {
+                self.option = self.option.saturating_sub(1);
+            }
+            PickerKey::Right | PickerKey::Char('l') => {
+                self.option = (self.option + 1).min(option_count.saturating_sub(1));
+            }
+            PickerKey::Enter => {
+                let entry = self.current()?;
+                match entry.options.options.get(self.option) {
+                    Some(opt) => return Some(PickerCommand::Choose(opt.n)),
+                    None => {
+                        self.status = Some(format!(
+                            "{} has no numbered options — press e to answer",
+                            entry.id
+                        ));
+                    }
+                }
+            }
+            PickerKey::Char(c @ '1'..='9') => {
+                return c.to_digit(10).map(PickerCommand::Choose);
+            }
+            PickerKey::Char('e') => return Some(PickerCommand::Answer),
+            PickerKey::Char('d') => {
+                let id = self.current()?.id.clone();
+                self.confirm_dismiss = true;
+                self.status = Some(format!("dismiss {id}? press y to confirm"));
+            }
+            PickerKey::Char('q') | PickerKey::Esc | PickerKey::Interrupt => {
+                return Some(PickerCommand::Quit);
+            }
+            PickerKey::Char(_) => {}
+        }
+        None
+    }
+
+    /// Drop the clarify under the cursor after the driver cleared it, and
+    /// report `message` on the status line.
+    pub fn resolve(&mut self, message: impl Into<String>) {
+        if self.selected < self.entries.len() {
+            self.entries.remove(self.selected);
+        }
+        self.selected = self.selected.min(self.entries.len().saturating_sub(1));
+        self.option = 0;
+        self.status = Some(message.into());
+    }
+}
+
+/// Draw one picker frame: a header, the clarify list on the left, the
+/// selected clarify's `OptionsParse` summary and option bodies on the right,
+/// then the status and key-help lines. Every line is clipped to `width` and
+/// the frame to `height` rows; the list scrolls to keep the cursor visible.
+pub fn compose_picker_frame(picker: &Picker, width: usize, height: usize) -> String {
+    let width = width.max(MIN_WIDTH);
+    let body_rows = height.saturating_sub(4).max(1);
+    let gutter = PANE_GUTTER.chars().count();
+    let list_width = LIST_WIDTH.min(width / 3);
+    let pane_width = width - list_width - gutter;
+
+    let mut rows = vec![
+        clip(
+            &format!("loom msg — {} outstanding clarify(s)", picker.len()),
+            width,
+        ),
+        "─".repeat(width),
+    ];
+
+    let first = picker.selected.saturating_sub(body_rows - 1);
+    let list: Vec<String> = picker
+        .entries
+        .iter()
+        .enumerate()
+        .skip(first)
+        .take(body_rows)
+        .map(|(i, entry)| {
+            let cursor = if i == picker.selected { '›' } else { ' ' };
+            pad(
+                &format!("{cursor} {} {}", entry.id, entry.summary()),
+                list_width,
+            )
+        })
+        .collect();
+    let pane = picker
+        .current()
+        .map(|entry| side_pane(entry, picker.option))
+        .unwrap_or_default();
+
+    let blank = " ".repeat(list_width);
+    for i in 0..body_rows.min(list.len().max(pane.len())) {
+        let left = list.get(i).map(String::as_str).unwrap_or(&blank);
+        let right = pane.get(i).map(|l| clip(l, pane_width)).unwrap_or_default();
+        rows.push(format!("{left}{PANE_GUTTER}{right}").trim_end().to_string());
+    }
+    while rows.len() < height.saturating_sub(2) {
+        rows.push(String::new());
+    }
+
+    rows.push(clip(picker.status.as_deref().unwrap_or(""), width));
+    rows.push(clip(KEY_HELP, width));
+    rows.truncate(height.max(1));
+    let mut out = rows.join("\n");
+    out.push('\n');
+    out
+}
+
+fn side_pane(entry: &PickerEntry, cursor: usize) -> Vec<String> {
+    let mut lines = vec![format!("{} — {}", entry.id, entry.title)];
+    if let Some(spec) = &entry.spec {
+        lines.push(format!("spec: {spec}"));
+    }
+    lines.push(String::new());
+    if !entry.options.summary.is_empty() {
+        lines.push(format!("Options — {}", entry.options.summary));
+        lines.push(String::new());
+    }
+    if entry.options.options.is_empty() {
+        lines.extend(entry.description.lines().map(str::to_string));
+        lines.push(String::new());
+        lines.push("(no numbered options — press e to answer)".to_string());
+        return lines;
+    }
+    for (i, opt) in entry.options.options.iter().enumerate() {
+        let marker = if i == cursor { '›' } else { ' ' };
+        if opt.title.is_empty() {
+            lines.push(format!("{marker} {}.", opt.n));
+        } else {
+            lines.push(format!("{marker} {}. {}", opt.n, opt.title));
+        }
+        lines.extend(opt.body.lines().map(|l| format!("     {l}")));
+    }
+    lines
+}
+
+/// Seed text for the verbatim-answer editor. Everything after the first
+/// line is `#`-commented context that [`parse_answer`] strips again.
+pub fn answer_template(entry: &PickerEntry) -> String {
+    let mut out = String::from("\n");
+    out.push_str(&format!("# Answer for {} — {}\n", entry.id, entry.title));
+    out.push_str("# Lines starting with '#' are ignored; an empty answer cancels.\n");
+    out.push_str("# A bare option number picks that option, as with `loom msg -a N`.\n");
+    if !entry.options.options.is_empty() {
+        out.push_str("#\n");
+        if !entry.options.summary.is_empty() {
+            out.push_str(&format!("# Options — {}\n", entry.options.summary));
+        }
+        for opt in &entry.options.options {
+            out.push_str(&format!("#   {}. {}\n", opt.n, opt.title));
+        }
+    }
+    out
+}
+
+/// Read an edited [`answer_template`] back: drop `#` lines and surrounding
+/// whitespace. `None` when nothing is left — the user cancelled.
+pub fn parse_answer(text: &str) -> Option<String> {
+    let kept: Vec<&str> = text.lines().filter(|l| !l.starts_with('#')).collect();
+    let answer = kept.join("\n").trim().to_string();
+    if answer.is_empty() {
+        None
+    } else {
+        Some(answer)
+    }
+}
+
+/// The terminal half of the picker, split out so tests can script keys.
+/// [`TtyTerminal`](super::TtyTerminal) is the raw-mode implementation.
+pub trait PickerTerminal {
+    /// Replace the screen with `frame`.
+    fn draw(&mut self, frame: &str) -> io::Result<()>;
+    /// Block for the next batch of keys.
+    fn read_keys(&mut self) -> io::Result<Vec<PickerKey>>;
+    /// Hand `template` to the user's editor and return the [`parse_answer`]
+    /// result. `None` means the answer was abandoned.
+    fn edit_answer(&mut self, bead: &BeadId, template: &str) -> io::Result<Option<String>>;
+}
+
+/// Options accepted by [`pick`].
+#[derive(Debug, Clone)]
+pub struct PickOpts {
+    /// Restrict the list to clarifies carrying `spec:<label>`.
+    pub spec: Option<SpecLabel>,
+    /// Terminal size in columns × rows. Frames are clipped to it.
+    pub width: usize,
+    pub height: usize,
+}
+
+/// One clarify resolved from the picker, with the note the CLI fast path
+/// would have written for the same choice.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PickedClarify {
+    pub id: BeadId,
+    pub spec: Option<SpecLabel>,
+    pub note: String,
+    pub dismissed: bool,
+}
+
+/// Outcome of [`pick`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PickReport {
+    /// `false` when there was nothing to triage and nothing was drawn.
+    pub launched: bool,
+    /// Clarifies resolved in the order the user resolved them.
+    pub resolved: Vec<PickedClarify>,
+    /// Clarifies still outstanding when the user quit.
+    pub remaining: usize,
+}
+
+/// Run `loom msg --pick`: list outstanding clarifies, draw the [`Picker`],
+/// and resolve whatever the user picks until they quit or the list empties.
+///
+/// Every resolution goes through the fast-path helpers — an option pick is
+/// `build_fast_reply(id, "<n>", …)`, an edited answer is
+/// `build_fast_reply(id, "<text>", …)`, a dismiss writes [`DISMISS_NOTE`] —
+/// and then [`clear_clarify`], so the bead ends up exactly as `-a` / `-d`
+/// would leave it. A missing option is shown on the status line rather than
+/// ending the session.
+pub async fn pick<R: CommandRunner, T: PickerTerminal>(
+    bd: &BdClient<R>,
+    opts: PickOpts,
+    term: &mut T,
+) -> Result<PickReport, MsgError> {
+    let beads = bd
+        .list(ListOpts {
+            status: None,
+            label: Some("loom:clarify".to_string()),
+        })
+        .await?;
+    let kept = filter_clarifies(&beads, opts.spec.as_ref());
+    let mut picker = Picker::new(&kept);
+    let mut report = PickReport {
+        launched: !picker.is_empty(),
+        ..PickReport::default()
+    };
+
+    'session: while !picker.is_empty() {
+        let frame = compose_picker_frame(&picker, opts.width, opts.height);
+        term.draw(&frame).map_err(terminal)?;
+        for key in term.read_keys().map_err(terminal)? {
+            let Some(command) = picker.handle(key) else {
+                continue;
+            };
+            let Some(entry) = picker.current().cloned() else {
+                break 'session;
+            };
+            let choice = match command {
+                PickerCommand::Quit => break 'session,
+                PickerCommand::Dismiss => None,
+                PickerCommand::Choose(n) => Some(n.to_string()),
+                PickerCommand::Answer => {
+                    let template = answer_template(&entry);
+                    match term.edit_answer(&entry.id, &template).map_err(terminal)? {
+                        Some(text) => Some(text),
+                        None => {
+                            picker.set_status("answer cancelled");
+                            break;
+                        }
+                    }
+                }
+            };
+            let (note, dismissed) = match choice {
+                None => (DISMISS_NOTE.to_string(), true),
+                Some(choice) => match build_fast_reply(&entry.id, &choice, &entry.description) {
+                    Ok(reply) => (reply.note().to_string(), false),
+                    Err(e) => {
+                        picker.set_status(e.to_string());
+                        continue;
+                    }
+                },
+            };
+            clear_clarify(bd, &entry.id, &note).await?;
+            picker.resolve(if dismissed {
+                format!("dismissed {}", entry.id)
+            } else {
+                format!("answered {}: {note}", entry.id)
+            });
+            report.resolved.push(PickedClarify {
+                id: entry.id,
+                spec: entry.spec,
+                note,
+                dismissed,
+            });
+            // Keys typed ahead were aimed at the old list; redraw first.
+            break;
+        }
+    }
+    report.remaining = picker.len();
+    Ok(report)
+}
+
+fn terminal(source: io::Error) -> MsgError {
+    MsgError::Terminal { source }
+}
+
+fn clip(line: &str, width: usize) -> String {
+    if line.chars().count() <= width {
+        return line.to_string();
+    }
+    let mut out: String = line.chars().take(width.saturating_sub(1)).collect();
+    out.push('…');
+    out
+}
+
+fn pad(line: &str, width: usize) -> String {
+    let clipped = clip(line, width);
+    let len = clipped.chars().count();
+    format!("{clipped}{}", " ".repeat(width - len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use loom_core::bd::{BdError, RunOutput};
+    use std::collections::VecDeque;
+    use std::ffi::OsString;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    const CLARIFIES: &str = r###"[
+        {"id":"wx-1","title":"Pick a store","description":"## Options — storage backend\n\n### Option 1 — SQLite\nEmbedded.\n\n### Option 2 — Postgres\nServer.\n","status":"open","priority":2,"issue_type":"task","labels":["loom:clarify","spec:alpha"]},
+        {"id":"wx-2","title":"Name the flag","description":"free-form question","status":"open","priority":2,"issue_type":"task","labels":["loom:clarify","spec:beta"]}
+    ]"###;
+
+    /// Records every bd argv and answers `list` with [`CLARIFIES`].
+    #[derive(Default, Clone)]
+    struct RecordingRunner {
+        calls: Arc<Mutex<Vec<Vec<String>>>>,
+    }
+
+    impl RecordingRunner {
+        fn calls(&self) -> Vec<Vec<String>> {
+            self.calls.lock().unwrap_or_else(|p| p.into_inner()).clone()
+        }
+    }
+
+    impl CommandRunner for RecordingRunner {
+        async fn run(&self, args: Vec<OsString>, _t: Duration) -> Result<RunOutput, BdError> {
+            let args: Vec<String> = args
+                .iter()
+                .map(|a| a.to_string_lossy().into_owned())
+                .collect();
+            let stdout = if args.first().map(String::as_str) == Some("list") {
+                CLARIFIES.as_bytes().to_vec()
+            } else {
+                Vec::new()
+            };
+            self.calls
+                .lock()
+                .unwrap_or_else(|p| p.into_inner())
+                .push(args);
+            Ok(RunOutput {
+                status: 0,
+                stdout,
+                stderr: Vec::new(),
+            })
+        }
+    }
+
+    struct ScriptedTerminal {
+        keys: VecDeque<Vec<PickerKey>>,
+        answers: VecDeque<Option<String>>,
+        frames: Vec<String>,
+    }
+
+    impl ScriptedTerminal {
+        fn new(keys: &[&[PickerKey]], answers: &[Option<&str>]) -> Self {
+            Self {
+                keys: keys.iter().map(|k| k.to_vec()).collect(),
+                answers: answers.iter().map(|a| a.map(str::to_string)).collect(),
+                frames: Vec::new(),
+            }
+        }
+    }
+
+    impl PickerTerminal for ScriptedTerminal {
+        fn draw(&mut self, frame: &str) -> io::Result<()> {
+            self.frames.push(frame.to_string());
+            Ok(())
+        }
+
+        fn read_keys(&mut self) -> io::Result<Vec<PickerKey>> {
+            Ok(self
+                .keys
+                .pop_front()
+                .unwrap_or_else(|| vec![PickerKey::Interrupt]))
+        }
+
+        fn edit_answer(&mut self, _bead: &BeadId, template: &str) -> io::Result<Option<String>> {
+            assert!(template.contains("# Answer for"), "{template}");
+            Ok(self.answers.pop_front().flatten())
+        }
+    }
+
+    fn opts() -> PickOpts {
+        PickOpts {
+            spec: None,
+            width: 100,
+            height: 20,
+        }
+    }
+
+    #[test]
+    fn parse_keys_decodes_arrows_and_chars() {
+        let keys = parse_keys(b"\x1b[A\x1b[Bj\r\x1bq\x03\xc3\xa9");
+        assert_eq!(
+            keys,
+            [
+                PickerKey::Up,
+                PickerKey::Down,
+                PickerKey::Char('j'),
+                PickerKey::Enter,
+                PickerKey::Esc,
+                PickerKey::Char('q'),
+                PickerKey::Interrupt,
+                PickerKey::Char('é'),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_answer_strips_comments_and_cancels_when_empty() {
+        assert_eq!(
+            parse_answer("Use SQLite.\n# Answer for wx-1\n\n"),
+            Some("Use SQLite.".to_string())
+        );
+        assert_eq!(parse_answer("\n# Answer for wx-1\n"), None);
+    }
+
+    #[tokio::test]
+    async fn enter_chooses_the_highlighted_option_like_the_fast_path() -> Result<()> {
+        let runner = RecordingRunner::default();
+        let bd = BdClient::with_runner(runner.clone());
+        let mut term = ScriptedTerminal::new(&[&[PickerKey::Right, PickerKey::Enter]], &[]);
+
+        let report = pick(&bd, opts(), &mut term).await?;
+
+        let expected = build_fast_reply(&BeadId::new("wx-1")?, "2", &kept_desc()?)?;
+        assert_eq!(
+            report.resolved,
+            [PickedClarify {
+                id: BeadId::new("wx-1")?,
+                spec: Some(SpecLabel::new("alpha")),
+                note: expected.note().to_string(),
+                dismissed: false,
+            }]
+        );
+        assert_eq!(report.remaining, 1);
+        let calls = runner.calls();
+        let update = calls
+            .iter()
+            .find(|c| c[0] == "update")
+            .ok_or_else(|| anyhow::anyhow!("no bd update in {calls:?}"))?;
+        assert_eq!(
+            update[1..],
+            [
+                "wx-1",
+                "--remove-label",
+                "loom:clarify",
+                "--append-notes",
+                expected.note(),
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn verbatim_answer_and_dismiss_resolve_both_clarifies() -> Result<()> {
+        let runner = RecordingRunner::default();
+        let bd = BdClient::with_runner(runner.clone());
+        let mut term = ScriptedTerminal::new(
+            &[
+                &[PickerKey::Char('e')],
+                &[PickerKey::Char('d')],
+                &[PickerKey::Char('y')],
+            ],
+            &[Some("Use Postgres, we already run it.")],
+        );
+
+        let report = pick(&bd, opts(), &mut term).await?;
+
+        assert_eq!(report.resolved.len(), 2);
+        assert_eq!(report.resolved[0].note, "Use Postgres, we already run it.");
+        assert!(!report.resolved[0].dismissed);
+        assert_eq!(report.resolved[1].id, BeadId::new("wx-2")?);
+        assert_eq!(report.resolved[1].note, DISMISS_NOTE);
+        assert!(report.resolved[1].dismissed);
+        assert_eq!(report.remaining, 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn missing_option_stays_on_the_status_line() -> Result<()> {
+        let runner = RecordingRunner::default();
+        let bd = BdClient::with_runner(runner.clone());
+        let mut term = ScriptedTerminal::new(&[&[PickerKey::Char('7')], &[PickerKey::Esc]], &[]);
+
+        let report = pick(&bd, opts(), &mut term).await?;
+
+        assert!(report.launched);
+        assert!(report.resolved.is_empty());
+        assert_eq!(report.remaining, 2);
+        let last = term.frames.last().map(String::as_str).unwrap_or_default();
+        assert!(last.contains("option 7 not found in wx-1"), "{last}");
+        assert!(runner.calls().iter().all(|c| c[0] == "list"));
+        Ok(())
+    }
+
+    #[test]
+    fn frame_shows_list_and_selected_options_side_by_side() -> Result<()> {
+        let beads: Vec<Bead> = serde_json::from_str(CLARIFIES)?;
+        let refs: Vec<&Bead> = beads.iter().collect();
+        let mut picker = Picker::new(&refs);
+        picker.handle(PickerKey::Right);
+
+        let frame = compose_picker_frame(&picker, 90, 14);
+        let rows: Vec<&str> = frame.lines().collect();
+
+        assert_eq!(rows[0], "loom msg — 2 outstanding clarify(s)");
+        assert!(rows[2].starts_with("› wx-1 storage backend"), "{frame}");
+        assert!(rows[2].ends_with(" │ wx-1 — Pick a store"), "{frame}");
+        assert!(rows[3].starts_with("  wx-2 Name the flag"), "{frame}");
+        assert!(frame.contains("│ Options — storage backend"), "{frame}");
+        assert!(frame.contains("│   1. SQLite"), "{frame}");
+        assert!(frame.contains("│ › 2. Postgres"), "{frame}");
+        assert!(frame.contains("│      Server."), "{frame}");
+        assert_eq!(rows.len(), 14);
+        assert_eq!(rows[13], KEY_HELP);
+        assert!(rows.iter().all(|r| r.chars().count() <= 90), "{frame}");
+        Ok(())
+    }
+
+    fn kept_desc() -> Result<String> {
+        let beads: Vec<Bead> = serde_json::from_str(CLARIFIES)?;
+        Ok(beads[0].description.clone())
+    }
+}
@@ -1,3 +1,4 @@
+use loom_core::bd::{BdClient, CommandRunner, UpdateOpts};
 use loom_core::identifier::BeadId;
 
 use super::error::MsgError;
@@ -12,6 +13,15 @@ pub enum FastReply {
     Verbatim { note: String },
 }
 
+impl FastReply {
+    /// The note text, whichever way the choice resolved.
+    pub fn note(&self) -> &str {
+        match self {
+            Self::Option { note, .. } | Self::Verbatim { note } => note,
+        }
+    }
+}
+
 /// Compose the bead note for a `-a <choice>` fast-reply.
 ///
 /// - Pure-integer `choice` → look up `### Option <choice>` in the parsed
@@ -62,6 +72,27 @@ fn resolve_option(bead: &BeadId, n: u32, parsed: &OptionsParse) -> Result<FastRe
 pub const DISMISS_NOTE: &str =
     "Dismissed via loom msg -d. Agent should work around the open question.";
 
+/// Append `note` to `bead`'s notes and drop `loom:clarify` in one `bd
+/// update`, once the clarify has been answered or dismissed. Shared by the
+/// `-a` / `-d` fast paths and the `--pick` picker so every resolution
+/// touches the bead the same way.
+pub async fn clear_clarify<R: CommandRunner>(
+    bd: &BdClient<R>,
+    bead: &BeadId,
+    note: &str,
+) -> Result<(), MsgError> {
+    bd.update(
+        bead,
+        UpdateOpts {
+            remove_labels: vec!["loom:clarify".to_string()],
+            append_notes: Some(note.to_string()),
+            ..UpdateOpts::default()
+        },
+    )
+    .await?;
+    Ok(())
+}
+
 #[cfg(test)]
 #[expect(
     clippy::expect_used,
@@ -0,0 +1,184 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use tokio::process::Command;
+use tracing::info;
+
+use loom_core::bd::{BdBackend, BdClient, CommandRunner, ListOpts};
+use loom_core::identifier::SpecLabel;
+
+use super::error::MsgError;
+use super::list::filter_clarifies;
+
+/// Which loop a spec goes back to once its last clarify is cleared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResumeStep {
+    /// The spec still has open beads: `loom run` picks up the next ready
+    /// one — including the bead that raised the clarify — and hands off to
+    /// `loom check` by itself when the queue drains.
+    Run,
+    /// Every bead is closed, so the clarify came out of review: `loom check`
+    /// re-runs the reviewer and push gate.
+    Check,
+}
+
+impl ResumeStep {
+    /// The `loom` subcommand this step execs.
+    pub fn subcommand(self) -> &'static str {
+        match self {
+            Self::Run => "run",
+            Self::Check => "check",
+        }
+    }
+}
+
+/// Of the specs that just had a clarify cleared, the ones with no
+/// `loom:clarify` bead left — nothing stands between them and the loop.
+/// Duplicates collapse; order follows `cleared`.
+pub async fn unblocked_specs<R: CommandRunner>(
+    bd: &BdClient<R>,
+    cleared: &[SpecLabel],
+) -> Result<Vec<SpecLabel>, MsgError> {
+    if cleared.is_empty() {
+        return Ok(Vec::new());
+    }
+    let outstanding = bd
+        .list(ListOpts {
+            status: None,
+            label: Some("loom:clarify".to_string()),
+        })
+        .await?;
+    let mut seen = HashSet::new();
+    Ok(cleared
+        .iter()
+        .filter(|label| seen.insert(*label))
+        .filter(|label| filter_clarifies(&outstanding, Some(label)).is_empty())
+        .cloned()
+        .collect())
+}
+
+/// Decide whether `spec` resumes with `loom run` or `loom check`.
+pub async fn resume_step<R: CommandRunner>(
+    bd: &BdClient<R>,
+    spec: &SpecLabel,
+) -> Result<ResumeStep, MsgError> {
+    let beads = bd
+        .list(ListOpts {
+            status: None,
+            label: Some(format!("spec:{}", spec.as_str())),
+        })
+        .await?;
+    if beads.iter().any(|b| b.status != "closed") {
+        Ok(ResumeStep::Run)
+    } else {
+        Ok(ResumeStep::Check)
+    }
+}
+
+/// `loom msg --resume`: run `loom <step> -s <spec>` in `workspace` and wait
+/// for it, the same child-exec handoff `loom run` uses for `loom check`.
+/// The child inherits `backend`. The caller must have released its own
+/// spec lock first — the child takes it.
+pub async fn exec_resume(
+    loom_bin: &Path,
+    workspace: &Path,
+    backend: &BdBackend,
+    spec: &SpecLabel,
+    step: ResumeStep,
+) -> Result<(), MsgError> {
+    info!(spec = %spec, step = step.subcommand(), "loom msg: resuming loop");
+    let status = Command::new(loom_bin)
+        .current_dir(workspace)
+        .env(BdBackend::ENV, backend.name())
+        .arg(step.subcommand())
+        .arg("-s")
+        .arg(spec.as_str())
+        .status()
+        .await
+        .map_err(|source| MsgError::ResumeSpawn {
+            step: step.subcommand(),
+            source,
+        })?;
+    if !status.success() {
+        return Err(MsgError::ResumeHandoff {
+            step: step.subcommand(),
+            status: status.to_string(),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use loom_core::bd::{FakeBd, ScriptedRunner};
+    use std::os::unix::fs::PermissionsExt;
+
+    const BETA_CLARIFY_LEFT: &str = r#"[
+        {"id":"wx-2","title":"q","status":"open","labels":["loom:clarify","spec:beta"]}
+    ]"#;
+
+    #[tokio::test]
+    async fn only_specs_without_remaining_clarifies_are_unblocked() -> Result<()> {
+        let bd = BdClient::with_runner(ScriptedRunner::new(&[BETA_CLARIFY_LEFT]));
+        let cleared = [
+            SpecLabel::new("alpha"),
+            SpecLabel::new("beta"),
+            SpecLabel::new("alpha"),
+        ];
+        let unblocked = unblocked_specs(&bd, &cleared).await?;
+        assert_eq!(unblocked, [SpecLabel::new("alpha")]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn open_beads_resume_run_and_closed_ones_check() -> Result<()> {
+        let bd = BdClient::with_runner(ScriptedRunner::new(&[
+            r#"[{"id":"wx-1","title":"t","status":"closed"},{"id":"wx-3","title":"t","status":"in_progress"}]"#,
+            r#"[{"id":"wx-1","title":"t","status":"closed"}]"#,
+        ]));
+        let spec = SpecLabel::new("alpha");
+        assert_eq!(resume_step(&bd, &spec).await?, ResumeStep::Run);
+        assert_eq!(resume_step(&bd, &spec).await?, ResumeStep::Check);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn exec_resume_runs_the_step_for_the_spec() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let bin = dir.path().join("loom-stub");
+        let log = dir.path().join("argv.log");
+        std::fs::write(
+            &bin,
+            format!("#!/bin/sh\necho \"$LOOM_BD_BACKEND $@\" > {log:?}\n"),
+        )?;
+        let mut perm = std::fs::metadata(&bin)?.permissions();
+        perm.set_mode(0o755);
+        std::fs::set_permissions(&bin, perm)?;
+
+        exec_resume(
+            &bin,
+            dir.path(),
+            &BdBackend::Fake(FakeBd::new()),
+            &SpecLabel::new("alpha"),
+            ResumeStep::Check,
+        )
+        .await?;
+        assert_eq!(std::fs::read_to_string(&log)?, "fake check -s alpha\n");
+
+        std::fs::write(&bin, "#!/bin/sh\nexit 3\n")?;
+        let err = exec_resume(
+            &bin,
+            dir.path(),
+            &BdBackend::default(),
+            &SpecLabel::new("alpha"),
+            ResumeStep::Run,
+        )
+        .await
+        .err()
+        .ok_or_else(|| anyhow::anyhow!("expected handoff failure"))?;
+        assert!(matches!(err, MsgError::ResumeHandoff { step: "run", .. }));
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+use std::io::{self, IsTerminal, Read, Write};
+use std::process::Command;
+
+use nix::sys::termios::{self, LocalFlags, SetArg, SpecialCharacterIndices, Termios};
+
+use loom_core::identifier::BeadId;
+
+use super::error::MsgError;
+use super::picker::{PickerKey, PickerTerminal, parse_answer, parse_keys};
+
+/// Switch to the alternate screen and hide the cursor.
+const ENTER_SCREEN: &str = "\x1b[?1049h\x1b[?25l";
+
+/// Show the cursor and return to the main screen.
+const LEAVE_SCREEN: &str = "\x1b[?25h\x1b[?1049l";
+
+/// Clear the screen and home the cursor before each frame.
+const CLEAR_SCREEN: &str = "\x1b[H\x1b[2J";
+
+/// Editor used when neither `$VISUAL` nor `$EDITOR` is set.
+const FALLBACK_EDITOR: &str = "vi";
+
+/// The process's controlling terminal as a [`PickerTerminal`].
+///
+/// The terminal is put into non-canonical, no-echo mode on the alternate
+/// screen at the first [`draw`](PickerTerminal::draw) and restored on drop —
+/// and around every editor launch, so the editor gets a normal terminal.
+/// `ISIG` is cleared too: Ctrl-C arrives as a key and quits the picker
+/// cleanly instead of killing the process with the terminal left raw.
+pub struct TtyTerminal {
+    saved: Termios,
+    active: bool,
+}
+
+impl TtyTerminal {
+    /// Check stdin and stdout are a terminal and remember its settings.
+    /// Nothing is changed until the first frame is drawn.
+    pub fn open() -> Result<Self, MsgError> {
+        if !io::stdin().is_terminal() || !io::stdout().is_terminal() {
+            return Err(MsgError::NotATerminal);
+        }
+        let saved =
+            termios::tcgetattr(io::stdin()).map_err(|e| MsgError::Terminal { source: e.into() })?;
+        Ok(Self {
+            saved,
+            active: false,
+        })
+    }
+
+    fn enter(&mut self) -> io::Result<()> {
+        if self.active {
+            return Ok(());
+        }
+        let mut raw = self.saved.clone();
+        raw.local_flags
+            .remove(LocalFlags::ICANON | LocalFlags::ECHO | LocalFlags::ISIG);
+        raw.control_chars[SpecialCharacterIndices::VMIN as usize] = 1;
+        raw.control_chars[SpecialCharacterIndices::VTIME as usize] = 0;
+        termios::tcsetattr(io::stdin(), SetArg::TCSAFLUSH, &raw)?;
+        self.active = true;
+        let mut out = io::stdout();
+        out.write_all(ENTER_SCREEN.as_bytes())?;
+        out.flush()
+    }
+
+    fn leave(&mut self) -> io::Result<()> {
+        if !self.active {
+            return Ok(());
+        }
+        self.active = false;
+        let mut out = io::stdout();
+        out.write_all(LEAVE_SCREEN.as_bytes())?;
+        out.flush()?;
+        termios::tcsetattr(io::stdin(), SetArg::TCSAFLUSH, &self.saved)?;
+        Ok(())
+    }
+}
+
+impl PickerTerminal for TtyTerminal {
+    fn draw(&mut self, frame: &str) -> io::Result<()> {
+        self.enter()?;
+        let mut out = io::stdout().lock();
+        out.write_all(CLEAR_SCREEN.as_bytes())?;
+        out.write_all(frame.as_bytes())?;
+        out.flush()
+    }
+
+    fn read_keys(&mut self) -> io::Result<Vec<PickerKey>> {
+        let mut buf = [0u8; 64];
+        let n = io::stdin().lock().read(&mut buf)?;
+        if n == 0 {
+            return Ok(vec![PickerKey::Interrupt]);
+        }
+        Ok(parse_keys(&buf[..n]))
+    }
+
+    /// Write `template` to a scratch file, run `$VISUAL` / `$EDITOR` on it
+    /// through `sh` (so values like `code -w` work), and read it back. A
+    /// non-zero editor exit abandons the answer, as `git commit` does. The
+    /// file is created exclusively and removed when it goes out of scope.
+    fn edit_answer(&mut self, _bead: &BeadId, template: &str) -> io::Result<Option<String>> {
+        let mut file = tempfile::Builder::new()
+            .prefix("loom-msg-")
+            .suffix(".md")
+            .tempfile()?;
+        file.write_all(template.as_bytes())?;
+        file.flush()?;
+        let editor = std::env::var("VISUAL")
+            .or_else(|_| std::env::var("EDITOR"))
+            .unwrap_or_else(|_| FALLBACK_EDITOR.to_string());
+
+        self.leave()?;
+        let status = Command::new("sh")
+            .arg("-c")
+            .arg(format!("{editor} \"$1\""))
+            .arg("sh")
+            .arg(file.path())
+            .status();
+        self.enter()?;
+
+        // Editors may replace the file rather than rewrite it, so read it
+        // back by path.
+        let text = std::fs::read_to_string(file.path());
+        if !status?.success() {
+            return Ok(None);
+        }
+        Ok(parse_answer(&text?))
+    }
+}
+
+impl Drop for TtyTerminal {
+    fn drop(&mut self) {
+        let _ = self.leave();
+    }
+}
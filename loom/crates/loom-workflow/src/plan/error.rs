@@ -7,6 +7,8 @@ use thiserror::Error;
 use loom_core::lock::LockError;
 use loom_core::state::StateError;
 
+use crate::prompt::PromptError;
+
 /// Failures raised by [`super::run`] and the helpers it composes.
 #[derive(Debug, Display, Error)]
 pub enum PlanError {
@@ -29,6 +31,9 @@ pub enum PlanError {
         source: io::Error,
     },
 
+    /// failed to load the plan prompt overlay
+    Overlay(#[source] Box<PromptError>),
+
     /// failed to read spec file at {path}
     ReadSpec {
         path: PathBuf,
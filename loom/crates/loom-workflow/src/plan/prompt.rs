@@ -1,9 +1,9 @@
-use askama::Template;
-
+use loom_templates::overlay::ProjectOverlay;
 use loom_templates::plan::{PlanNewContext, PlanUpdateContext};
 
 use super::args::PlanMode;
 use super::error::PlanError;
+use crate::prompt::render_within;
 
 /// Inputs threaded into the plan-new/plan-update context structs.
 ///
@@ -16,27 +16,40 @@ pub struct PlanPromptInputs {
     pub pinned_context: String,
     pub companion_paths: Vec<String>,
     pub exit_signals: String,
+    /// Estimated-token budget the rendered prompt is cut to.
+    pub max_tokens: usize,
+    /// `.wrapix/loom/prompts/plan.{pre,post}.md`.
+    pub overlay: ProjectOverlay,
 }
 
-/// Render the appropriate Askama template for `inputs.mode`. Returns the
-/// rendered prompt body the driver will pass to `wrapix run`.
+/// Render the appropriate Askama template for `inputs.mode` within
+/// `inputs.max_tokens`. Returns the rendered prompt body the driver will
+/// pass to `wrapix run`.
 pub fn render_prompt(inputs: PlanPromptInputs) -> Result<String, PlanError> {
     let body = match inputs.mode {
-        PlanMode::New(label) => PlanNewContext {
-            pinned_context: inputs.pinned_context,
-            label,
-            spec_path: inputs.spec_path,
-            exit_signals: inputs.exit_signals,
-        }
-        .render()?,
-        PlanMode::Update(label) => PlanUpdateContext {
-            pinned_context: inputs.pinned_context,
-            label,
-            spec_path: inputs.spec_path,
-            companion_paths: inputs.companion_paths,
-            exit_signals: inputs.exit_signals,
-        }
-        .render()?,
+        PlanMode::New(label) => render_within(
+            "plan_new.md",
+            PlanNewContext {
+                pinned_context: inputs.pinned_context,
+                label,
+                spec_path: inputs.spec_path,
+                exit_signals: inputs.exit_signals,
+                overlay: inputs.overlay,
+            },
+            inputs.max_tokens,
+        )?,
+        PlanMode::Update(label) => render_within(
+            "plan_update.md",
+            PlanUpdateContext {
+                pinned_context: inputs.pinned_context,
+                label,
+                spec_path: inputs.spec_path,
+                companion_paths: inputs.companion_paths,
+                exit_signals: inputs.exit_signals,
+                overlay: inputs.overlay,
+            },
+            inputs.max_tokens,
+        )?,
     };
     Ok(body)
 }
@@ -46,6 +59,7 @@ pub fn render_prompt(inputs: PlanPromptInputs) -> Result<String, PlanError> {
 mod tests {
     use super::*;
     use loom_core::identifier::SpecLabel;
+    use loom_templates::overlay::OverlayFragment;
 
     fn inputs_new() -> PlanPromptInputs {
         PlanPromptInputs {
@@ -54,6 +68,8 @@ mod tests {
             pinned_context: "PIN".into(),
             companion_paths: vec![],
             exit_signals: "LOOM_COMPLETE".into(),
+            max_tokens: 50_000,
+            overlay: ProjectOverlay::default(),
         }
     }
 
@@ -64,6 +80,8 @@ mod tests {
             pinned_context: "PIN".into(),
             companion_paths: vec!["lib/sandbox/".into()],
             exit_signals: "LOOM_COMPLETE".into(),
+            max_tokens: 50_000,
+            overlay: ProjectOverlay::default(),
         }
     }
 
@@ -81,4 +99,25 @@ mod tests {
         assert!(body.contains("# Specification Update Interview"));
         assert!(body.contains("- lib/sandbox/"));
     }
+
+    #[test]
+    fn overlay_fragments_render_into_both_plan_templates() {
+        let overlay = ProjectOverlay {
+            pre: vec![OverlayFragment {
+                source: ".wrapix/loom/prompts/plan.pre.md".into(),
+                body: "Ask about migrations.".into(),
+            }],
+            post: vec![],
+        };
+        for inputs in [inputs_new(), inputs_update()] {
+            let body = render_prompt(PlanPromptInputs {
+                overlay: overlay.clone(),
+                ..inputs
+            })
+            .expect("render");
+            assert!(
+                body.contains("<!-- .wrapix/loom/prompts/plan.pre.md -->\nAsk about migrations.")
+            );
+        }
+    }
 }
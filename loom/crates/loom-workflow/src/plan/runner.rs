@@ -4,7 +4,7 @@ use std::time::Duration;
 
 use tracing::info;
 
-use loom_core::config::{ExitSignalsConfig, LoomConfig};
+use loom_core::config::{LoomConfig, Phase};
 use loom_core::identifier::SpecLabel;
 use loom_core::lock::LockManager;
 use loom_core::state::StateDb;
@@ -14,6 +14,7 @@ use super::command::{WRAPIX_BIN, build_wrapix_argv};
 use super::companions::reconcile_companions;
 use super::error::PlanError;
 use super::prompt::{PlanPromptInputs, render_prompt};
+use crate::prompt::{load_overlay, read_pinned_context, render_exit_signals};
 
 /// Default timeout used by [`run`] — mirrors the rest of the spec-scoped
 /// command surface (see `LockManager::acquire_spec`).
@@ -77,8 +78,15 @@ pub fn run_with_timeout(
         });
     }
 
-    let pinned_context = read_pinned_context(workspace, &cfg.pinned_context)?;
+    let pinned_context = read_pinned_context(workspace, &cfg.pinned_context).map_err(|source| {
+        PlanError::ReadPinnedContext {
+            path: workspace.join(&cfg.pinned_context),
+            source,
+        }
+    })?;
     let exit_signals = render_exit_signals(&cfg.exit_signals);
+    let overlay =
+        load_overlay(workspace, Phase::Plan, None).map_err(|e| PlanError::Overlay(Box::new(e)))?;
 
     let db = StateDb::open(workspace.join(".wrapix/loom/state.db"))?;
     let companion_paths = if is_new {
@@ -93,6 +101,8 @@ pub fn run_with_timeout(
         pinned_context,
         companion_paths,
         exit_signals,
+        max_tokens: cfg.prompt_budget(Phase::Plan),
+        overlay,
     })?;
 
     let argv = build_wrapix_argv(workspace, &prompt_body);
@@ -124,22 +134,6 @@ pub fn run_with_timeout(
     })
 }
 
-fn read_pinned_context(workspace: &Path, rel: &str) -> Result<String, PlanError> {
-    let path = workspace.join(rel);
-    match std::fs::read_to_string(&path) {
-        Ok(s) => Ok(s),
-        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(String::new()),
-        Err(source) => Err(PlanError::ReadPinnedContext { path, source }),
-    }
-}
-
-fn render_exit_signals(cfg: &ExitSignalsConfig) -> String {
-    format!(
-        "- `{}`\n- `{}`\n- `{}`",
-        cfg.complete, cfg.blocked, cfg.clarify
-    )
-}
-
 #[cfg(test)]
 #[expect(clippy::unwrap_used, reason = "tests use panicking helpers")]
 mod tests {
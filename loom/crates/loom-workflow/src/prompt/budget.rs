@@ -0,0 +1,55 @@
+//! Fit a context to the phase's `[prompt]` budget and render it.
+
+use loom_templates::budget::Budgeted;
+use tracing::warn;
+
+/// Cut `ctx` to `max_tokens` estimated tokens and render it, warning once
+/// per truncated field so an oversized spec diff or pinned context shows up
+/// in the logs instead of as a confused first turn.
+pub fn render_within<T: Budgeted>(
+    template: &'static str,
+    mut ctx: T,
+    max_tokens: usize,
+) -> Result<String, askama::Error> {
+    for cut in ctx.fit(max_tokens)? {
+        warn!(
+            template,
+            field = cut.field,
+            original_chars = cut.original_chars,
+            kept_chars = cut.kept_chars,
+            max_tokens,
+            "prompt over budget: truncated field",
+        );
+    }
+    ctx.render()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use askama::Template;
+    use loom_core::identifier::SpecLabel;
+    use loom_templates::budget::estimate_tokens;
+    use loom_templates::overlay::ProjectOverlay;
+    use loom_templates::plan::PlanNewContext;
+
+    #[test]
+    fn render_within_truncates_pinned_context_to_fit() -> Result<()> {
+        let ctx = || PlanNewContext {
+            pinned_context: "x".repeat(40_000),
+            label: SpecLabel::new("alpha"),
+            spec_path: "specs/alpha.md".into(),
+            exit_signals: "- `LOOM_COMPLETE`".into(),
+            overlay: ProjectOverlay::default(),
+        };
+        let out = render_within("plan_new.md", ctx(), 5_000)?;
+        assert!(estimate_tokens(&out) <= 5_000);
+        assert!(out.contains("truncated by loom to fit the prompt budget"));
+        assert_eq!(
+            render_within("plan_new.md", ctx(), 50_000)?,
+            ctx().render()?
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use std::io;
+use std::path::PathBuf;
+
+use displaydoc::Display;
+use thiserror::Error;
+
+use loom_core::bd::BdError;
+use loom_core::state::StateError;
+
+use crate::plan::PlanError;
+
+/// Failures raised while loading overlays, scaffolding them, or rendering
+/// a prompt preview.
+#[derive(Debug, Display, Error)]
+pub enum PromptError {
+    /// failed to read {path}
+    Read {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    /// failed to write {path}
+    Write {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    /// `bd` CLI invocation failed
+    Bd(#[from] BdError),
+
+    /// state-db operation failed
+    State(#[from] StateError),
+
+    /// bead {id} carries no `spec:<label>` label
+    MissingSpecLabel { id: String },
+
+    /// rendering the prompt template failed
+    Render(#[from] askama::Error),
+
+    /// rendering the plan prompt failed
+    Plan(#[from] PlanError),
+}
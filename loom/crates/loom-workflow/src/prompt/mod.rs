@@ -0,0 +1,33 @@
+//! `loom prompt` — project prompt overlays and rendered-prompt previews.
+//!
+//! [`preview`] renders the prompt and `RePinContent` payload of a phase's
+//! template, cut to the phase's `[prompt]` budget by [`render_within`].
+//! `loom plan`, `loom msg --chat` and `loom run` send exactly that; the
+//! `todo` and `check` controllers still send placeholder prompts.
+//! [`build_repin`] and the pinned-context helpers are shared with the
+//! drivers.
+//!
+//! Overlays are optional Markdown files under [`PROMPTS_DIR`]:
+//!
+//! - `<phase>.pre.md` / `<phase>.post.md` fill the `pre` / `post` slots of
+//!   the `plan`, `todo`, `run` and `check` templates (see
+//!   `partial/project_overlay.md`);
+//! - `profile/<name>.md` is appended to the `run` post slot for beads that
+//!   resolve to that profile — e.g. extra quality gates for `profile:rust`.
+//!
+//! HTML comments are stripped and a file with nothing else in it counts as
+//! absent, so the stubs `loom init --prompts` scaffolds leave every prompt
+//! unchanged until someone writes into them.
+
+mod budget;
+mod error;
+mod overlay;
+mod pinning;
+mod preview;
+
+pub use budget::render_within;
+pub use error::PromptError;
+pub use overlay::{PROMPTS_DIR, load_overlay, scaffold};
+pub use pinning::build_repin;
+pub(crate) use pinning::{read_pinned_context, render_exit_signals};
+pub use preview::{Preview, PreviewTarget, preview};
@@ -0,0 +1,223 @@
+//! Loading and scaffolding of the `.wrapix/loom/prompts/` overlay files.
+
+use std::fs;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+
+use loom_core::config::Phase;
+use loom_core::identifier::ProfileName;
+use loom_templates::overlay::{OverlayFragment, ProjectOverlay};
+
+use super::error::PromptError;
+
+/// Workspace-relative directory holding the overlay files.
+pub const PROMPTS_DIR: &str = ".wrapix/loom/prompts";
+
+/// Phases whose templates carry overlay slots, in scaffold order.
+const OVERLAID_PHASES: [Phase; 4] = [Phase::Plan, Phase::Todo, Phase::Run, Phase::Check];
+
+/// Layout notes written next to the stubs by [`scaffold`].
+const README: &str = include_str!("scaffold-readme.md");
+
+/// Read the overlay for `phase`: `<phase>.pre.md` into the `pre` slot,
+/// then `<phase>.post.md` and — when `profile` is given —
+/// `profile/<profile>.md` into the `post` slot. Missing or comment-only
+/// files contribute nothing.
+pub fn load_overlay(
+    workspace: &Path,
+    phase: Phase,
+    profile: Option<&ProfileName>,
+) -> Result<ProjectOverlay, PromptError> {
+    let name = phase.as_str();
+    let mut overlay = ProjectOverlay::default();
+    overlay
+        .pre
+        .extend(read_fragment(workspace, &format!("{name}.pre.md"))?);
+    overlay
+        .post
+        .extend(read_fragment(workspace, &format!("{name}.post.md"))?);
+    // A profile name is a label suffix; never let one walk out of the
+    // prompts directory.
+    if let Some(profile) = profile
+        && matches!(
+            Path::new(profile.as_str()).components().collect::<Vec<_>>()[..],
+            [Component::Normal(_)]
+        )
+    {
+        overlay
+            .post
+            .extend(read_fragment(workspace, &format!("profile/{profile}.md"))?);
+    }
+    Ok(overlay)
+}
+
+/// Write a comment-only stub for every `<phase>.{pre,post}.md` slot, an
+/// empty `profile/` directory and a `README.md` under [`PROMPTS_DIR`].
+/// Existing files are kept; returns the files created.
+pub fn scaffold(workspace: &Path) -> Result<Vec<PathBuf>, PromptError> {
+    let dir = workspace.join(PROMPTS_DIR);
+    let profiles = dir.join("profile");
+    fs::create_dir_all(&profiles).map_err(|source| PromptError::Write {
+        path: profiles.clone(),
+        source,
+    })?;
+    let mut files = vec![(dir.join("README.md"), README.to_string())];
+    for phase in OVERLAID_PHASES {
+        for slot in ["pre", "post"] {
+            files.push((
+                dir.join(format!("{}.{slot}.md", phase.as_str())),
+                stub(phase, slot),
+            ));
+        }
+    }
+    let mut created = Vec::new();
+    for (path, body) in files {
+        if path.exists() {
+            continue;
+        }
+        fs::write(&path, body).map_err(|source| PromptError::Write {
+            path: path.clone(),
+            source,
+        })?;
+        created.push(path);
+    }
+    Ok(created)
+}
+
+fn stub(phase: Phase, slot: &str) -> String {
+    let name = phase.as_str();
+    let place = match slot {
+        "pre" => "right after the pinned project context",
+        _ => "after the phase's instructions, before the exit signals",
+    };
+    format!(
+        "<!-- loom {name} prompt, {slot} slot.\n\
+         Text outside HTML comments here is rendered into every `loom {name}`\n\
+         prompt {place}. Preview with `loom prompt`. -->\n"
+    )
+}
+
+/// The fragment at `<PROMPTS_DIR>/<rel>` with HTML comments stripped, or
+/// `None` when the file is missing or holds nothing else.
+fn read_fragment(workspace: &Path, rel: &str) -> Result<Option<OverlayFragment>, PromptError> {
+    let source = format!("{PROMPTS_DIR}/{rel}");
+    let path = workspace.join(&source);
+    let raw = match fs::read_to_string(&path) {
+        Ok(raw) => raw,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(source) => return Err(PromptError::Read { path, source }),
+    };
+    let body = strip_comments(&raw);
+    let body = body.trim();
+    Ok((!body.is_empty()).then(|| OverlayFragment {
+        source,
+        body: body.to_string(),
+    }))
+}
+
+/// `raw` without its `<!-- ... -->` comments. An unterminated comment is
+/// kept verbatim rather than swallowing the rest of the file.
+fn strip_comments(raw: &str) -> String {
+    let mut out = String::with_capacity(raw.len());
+    let mut rest = raw;
+    while let Some(open) = rest.find("<!--") {
+        let Some(len) = rest[open..].find("-->") else {
+            break;
+        };
+        out.push_str(&rest[..open]);
+        rest = &rest[open + len + "-->".len()..];
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    fn write(workspace: &Path, rel: &str, body: &str) -> Result<()> {
+        let path = workspace.join(PROMPTS_DIR).join(rel);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, body)?;
+        Ok(())
+    }
+
+    #[test]
+    fn load_overlay_reads_phase_slots_and_profile_fragment() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        write(
+            dir.path(),
+            "run.pre.md",
+            "<!-- note -->\nUse the workspace MSRV.\n",
+        )?;
+        write(dir.path(), "run.post.md", "Run `nix flake check`.\n")?;
+        write(
+            dir.path(),
+            "profile/rust.md",
+            "- [ ] `cargo clippy` clean\n",
+        )?;
+        write(dir.path(), "check.post.md", "Review docs too.\n")?;
+
+        let rust = ProfileName::new("rust");
+        let overlay = load_overlay(dir.path(), Phase::Run, Some(&rust))?;
+        let bodies = |f: &[OverlayFragment]| f.iter().map(|f| f.body.clone()).collect::<Vec<_>>();
+        assert_eq!(bodies(&overlay.pre), ["Use the workspace MSRV."]);
+        assert_eq!(
+            bodies(&overlay.post),
+            ["Run `nix flake check`.", "- [ ] `cargo clippy` clean"]
+        );
+        assert_eq!(
+            overlay.post[1].source,
+            ".wrapix/loom/prompts/profile/rust.md"
+        );
+
+        // Other profiles and phases only see their own files.
+        let base = load_overlay(dir.path(), Phase::Run, Some(&ProfileName::new("base")))?;
+        assert_eq!(bodies(&base.post), ["Run `nix flake check`."]);
+        let check = load_overlay(dir.path(), Phase::Check, None)?;
+        assert!(check.pre.is_empty());
+        assert_eq!(bodies(&check.post), ["Review docs too."]);
+        Ok(())
+    }
+
+    #[test]
+    fn profile_names_cannot_escape_the_prompts_dir() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        write(dir.path(), "secret.md", "nope")?;
+        let sneaky = ProfileName::new("../secret");
+        assert!(load_overlay(dir.path(), Phase::Run, Some(&sneaky))?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn scaffold_writes_inert_stubs_and_keeps_existing_files() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        write(dir.path(), "run.post.md", "Mine.\n")?;
+
+        let created = scaffold(dir.path())?;
+        assert_eq!(created.len(), 8, "README + 7 stubs: {created:?}");
+        assert!(dir.path().join(PROMPTS_DIR).join("profile").is_dir());
+        assert_eq!(
+            fs::read_to_string(dir.path().join(PROMPTS_DIR).join("run.post.md"))?,
+            "Mine.\n"
+        );
+        // Untouched stubs add nothing to a prompt.
+        for phase in OVERLAID_PHASES {
+            let overlay = load_overlay(dir.path(), phase, None)?;
+            assert!(overlay.pre.is_empty());
+        }
+        assert!(load_overlay(dir.path(), Phase::Todo, None)?.is_empty());
+
+        assert!(scaffold(dir.path())?.is_empty(), "second run is a no-op");
+        Ok(())
+    }
+
+    #[test]
+    fn strip_comments_keeps_unterminated_comments() {
+        assert_eq!(strip_comments("a<!-- x -->b<!-- y -->c"), "abc");
+        assert_eq!(strip_comments("a<!-- open"), "a<!-- open");
+    }
+}
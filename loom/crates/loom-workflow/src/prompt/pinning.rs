@@ -0,0 +1,77 @@
+//! Context-pinning helpers shared by the phase drivers and the previews:
+//! the pinned project context, the exit-signal list, and the
+//! [`RePinContent`] re-sent after compaction.
+
+use std::io;
+use std::path::Path;
+
+use loom_core::agent::RePinContent;
+use loom_core::config::ExitSignalsConfig;
+use loom_templates::overlay::ProjectOverlay;
+
+/// The `[pinned_context]` file at `<workspace>/<rel>`, or empty when the
+/// project has none. Callers map other read errors into their own error
+/// type.
+pub(crate) fn read_pinned_context(workspace: &Path, rel: &str) -> io::Result<String> {
+    match std::fs::read_to_string(workspace.join(rel)) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(String::new()),
+        read => read,
+    }
+}
+
+/// The `exit_signals` block every phase template renders: one bullet per
+/// configured marker.
+pub(crate) fn render_exit_signals(cfg: &ExitSignalsConfig) -> String {
+    format!(
+        "- `{}`\n- `{}`\n- `{}`",
+        cfg.complete, cfg.blocked, cfg.clarify
+    )
+}
+
+/// The [`RePinContent`] for a phase session: `orientation` names the phase
+/// and its target, the pinned block carries the spec path, companions and
+/// exit signals, and the project overlay fragments ride along as partial
+/// bodies so they survive compaction.
+pub fn build_repin(
+    orientation: String,
+    spec_path: &str,
+    companion_paths: &[String],
+    exit_signals: &str,
+    overlay: &ProjectOverlay,
+) -> RePinContent {
+    let mut pinned_context = format!("Spec: {spec_path}\n");
+    if !companion_paths.is_empty() {
+        pinned_context.push_str(&format!("Companions: {}\n", companion_paths.join(", ")));
+    }
+    pinned_context.push_str(&format!("Exit signals:\n{exit_signals}"));
+    RePinContent {
+        orientation,
+        pinned_context,
+        partial_bodies: overlay
+            .pre
+            .iter()
+            .chain(&overlay.post)
+            .map(|f| f.body.clone())
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn missing_pinned_context_reads_as_empty_and_other_errors_surface() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        assert_eq!(read_pinned_context(dir.path(), "docs/README.md")?, "");
+        std::fs::create_dir(dir.path().join("docs"))?;
+        assert!(read_pinned_context(dir.path(), "docs").is_err());
+        std::fs::write(dir.path().join("docs/README.md"), "# Project\n")?;
+        assert_eq!(
+            read_pinned_context(dir.path(), "docs/README.md")?,
+            "# Project\n"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,386 @@
+//! Render the prompt a phase would send, without spawning an agent.
+//!
+//! Each phase's context is built with the template builders —
+//! [`build_run_context`], [`build_check_context`],
+//! [`build_template_context`], [`build_msg_context`] and the plan
+//! [`render_prompt`] — from the workspace's config, state DB and beads.
+//!
+//! For `plan` and `msg` the preview is what the agent receives: `loom plan`
+//! and `loom msg --chat` render the same templates. A `run` preview is
+//! exactly what a `loom run` attempt sends, serial or `--parallel`, since
+//! the attempt builds its prompt through [`preview`]. The `todo` and `check`
+//! controllers still send placeholder prompts, so their previews show the
+//! template those drivers are meant to render, not what they send today.
+
+use std::path::Path;
+
+use loom_core::agent::RePinContent;
+use loom_core::bd::{BdClient, CommandRunner, ListOpts};
+use loom_core::config::{LoomConfig, Phase};
+use loom_core::identifier::{BeadId, MoleculeId, ProfileName, SpecLabel};
+use loom_core::state::StateDb;
+
+use super::budget::render_within;
+use super::error::PromptError;
+use super::overlay::load_overlay;
+use super::pinning::{build_repin, read_pinned_context, render_exit_signals};
+use crate::check::{CheckContextInputs, beads_summary, build_check_context};
+use crate::msg::{build_msg_context, filter_clarifies};
+use crate::plan::{PlanMode, PlanPromptInputs, render_prompt};
+use crate::run::{RunContextInputs, build_run_context, resolve_profile};
+use crate::todo::{TemplateBaseFields, TierDecision, TodoTemplateContext, build_template_context};
+
+const CLARIFY_LABEL: &str = "loom:clarify";
+
+/// What to preview, per phase.
+#[derive(Debug, Clone)]
+pub enum PreviewTarget {
+    /// `loom plan -u` when `specs/<spec>.md` exists, `-n` otherwise.
+    Plan { spec: SpecLabel },
+    /// `loom todo`: the update prompt over the spec's active molecule, or
+    /// the new-molecule prompt when it has none.
+    Todo { spec: SpecLabel },
+    /// `loom run` for one bead. `previous_failure` renders the retry
+    /// prompt; `profile` overrides the bead's `profile:X` label.
+    Run {
+        bead: BeadId,
+        previous_failure: Option<String>,
+        profile: Option<ProfileName>,
+    },
+    /// `loom check` reviewing the spec's active molecule.
+    Check { spec: SpecLabel },
+    /// `loom msg --chat` over the outstanding clarifies (of `spec`, if set).
+    Msg { spec: Option<SpecLabel> },
+}
+
+/// A rendered preview: the initial prompt and the payload re-sent after
+/// compaction.
+#[derive(Debug, Clone)]
+pub struct Preview {
+    pub prompt: String,
+    pub repin: RePinContent,
+}
+
+/// Build and render the prompt for `target`.
+pub async fn preview<R: CommandRunner>(
+    workspace: &Path,
+    bd: &BdClient<R>,
+    target: PreviewTarget,
+) -> Result<Preview, PromptError> {
+    let cfg = LoomConfig::load(workspace.join(".wrapix/loom/config.toml"))
+        .unwrap_or_else(|_| LoomConfig::default());
+    let pinned_context = read_pinned_context(workspace, &cfg.pinned_context).map_err(|source| {
+        PromptError::Read {
+            path: workspace.join(&cfg.pinned_context),
+            source,
+        }
+    })?;
+    let exit_signals = render_exit_signals(&cfg.exit_signals);
+    let db = StateDb::open(workspace.join(".wrapix/loom/state.db"))?;
+
+    match target {
+        PreviewTarget::Plan { spec } => {
+            let spec_path = spec_path(&spec);
+            let (mode, companion_paths) = if workspace.join(&spec_path).exists() {
+                let companions = db.companions(&spec)?;
+                (PlanMode::Update(spec.clone()), companions)
+            } else {
+                (PlanMode::New(spec.clone()), Vec::new())
+            };
+            let overlay = load_overlay(workspace, Phase::Plan, None)?;
+            let repin = build_repin(
+                format!("loom plan @ {spec}"),
+                &spec_path,
+                &companion_paths,
+                &exit_signals,
+                &overlay,
+            );
+            let prompt = render_prompt(PlanPromptInputs {
+                mode,
+                spec_path,
+                pinned_context,
+                companion_paths,
+                exit_signals,
+                max_tokens: cfg.prompt_budget(Phase::Plan),
+                overlay,
+            })?;
+            Ok(Preview { prompt, repin })
+        }
+        PreviewTarget::Todo { spec } => {
+            let spec_path = spec_path(&spec);
+            let companion_paths = db.companions(&spec)?;
+            let overlay = load_overlay(workspace, Phase::Todo, None)?;
+            let repin = build_repin(
+                format!("loom todo @ {spec}"),
+                &spec_path,
+                &companion_paths,
+                &exit_signals,
+                &overlay,
+            );
+            // A spec `loom use` never registered has no row, hence no notes.
+            let implementation_notes = db
+                .spec(&spec)
+                .ok()
+                .and_then(|row| row.implementation_notes)
+                .unwrap_or_default();
+            let (tier, existing_tasks) = match db.active_molecule(&spec)? {
+                Some(molecule) => {
+                    let beads = bd.list(spec_beads(&spec)).await?;
+                    let tasks: Vec<_> = beads
+                        .into_iter()
+                        .filter(|b| b.id.as_str() != molecule.id.as_str())
+                        .collect();
+                    (
+                        TierDecision::Tasks {
+                            molecule: molecule.id,
+                        },
+                        beads_summary(&tasks),
+                    )
+                }
+                None => (TierDecision::New, None),
+            };
+            let base = TemplateBaseFields {
+                label: spec,
+                spec_path,
+                pinned_context,
+                companion_paths,
+                implementation_notes,
+                exit_signals,
+                overlay,
+            };
+            let max_tokens = cfg.prompt_budget(Phase::Todo);
+            let prompt = match build_template_context(&tier, base, existing_tasks, None) {
+                TodoTemplateContext::New(ctx) => render_within("todo_new.md", ctx, max_tokens)?,
+                TodoTemplateContext::Update(ctx) => {
+                    render_within("todo_update.md", ctx, max_tokens)?
+                }
+            };
+            Ok(Preview { prompt, repin })
+        }
+        PreviewTarget::Run {
+            bead,
+            previous_failure,
+            profile,
+        } => {
+            let bead = bd.show(&bead).await?;
+            let label = bead
+                .labels
+                .iter()
+                .find_map(|l| l.spec_label())
+                .ok_or_else(|| PromptError::MissingSpecLabel {
+                    id: bead.id.to_string(),
+                })?;
+            let spec_path = spec_path(&label);
+            let companion_paths = db.companions(&label)?;
+            let profile = resolve_profile(&bead.labels, profile.as_ref());
+            let overlay = load_overlay(workspace, Phase::Run, Some(&profile))?;
+            let repin = build_repin(
+                format!("loom run @ {}", bead.id),
+                &spec_path,
+                &companion_paths,
+                &exit_signals,
+                &overlay,
+            );
+            let ctx = build_run_context(RunContextInputs {
+                spec_path,
+                pinned_context,
+                companion_paths,
+                molecule_id: bead.parent.as_ref().map(|p| MoleculeId::new(p.as_str())),
+                label,
+                issue_id: bead.id,
+                title: bead.title,
+                description: bead.description,
+                previous_failure,
+                exit_signals,
+                overlay,
+            });
+            let prompt = render_within("run.md", ctx, cfg.prompt_budget(Phase::Run))?;
+            Ok(Preview { prompt, repin })
+        }
+        PreviewTarget::Check { spec } => {
+            let spec_path = spec_path(&spec);
+            let companion_paths = db.companions(&spec)?;
+            let overlay = load_overlay(workspace, Phase::Check, None)?;
+            let repin = build_repin(
+                format!("loom check @ {spec}"),
+                &spec_path,
+                &companion_paths,
+                &exit_signals,
+                &overlay,
+            );
+            let molecule = db.active_molecule(&spec)?;
+            let beads = bd.list(spec_beads(&spec)).await?;
+            let ctx = build_check_context(CheckContextInputs {
+                label: spec,
+                spec_path,
+                pinned_context,
+                companion_paths,
+                base_commit: molecule.as_ref().and_then(|m| m.base_commit.clone()),
+                molecule_id: molecule.map(|m| m.id),
+                beads_summary: beads_summary(&beads),
+                exit_signals,
+                overlay,
+            });
+            let prompt = render_within("check.md", ctx, cfg.prompt_budget(Phase::Check))?;
+            Ok(Preview { prompt, repin })
+        }
+        PreviewTarget::Msg { spec } => {
+            let clarifies = bd
+                .list(ListOpts {
+                    status: None,
+                    label: Some(CLARIFY_LABEL.into()),
+                })
+                .await?;
+            let clarifies = filter_clarifies(&clarifies, spec.as_ref());
+            // Mirrors `loom msg --chat`: the Drafter only ever completes.
+            let exit_signals = format!("- `{}`", cfg.exit_signals.complete);
+            let target = spec.map_or_else(|| "all specs".to_string(), |s| s.to_string());
+            let repin = RePinContent {
+                orientation: format!("loom msg @ {target}"),
+                pinned_context: format!("Exit signals:\n{exit_signals}"),
+                partial_bodies: vec![],
+            };
+            let ctx = build_msg_context(pinned_context, &clarifies, exit_signals);
+            let prompt = render_within("msg.md", ctx, cfg.prompt_budget(Phase::Msg))?;
+            Ok(Preview { prompt, repin })
+        }
+    }
+}
+
+fn spec_path(label: &SpecLabel) -> String {
+    format!("specs/{}.md", label.as_str())
+}
+
+fn spec_beads(label: &SpecLabel) -> ListOpts {
+    ListOpts {
+        status: None,
+        label: Some(format!("spec:{}", label.as_str())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prompt::PROMPTS_DIR;
+    use anyhow::Result;
+    use loom_core::bd::ScriptedRunner;
+    use loom_core::state::ActiveMolecule;
+
+    const RUST_BEAD: &str = r#"[{"id":"wx-1.2","title":"Parse the config","description":"Read TOML.","status":"open","priority":2,"issue_type":"task","labels":["spec:alpha","profile:rust"],"parent":"wx-1"}]"#;
+
+    const CLARIFY: &str = r#"[{"id":"wx-7","title":"Pick a store","description":"","status":"open","priority":2,"issue_type":"task","labels":["loom:clarify","spec:alpha"]}]"#;
+
+    fn workspace() -> Result<tempfile::TempDir> {
+        let dir = tempfile::tempdir()?;
+        let prompts = dir.path().join(PROMPTS_DIR);
+        std::fs::create_dir_all(prompts.join("profile"))?;
+        std::fs::write(prompts.join("run.pre.md"), "PROJECT-PRE\n")?;
+        std::fs::write(prompts.join("profile/rust.md"), "RUST-GATES\n")?;
+        std::fs::write(prompts.join("check.post.md"), "CHECK-POST\n")?;
+        std::fs::create_dir_all(dir.path().join("docs"))?;
+        std::fs::write(dir.path().join("docs/README.md"), "PINNED\n")?;
+        std::fs::create_dir_all(dir.path().join("specs"))?;
+        Ok(dir)
+    }
+
+    #[tokio::test]
+    async fn run_preview_renders_bead_overlay_and_repin() -> Result<()> {
+        let dir = workspace()?;
+        let bd = BdClient::with_runner(ScriptedRunner::always(RUST_BEAD));
+        let bead = BeadId::new("wx-1.2")?;
+        let run = |previous_failure, profile| PreviewTarget::Run {
+            bead: bead.clone(),
+            previous_failure,
+            profile,
+        };
+
+        let out = preview(dir.path(), &bd, run(None, None)).await?;
+        assert!(out.prompt.contains("PINNED"), "{}", out.prompt);
+        assert!(out.prompt.contains("Issue: wx-1.2"));
+        assert!(out.prompt.contains("Read: specs/alpha.md"));
+        assert!(out.prompt.contains("--parent=\"wx-1\""));
+        assert!(out.prompt.contains("PROJECT-PRE"));
+        assert!(out.prompt.contains("RUST-GATES"));
+        assert_eq!(
+            out.repin.to_prompt(),
+            "loom run @ wx-1.2\n\n\
+             Spec: specs/alpha.md\n\
+             Exit signals:\n- `LOOM_COMPLETE`\n- `LOOM_BLOCKED`\n- `LOOM_CLARIFY`\n\n\
+             PROJECT-PRE\n\nRUST-GATES"
+        );
+
+        // A retry carries the failure; `--profile` wins over the label.
+        let base = Some(ProfileName::new("base"));
+        let out = preview(
+            dir.path(),
+            &bd,
+            run(Some("cargo test: 2 failed".into()), base),
+        )
+        .await?;
+        assert!(out.prompt.contains("cargo test: 2 failed"));
+        assert!(!out.prompt.contains("RUST-GATES"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn spec_phases_pick_their_template_from_workspace_state() -> Result<()> {
+        let dir = workspace()?;
+        let bd = BdClient::with_runner(ScriptedRunner::always("[]"));
+        let alpha = SpecLabel::new("alpha");
+        let plan = || PreviewTarget::Plan {
+            spec: alpha.clone(),
+        };
+        let todo = || PreviewTarget::Todo {
+            spec: alpha.clone(),
+        };
+
+        // No spec file and no molecule: the new-spec / new-molecule prompts.
+        let out = preview(dir.path(), &bd, plan()).await?;
+        assert!(out.prompt.starts_with("# Specification Interview"));
+        let out = preview(dir.path(), &bd, todo()).await?;
+        assert!(out.prompt.starts_with("# Task Decomposition"));
+
+        std::fs::write(dir.path().join("specs/alpha.md"), "# Alpha\n")?;
+        let db = StateDb::open(dir.path().join(".wrapix/loom/state.db"))?;
+        db.rebuild(
+            dir.path(),
+            &[ActiveMolecule {
+                id: MoleculeId::new("wx-1"),
+                spec_label: alpha.clone(),
+                base_commit: Some("abc123".into()),
+            }],
+        )?;
+        drop(db);
+        let out = preview(dir.path(), &bd, plan()).await?;
+        assert!(out.prompt.starts_with("# Specification Update Interview"));
+        let out = preview(dir.path(), &bd, todo()).await?;
+        assert!(out.prompt.starts_with("# Add Tasks to Existing Molecule"));
+
+        let out = preview(dir.path(), &bd, PreviewTarget::Check { spec: alpha }).await?;
+        assert!(
+            out.prompt.contains("Base commit**: abc123"),
+            "{}",
+            out.prompt
+        );
+        assert!(out.prompt.contains("Molecule**: wx-1"));
+        assert!(out.prompt.contains("CHECK-POST"));
+        assert_eq!(out.repin.orientation, "loom check @ alpha");
+        assert_eq!(out.repin.partial_bodies, ["CHECK-POST"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn msg_preview_lists_outstanding_clarifies() -> Result<()> {
+        let dir = workspace()?;
+        let bd = BdClient::with_runner(ScriptedRunner::always(CLARIFY));
+        let out = preview(dir.path(), &bd, PreviewTarget::Msg { spec: None }).await?;
+        assert!(out.prompt.contains("wx-7"), "{}", out.prompt);
+        assert!(out.prompt.contains("Pick a store"));
+        assert_eq!(out.repin.orientation, "loom msg @ all specs");
+
+        let beta = Some(SpecLabel::new("beta"));
+        let out = preview(dir.path(), &bd, PreviewTarget::Msg { spec: beta }).await?;
+        assert!(!out.prompt.contains("wx-7"));
+        Ok(())
+    }
+}
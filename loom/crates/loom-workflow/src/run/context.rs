@@ -1,4 +1,5 @@
 use loom_core::identifier::{BeadId, MoleculeId, SpecLabel};
+use loom_templates::overlay::ProjectOverlay;
 use loom_templates::run::{PreviousFailure, RunContext};
 
 /// Inputs for [`build_run_context`]. Constructed once per bead spawn — for
@@ -16,6 +17,8 @@ pub struct RunContextInputs {
     /// inside [`PreviousFailure::new`] when wrapped — see `templates/run.md`.
     pub previous_failure: Option<String>,
     pub exit_signals: String,
+    /// Project prompt fragments for the template's overlay slots.
+    pub overlay: ProjectOverlay,
 }
 
 /// Build the typed [`RunContext`] for a single bead spawn from the driver's
@@ -32,6 +35,7 @@ pub fn build_run_context(inputs: RunContextInputs) -> RunContext {
         description: Some(inputs.description),
         previous_failure: inputs.previous_failure.map(PreviousFailure::new),
         exit_signals: inputs.exit_signals,
+        overlay: inputs.overlay,
     }
 }
 
@@ -53,6 +57,7 @@ mod tests {
             description: "Per-bead loop".into(),
             previous_failure: None,
             exit_signals: "LOOM_COMPLETE".into(),
+            overlay: ProjectOverlay::default(),
         }
     }
 
@@ -0,0 +1,332 @@
+//! Failure digest: the `previous_failure` body a retry receives, built from
+//! the failed session's [`AgentEvent`]s rather than whatever string the
+//! driver had at hand.
+//!
+//! The digest keeps what a retry can act on — the last few failing tool
+//! results, the agent's final message and its `LOOM_BLOCKED` reason, plus any
+//! mid-stream error — sized so the whole body fits under
+//! [`PREVIOUS_FAILURE_MAX_LEN`]. Output of test and build commands is kept
+//! from the tail, where compilers and test runners put their errors; other
+//! tool output is kept from the head.
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Write as _;
+
+use loom_core::agent::AgentEvent;
+use loom_core::identifier::ToolCallId;
+use loom_templates::run::PREVIOUS_FAILURE_MAX_LEN;
+
+use crate::todo::{ExitSignal, parse_exit_signal};
+
+/// Failing tool results kept, most recent last.
+pub const MAX_TOOL_ERRORS: usize = 3;
+
+/// Characters kept of each failing tool result's output.
+const TOOL_OUTPUT_MAX_CHARS: usize = 800;
+
+/// Characters kept of the final assistant message, the blocked reason and
+/// the last stream error.
+const MESSAGE_MAX_CHARS: usize = 600;
+
+/// Programs whose failures print the interesting part last.
+const BUILD_PROGRAMS: &[&str] = &[
+    "bats", "bun", "cargo", "ctest", "deno", "go", "gradle", "jest", "just", "make", "meson",
+    "mvn", "ninja", "nix", "npm", "npx", "pnpm", "pytest", "tox", "tsc", "vitest", "yarn",
+];
+
+/// Accumulates one session's events into a retry digest. Feed it every
+/// event with [`Self::observe`], then [`Self::render`] it when the session
+/// failed.
+#[derive(Debug, Clone, Default)]
+pub struct FailureDigest {
+    /// Display form of each tool call seen, for labelling its result.
+    calls: HashMap<ToolCallId, String>,
+    tool_errors: VecDeque<ToolError>,
+    /// Text of the message currently streaming.
+    message: String,
+    /// The last message completed before `message`.
+    last_message: String,
+    last_error: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct ToolError {
+    call: String,
+    output: String,
+    tail: bool,
+}
+
+impl FailureDigest {
+    pub fn observe(&mut self, event: &AgentEvent) {
+        match event {
+            AgentEvent::MessageDelta { text } => self.message.push_str(text),
+            AgentEvent::ToolCall { id, tool, params } => {
+                self.end_message();
+                let call = params
+                    .get("command")
+                    .and_then(|c| c.as_str())
+                    .map_or_else(|| tool.clone(), |c| c.trim().to_string());
+                self.calls.insert(id.clone(), call);
+            }
+            AgentEvent::ToolResult {
+                id,
+                output,
+                is_error: true,
+            } => {
+                let call = self
+                    .calls
+                    .get(id)
+                    .cloned()
+                    .unwrap_or_else(|| id.to_string());
+                if self.tool_errors.len() == MAX_TOOL_ERRORS {
+                    self.tool_errors.pop_front();
+                }
+                self.tool_errors.push_back(ToolError {
+                    tail: is_build_command(&call),
+                    call,
+                    output: output.clone(),
+                });
+            }
+            AgentEvent::TurnEnd => self.end_message(),
+            AgentEvent::Error { message } => self.last_error = Some(message.clone()),
+            _ => {}
+        }
+    }
+
+    /// The agent's final message: the one still streaming, or the last one
+    /// it finished.
+    pub fn final_message(&self) -> &str {
+        if self.message.trim().is_empty() {
+            self.last_message.trim()
+        } else {
+            self.message.trim()
+        }
+    }
+
+    /// The reason given with `LOOM_BLOCKED`, when the final message ends on
+    /// that signal.
+    pub fn blocked_reason(&self) -> Option<String> {
+        match parse_exit_signal(self.final_message())? {
+            ExitSignal::Blocked { reason } => Some(reason),
+            _ => None,
+        }
+    }
+
+    /// [`Self::render`] when the session failed: it exited non-zero or
+    /// ended on `LOOM_BLOCKED`.
+    pub fn failure(&self, exit_code: i32) -> Option<String> {
+        (exit_code != 0 || self.blocked_reason().is_some()).then(|| self.render(exit_code))
+    }
+
+    /// The digest for a session that exited with `exit_code`; always within
+    /// [`PREVIOUS_FAILURE_MAX_LEN`].
+    pub fn render(&self, exit_code: i32) -> String {
+        let mut out = format!("The previous attempt exited with code {exit_code}.\n");
+        if let Some(reason) = self.blocked_reason() {
+            let _ = write!(out, "\nBlocked: {}\n", head(&reason, MESSAGE_MAX_CHARS));
+        }
+        if let Some(error) = &self.last_error {
+            let _ = write!(out, "\nAgent error: {}\n", head(error, MESSAGE_MAX_CHARS));
+        }
+        if !self.tool_errors.is_empty() {
+            out.push_str("\nFailing tool calls (most recent last):\n");
+            for error in &self.tool_errors {
+                let (kept, end) = if error.tail {
+                    (tail(&error.output, TOOL_OUTPUT_MAX_CHARS), "tail")
+                } else {
+                    (head(&error.output, TOOL_OUTPUT_MAX_CHARS), "head")
+                };
+                let _ = write!(
+                    out,
+                    "\n$ {}\n{}",
+                    head(&error.call, MESSAGE_MAX_CHARS / 4),
+                    kept.trim_end()
+                );
+                if kept.len() < error.output.len() {
+                    let _ = write!(out, "\n[output cut to its {end}]");
+                }
+                out.push('\n');
+            }
+        }
+        let message = self.final_message();
+        if !message.is_empty() {
+            let _ = write!(
+                out,
+                "\nFinal message:\n{}\n",
+                tail(message, MESSAGE_MAX_CHARS)
+            );
+        }
+        head(&out, PREVIOUS_FAILURE_MAX_LEN).to_string()
+    }
+
+    fn end_message(&mut self) {
+        if !self.message.trim().is_empty() {
+            self.last_message = std::mem::take(&mut self.message);
+        } else {
+            self.message.clear();
+        }
+    }
+}
+
+/// Whether `command` runs a build or test tool, judged by the program of
+/// each `&&` / `;` / `|` step (skipping `VAR=value` prefixes) — a known
+/// build tool or anything named `*test`, such as `./tests/loom-test.sh`.
+fn is_build_command(command: &str) -> bool {
+    command
+        .split(['&', ';', '|', '\n'])
+        .filter_map(|step| {
+            step.split_whitespace()
+                .find(|word| !word.contains('='))
+                .map(|program| program.rsplit('/').next().unwrap_or(program))
+        })
+        .any(|program| {
+            let stem = program.split('.').next().unwrap_or(program);
+            BUILD_PROGRAMS.contains(&stem) || stem.ends_with("test")
+        })
+}
+
+/// The first `max` chars of `text`.
+fn head(text: &str, max: usize) -> &str {
+    match text.char_indices().nth(max) {
+        Some((end, _)) => &text[..end],
+        None => text,
+    }
+}
+
+/// The last `max` chars of `text`.
+fn tail(text: &str, max: usize) -> &str {
+    let count = text.chars().count();
+    match text.char_indices().nth(count.saturating_sub(max)) {
+        Some((start, _)) => &text[start..],
+        None => text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn call(id: &str, tool: &str, params: serde_json::Value) -> AgentEvent {
+        AgentEvent::ToolCall {
+            id: ToolCallId::new(id),
+            tool: tool.into(),
+            params,
+        }
+    }
+
+    fn result(id: &str, output: &str, is_error: bool) -> AgentEvent {
+        AgentEvent::ToolResult {
+            id: ToolCallId::new(id),
+            output: output.into(),
+            is_error,
+        }
+    }
+
+    fn delta(text: &str) -> AgentEvent {
+        AgentEvent::MessageDelta { text: text.into() }
+    }
+
+    #[test]
+    fn keeps_build_output_tail_and_other_output_head() {
+        let preamble = "   Compiling loom v0.1.0\n".repeat(100);
+        let mut digest = FailureDigest::default();
+        for event in [
+            call("1", "bash", json!({"command": "cargo test --workspace"})),
+            result(
+                "1",
+                &format!("{preamble}error[E0308]: mismatched types"),
+                true,
+            ),
+            call("2", "read", json!({"path": "src/lib.rs"})),
+            result("2", &format!("no such file{preamble}"), true),
+            call("3", "bash", json!({"command": "ls"})),
+            result("3", "ok", false),
+        ] {
+            digest.observe(&event);
+        }
+        let out = digest.render(1);
+        assert!(out.starts_with("The previous attempt exited with code 1.\n"));
+        assert!(out.contains("$ cargo test --workspace\n"));
+        assert!(out.contains("error[E0308]: mismatched types\n[output cut to its tail]"));
+        assert!(out.contains("$ read\nno such file"));
+        assert!(out.contains("[output cut to its head]"));
+        assert!(!out.contains("$ ls"));
+    }
+
+    #[test]
+    fn keeps_only_the_last_tool_errors() {
+        let mut digest = FailureDigest::default();
+        for n in 0..5 {
+            let id = n.to_string();
+            digest.observe(&call(&id, "bash", json!({"command": format!("step {n}")})));
+            digest.observe(&result(&id, "failed", true));
+        }
+        let out = digest.render(1);
+        assert!(!out.contains("$ step 1\n"));
+        assert!(out.contains("$ step 2\n") && out.contains("$ step 4\n"));
+    }
+
+    #[test]
+    fn surfaces_final_message_and_blocked_reason() {
+        let mut digest = FailureDigest::default();
+        for event in [
+            delta("Let me run the tests."),
+            call("1", "bash", json!({"command": "true"})),
+            result("1", "", false),
+            delta("The schema file is missing.\n"),
+            delta("LOOM_BLOCKED\n"),
+            AgentEvent::TurnEnd,
+            AgentEvent::Error {
+                message: "rate limited".into(),
+            },
+        ] {
+            digest.observe(&event);
+        }
+        assert_eq!(
+            digest.blocked_reason().as_deref(),
+            Some("The schema file is missing.")
+        );
+        let out = digest.render(0);
+        assert!(out.contains("\nBlocked: The schema file is missing.\n"));
+        assert!(out.contains("\nAgent error: rate limited\n"));
+        assert!(out.ends_with("\nFinal message:\nThe schema file is missing.\nLOOM_BLOCKED\n"));
+        assert!(!out.contains("Let me run the tests."));
+    }
+
+    #[test]
+    fn only_failed_sessions_have_a_failure() {
+        let mut digest = FailureDigest::default();
+        digest.observe(&delta("All green."));
+        assert_eq!(digest.failure(0), None);
+        assert_eq!(digest.failure(2), Some(digest.render(2)));
+        digest.observe(&delta("\nLOOM_BLOCKED\n"));
+        assert_eq!(digest.failure(0), Some(digest.render(0)));
+    }
+
+    #[test]
+    fn stays_within_the_previous_failure_cap() {
+        let mut digest = FailureDigest::default();
+        let huge = "x".repeat(10_000);
+        for n in 0..MAX_TOOL_ERRORS {
+            let id = n.to_string();
+            digest.observe(&call(&id, "bash", json!({"command": huge})));
+            digest.observe(&result(&id, &huge, true));
+        }
+        digest.observe(&delta(&huge));
+        digest.observe(&AgentEvent::Error {
+            message: huge.clone(),
+        });
+        assert!(digest.render(1).chars().count() <= PREVIOUS_FAILURE_MAX_LEN);
+    }
+
+    #[test]
+    fn build_commands_are_recognised_per_step() {
+        assert!(is_build_command("cargo clippy -- -D warnings"));
+        assert!(is_build_command("cd loom && RUST_LOG=debug cargo test"));
+        assert!(is_build_command("./tests/loom-test.sh"));
+        assert!(is_build_command("/usr/bin/make check"));
+        assert!(!is_build_command("git status"));
+        assert!(!is_build_command("cat Cargo.toml | head"));
+    }
+}
@@ -5,8 +5,9 @@
 //! & Logging". The loop:
 //!
 //! 1. resolves the per-bead profile from the bead's `profile:X` label (or a
-//!    `--profile` override) and builds a typed [`SpawnConfig`](
-//!    loom_core::agent::SpawnConfig);
+//!    `--profile` override) and its [`BeadOverrides`] from the `model:`,
+//!    `agent:`, `retries:` and `timeout:` labels, and builds a typed
+//!    [`SpawnConfig`](loom_core::agent::SpawnConfig);
 //! 2. renders the [`RunContext`](loom_templates::run::RunContext) prompt with
 //!    the bead's id/title/description, threading the previous-failure body
 //!    (truncated to 4000 chars) on retries;
@@ -14,8 +15,10 @@
 //!    [`AgentBackend`](loom_core::agent::AgentBackend) and tees the
 //!    [`AgentEvent`](loom_core::agent::AgentEvent) stream into the terminal
 //!    renderer + per-bead NDJSON log;
-//! 4. on agent failure retries with `previous_failure` injected up to
-//!    `max_retries` (default 2), then applies the `loom:clarify` label;
+//! 4. on agent failure retries with `previous_failure` — a [`FailureDigest`]
+//!    of the failed session's events — injected up to `max_retries`
+//!    (default 2), climbing the `[loop] retry_models` ladder when one is
+//!    configured, then applies the `loom:clarify` label;
 //! 5. on bead success closes the bead;
 //! 6. on molecule completion (no more ready beads) execs `loom check` —
 //!    continuous mode only.
@@ -27,8 +30,10 @@
 //! finished branches sequentially.
 
 mod context;
+mod digest;
 mod error;
 mod outcome;
+mod overrides;
 mod parallel;
 mod parallelism;
 mod production;
@@ -38,15 +43,17 @@ mod runner;
 mod spawn;
 
 pub use context::{RunContextInputs, build_run_context};
+pub use digest::{FailureDigest, MAX_TOOL_ERRORS};
 pub use error::RunError;
 pub use outcome::{AgentOutcome, BeadResult};
+pub use overrides::{BeadOverrides, timed_out};
 pub use parallel::{
     BatchOutcome, BatchResult, BatchSlot, WorktreeBead, create_worktrees, merge_back,
     run_concurrent_spawns, run_parallel_batch,
 };
 pub use parallelism::{Parallelism, ParallelismError};
-pub use production::{ProductionAgentLoopController, STUB_AGENT_ERROR, list_open_for_spec};
+pub use production::{ProductionAgentLoopController, list_open_for_spec};
 pub use profile::{DEFAULT_PROFILE, resolve_profile};
 pub use retry::{RetryDecision, RetryPolicy};
-pub use runner::{AgentLoopController, RunMode, RunSummary, run_loop};
+pub use runner::{AgentLoopController, AttemptRecord, BeadAttempts, RunMode, RunSummary, run_loop};
 pub use spawn::build_spawn_config;
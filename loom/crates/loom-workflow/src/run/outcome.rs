@@ -8,7 +8,8 @@ pub enum AgentOutcome {
 
     /// Agent exited non-clean — either crashed, ran out of budget, or emitted
     /// `LOOM_BLOCKED`. The string carries the body the driver should inject
-    /// into the next retry's prompt as `previous_failure`.
+    /// into the next retry's prompt as `previous_failure` — for a session
+    /// that ran, its [`FailureDigest`](super::FailureDigest).
     Failure { error: String },
 }
 
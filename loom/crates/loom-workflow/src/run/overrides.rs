@@ -0,0 +1,106 @@
+use std::time::Duration;
+
+use loom_core::agent::AgentKind;
+use loom_core::bd::Label;
+
+use super::retry::RetryPolicy;
+
+/// Per-bead overrides read from the bead's `model:`, `agent:`, `retries:`
+/// and `timeout:` labels, so `loom todo` can mark one bead for a stronger
+/// model or a longer budget without touching global config.
+///
+/// The first well-formed label of each family wins, matching
+/// [`resolve_profile`](super::resolve_profile); malformed values are ignored.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BeadOverrides {
+    /// `model:<provider>/<id>`, as `provider/id`.
+    pub model: Option<String>,
+    /// `agent:pi|claude` (`agent:mock` with the `mock` feature).
+    pub agent: Option<AgentKind>,
+    /// `retries:N` — replaces `[loop] max_retries`.
+    pub retries: Option<u32>,
+    /// `timeout:<dur>` — wall-clock limit on each attempt.
+    pub timeout: Option<Duration>,
+}
+
+impl BeadOverrides {
+    pub fn from_labels(labels: &[Label]) -> Self {
+        Self {
+            model: labels
+                .iter()
+                .find_map(Label::model)
+                .map(|m| format!("{}/{}", m.provider, m.model_id)),
+            agent: labels.iter().find_map(Label::agent_kind),
+            retries: labels.iter().find_map(Label::retries),
+            timeout: labels.iter().find_map(Label::timeout),
+        }
+    }
+
+    /// `base` with this bead's overrides applied: `retries:N` replaces the
+    /// retry budget, and `model:` pins every attempt to that model in place
+    /// of the `[loop] retry_models` ladder.
+    pub fn policy(&self, base: &RetryPolicy) -> RetryPolicy {
+        RetryPolicy {
+            max_retries: self.retries.unwrap_or(base.max_retries),
+            retry_models: match &self.model {
+                Some(model) => vec![model.clone()],
+                None => base.retry_models.clone(),
+            },
+        }
+    }
+}
+
+/// The `previous_failure` body for an attempt cut off by a `timeout:` label.
+pub fn timed_out(limit: Duration) -> String {
+    format!(
+        "The previous attempt timed out after {}s (bead `timeout:` label) before finishing.",
+        limit.as_secs()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(items: &[&str]) -> Vec<Label> {
+        items.iter().map(|s| Label::new(*s)).collect()
+    }
+
+    #[test]
+    fn reads_each_family_first_label_wins() {
+        let o = BeadOverrides::from_labels(&labels(&[
+            "spec:loom-harness",
+            "model:bogus",
+            "model:anthropic/claude-opus",
+            "model:openai/gpt",
+            "agent:claude",
+            "retries:5",
+            "timeout:45m",
+        ]));
+        assert_eq!(
+            o,
+            BeadOverrides {
+                model: Some("anthropic/claude-opus".into()),
+                agent: Some(AgentKind::Claude),
+                retries: Some(5),
+                timeout: Some(Duration::from_secs(45 * 60)),
+            }
+        );
+    }
+
+    #[test]
+    fn policy_applies_retries_and_pins_model() {
+        let base = RetryPolicy {
+            max_retries: 2,
+            retry_models: vec!["cheap".into(), "strong".into()],
+        };
+        let o = BeadOverrides::from_labels(&labels(&["model:anthropic/opus", "retries:0"]));
+        let policy = o.policy(&base);
+        assert_eq!(policy.max_retries, 0);
+        assert_eq!(policy.retry_models, ["anthropic/opus"]);
+
+        let untouched = BeadOverrides::default().policy(&base);
+        assert_eq!(untouched.max_retries, 2);
+        assert_eq!(untouched.retry_models, base.retry_models);
+    }
+}
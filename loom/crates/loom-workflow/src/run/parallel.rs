@@ -264,6 +264,13 @@ mod tests {
             priority: 2,
             issue_type: "task".into(),
             labels: vec![],
+            created_at: None,
+            updated_at: None,
+            closed_at: None,
+            notes: String::new(),
+            assignee: None,
+            parent: None,
+            dependencies: Vec::new(),
         }
     }
 
@@ -1,54 +1,69 @@
 //! Production [`AgentLoopController`] used by the `loom run` binary.
 //!
 //! Wires `BdClient` for bead lookup/close/clarify and a `tokio::process::Command`
-//! shell-out for `exec_check`. The agent backend itself is **not yet
-//! implemented** (`loom-agent` is a placeholder crate as of wx-3hhwq.20), so
-//! [`ProductionAgentLoopController::run_bead`] returns a stub failure that
-//! the retry policy will surface as a clarify after exhausting attempts. The
-//! `next_ready_bead → empty → exec_check` smoke path is fully wired and
-//! exercised end-to-end.
+//! shell-out for `exec_check`. Agent sessions go through a dispatch closure
+//! the binary supplies — the same per-attempt dispatch the `--parallel`
+//! slots use, since only the binary knows the concrete backend types — so a
+//! serial attempt renders the bead's run prompt, escalates along the
+//! [`BeadAttempts`] model ladder, honors the bead's override labels and
+//! hands its failure digest to the retry.
 
 use std::path::PathBuf;
 
-use loom_core::bd::{BdClient, Bead, ListOpts, ReadyOpts, UpdateOpts};
+use std::future::Future;
+
+use loom_core::bd::{BdBackend, BdClient, BdStats, Bead, ListOpts, ReadyOpts, UpdateOpts};
 use loom_core::identifier::{BeadId, SpecLabel};
 use tokio::process::Command;
-use tracing::info;
 
 use super::error::RunError;
 use super::outcome::AgentOutcome;
-use super::runner::AgentLoopController;
-
-/// Stub error body returned by [`ProductionAgentLoopController::run_bead`]
-/// until the `loom-agent` backend lands. The retry policy surfaces this as a
-/// clarify after the configured attempt count.
-pub const STUB_AGENT_ERROR: &str = "loom-agent backend not yet implemented (wx-3hhwq.20)";
+use super::runner::{AgentLoopController, BeadAttempts};
 
-/// Wires the [`AgentLoopController`] trait against the real `BdClient` and a
-/// child `loom check` exec for handoff.
-pub struct ProductionAgentLoopController {
+/// Wires the [`AgentLoopController`] trait against the real `BdClient`, the
+/// binary's agent `dispatch` and a child `loom check` exec for handoff.
+pub struct ProductionAgentLoopController<D> {
     bd: BdClient,
     label: SpecLabel,
     loom_bin: PathBuf,
     workspace: PathBuf,
+    dispatch: D,
 }
 
-impl ProductionAgentLoopController {
-    pub fn new(bd: BdClient, label: SpecLabel, loom_bin: PathBuf, workspace: PathBuf) -> Self {
+impl<D> ProductionAgentLoopController<D> {
+    /// `dispatch` runs one attempt of a bead as [`BeadAttempts`] describes it
+    /// and reports how the session went.
+    pub fn new(
+        bd: BdClient,
+        label: SpecLabel,
+        loom_bin: PathBuf,
+        workspace: PathBuf,
+        dispatch: D,
+    ) -> Self {
         Self {
             bd,
             label,
             loom_bin,
             workspace,
+            dispatch,
         }
     }
 
+    /// `bd` usage by this controller so far, for the end-of-loop summary.
+    pub fn bd_stats(&self) -> BdStats {
+        self.bd.stats()
+    }
+
     fn spec_label_filter(&self) -> String {
         format!("spec:{}", self.label.as_str())
     }
 }
 
-impl AgentLoopController for ProductionAgentLoopController {
+impl<D, F> AgentLoopController for ProductionAgentLoopController<D>
+where
+    D: FnMut(Bead, BeadAttempts) -> F + Send,
+    F: Future<Output = AgentOutcome> + Send,
+{
     async fn next_ready_bead(&mut self) -> Result<Option<Bead>, RunError> {
         let beads = self
             .bd
@@ -63,16 +78,13 @@ impl AgentLoopController for ProductionAgentLoopController {
     async fn run_bead(
         &mut self,
         bead: &Bead,
-        previous_failure: Option<String>,
+        state: &BeadAttempts,
     ) -> Result<AgentOutcome, RunError> {
-        info!(
-            bead = %bead.id,
-            retry = previous_failure.is_some(),
-            "loom run: agent backend stub — returning Failure (loom-agent crate is empty)",
-        );
-        Ok(AgentOutcome::Failure {
-            error: STUB_AGENT_ERROR.to_string(),
-        })
+        let outcome = (self.dispatch)(bead.clone(), state.clone()).await;
+        // The agent session writes to bd itself; nothing cached from
+        // before it may be trusted afterwards.
+        self.bd.invalidate_reads();
+        Ok(outcome)
     }
 
     async fn close_bead(&mut self, bead: &BeadId) -> Result<(), RunError> {
@@ -96,6 +108,7 @@ impl AgentLoopController for ProductionAgentLoopController {
     async fn exec_check(&mut self) -> Result<(), RunError> {
         let status = Command::new(&self.loom_bin)
             .current_dir(&self.workspace)
+            .env(BdBackend::ENV, self.bd.runner().name())
             .arg("check")
             .arg("-s")
             .arg(self.label.as_str())
@@ -1,38 +1,70 @@
-/// Retry policy for `loom run` — see `[loop] max_retries` in
-/// `specs/loom-harness.md`.
-#[derive(Debug, Clone, Copy)]
+use loom_core::config::LoopConfig;
+
+/// Retry policy for `loom run` — see `[loop] max_retries` and
+/// `[loop] retry_models` in `specs/loom-harness.md`.
+#[derive(Debug, Clone)]
 pub struct RetryPolicy {
     /// Maximum number of retries after the initial attempt. Default is 2 —
     /// the bead runs at most three times before escalating to clarify.
     pub max_retries: u32,
+    /// Model escalation ladder: attempt `n` runs `retry_models[n]`, and
+    /// attempts past the end stay on the last rung. Empty (the default)
+    /// keeps the phase's configured model for every attempt.
+    pub retry_models: Vec<String>,
 }
 
 impl Default for RetryPolicy {
     fn default() -> Self {
-        Self { max_retries: 2 }
+        Self {
+            max_retries: 2,
+            retry_models: Vec::new(),
+        }
     }
 }
 
 /// What the run loop should do after an agent failure.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum RetryDecision {
-    /// Retry the bead with `previous_failure` injected into the next prompt.
-    Retry { previous_failure: String },
+    /// Retry the bead with `previous_failure` injected into the next prompt,
+    /// on `model` when the ladder names one for that attempt.
+    Retry {
+        previous_failure: String,
+        model: Option<String>,
+    },
     /// Out of retries — apply `loom:clarify` and stop processing this bead.
     GiveUp,
 }
 
 impl RetryPolicy {
+    /// The policy `[loop]` configures.
+    pub fn from_config(cfg: &LoopConfig) -> Self {
+        Self {
+            max_retries: cfg.max_retries,
+            retry_models: cfg.retry_models.clone(),
+        }
+    }
+
+    /// The ladder's model for `attempt` (0 = the first run), or `None` to
+    /// use the phase's configured model.
+    pub fn model_for(&self, attempt: u32) -> Option<&str> {
+        let rung = usize::try_from(attempt)
+            .unwrap_or(usize::MAX)
+            .min(self.retry_models.len().checked_sub(1)?);
+        self.retry_models.get(rung).map(String::as_str)
+    }
+
     /// Decide whether to retry given the number of retries already consumed.
     /// `retries_used` is 0 when the *first* failure occurs (no retries yet).
     /// The function moves the failure body into the [`RetryDecision::Retry`]
-    /// variant so the caller can thread it back into the next prompt.
+    /// variant so the caller can thread it back into the next prompt, along
+    /// with the model the next attempt escalates to.
     pub fn decide(&self, retries_used: u32, failure: String) -> RetryDecision {
         if retries_used >= self.max_retries {
             RetryDecision::GiveUp
         } else {
             RetryDecision::Retry {
                 previous_failure: failure,
+                model: self.model_for(retries_used + 1).map(str::to_string),
             }
         }
     }
@@ -48,29 +80,66 @@ mod tests {
         assert_eq!(RetryPolicy::default().max_retries, 2);
     }
 
+    fn policy(max_retries: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_retries,
+            ..RetryPolicy::default()
+        }
+    }
+
     #[test]
     fn retries_when_attempts_remain() {
-        let p = RetryPolicy { max_retries: 2 };
+        let p = policy(2);
         match p.decide(0, "boom".to_string()) {
-            RetryDecision::Retry { previous_failure } => assert_eq!(previous_failure, "boom"),
+            RetryDecision::Retry {
+                previous_failure,
+                model: None,
+            } => assert_eq!(previous_failure, "boom"),
             other => panic!("expected Retry, got {other:?}"),
         }
         match p.decide(1, "boom2".to_string()) {
-            RetryDecision::Retry { previous_failure } => assert_eq!(previous_failure, "boom2"),
+            RetryDecision::Retry {
+                previous_failure,
+                model: None,
+            } => assert_eq!(previous_failure, "boom2"),
             other => panic!("expected Retry, got {other:?}"),
         }
     }
 
+    #[test]
+    fn retries_climb_the_model_ladder_and_stay_on_the_last_rung() {
+        let p = RetryPolicy {
+            max_retries: 3,
+            retry_models: vec!["haiku".into(), "sonnet".into(), "opus".into()],
+        };
+        assert_eq!(p.model_for(0), Some("haiku"));
+        let models: Vec<_> = (0..3)
+            .map(|used| match p.decide(used, "boom".to_string()) {
+                RetryDecision::Retry { model, .. } => model,
+                other => panic!("expected Retry, got {other:?}"),
+            })
+            .collect();
+        assert_eq!(
+            models,
+            [
+                Some("sonnet".into()),
+                Some("opus".into()),
+                Some("opus".into())
+            ]
+        );
+        assert_eq!(policy(2).model_for(0), None);
+    }
+
     #[test]
     fn gives_up_after_max_retries() {
-        let p = RetryPolicy { max_retries: 2 };
+        let p = policy(2);
         assert_eq!(p.decide(2, "boom".to_string()), RetryDecision::GiveUp);
         assert_eq!(p.decide(3, "boom".to_string()), RetryDecision::GiveUp);
     }
 
     #[test]
     fn zero_retries_gives_up_immediately() {
-        let p = RetryPolicy { max_retries: 0 };
+        let p = policy(0);
         assert_eq!(p.decide(0, "boom".to_string()), RetryDecision::GiveUp);
     }
 }
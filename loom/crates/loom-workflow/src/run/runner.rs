@@ -1,8 +1,12 @@
+use std::time::Duration;
+
 use loom_core::bd::Bead;
 use loom_core::identifier::BeadId;
+use tracing::info;
 
 use super::error::RunError;
 use super::outcome::{AgentOutcome, BeadResult};
+use super::overrides::{BeadOverrides, timed_out};
 use super::retry::{RetryDecision, RetryPolicy};
 
 /// Loop-termination policy for `loom run`. `Continuous` is the default — the
@@ -26,6 +30,20 @@ pub struct RunSummary {
     pub molecule_complete: bool,
     /// `loom check` was exec'd (continuous mode + molecule complete).
     pub execed_check: bool,
+    /// Every agent attempt in run order, with the model it ran on.
+    pub attempts: Vec<AttemptRecord>,
+}
+
+/// One agent attempt against a bead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttemptRecord {
+    pub bead: BeadId,
+    /// 0 for the first run, then 1, 2, ... per retry.
+    pub attempt: u32,
+    /// The `[loop] retry_models` rung the attempt ran on; `None` when the
+    /// phase's configured model was used.
+    pub model: Option<String>,
+    pub succeeded: bool,
 }
 
 /// Side-effect surface the [`run_loop`] driver depends on.
@@ -46,12 +64,14 @@ pub trait AgentLoopController: Send {
         &mut self,
     ) -> impl std::future::Future<Output = Result<Option<Bead>, RunError>> + Send;
 
-    /// Run one agent attempt against `bead`, threading `previous_failure` if
-    /// any (the wrapped truncation lives in `loom-templates`).
+    /// Run one agent attempt against `bead`. `state` names the attempt and
+    /// carries its `previous_failure`, if any (the wrapped truncation lives
+    /// in `loom-templates`), and its model, which when set replaces the
+    /// phase's model for this attempt — pi `set_model`, claude `--model`.
     fn run_bead(
         &mut self,
         bead: &Bead,
-        previous_failure: Option<String>,
+        state: &BeadAttempts,
     ) -> impl std::future::Future<Output = Result<AgentOutcome, RunError>> + Send;
 
     /// `bd close <id>` after a successful bead.
@@ -98,7 +118,7 @@ pub async fn run_loop<C: AgentLoopController>(
             }
         };
 
-        let result = process_one_bead(controller, &bead, policy).await?;
+        let result = process_one_bead(controller, &bead, &policy, &mut summary.attempts).await?;
         summary.beads_processed += 1;
 
         match result {
@@ -118,30 +138,124 @@ pub async fn run_loop<C: AgentLoopController>(
     Ok(summary)
 }
 
-/// Run a single bead through the retry state machine.
+/// One bead's walk through the retry state machine: which attempt is next,
+/// the model and `previous_failure` it runs with, and — once an outcome is
+/// in — whether to go again. The bead's override labels
+/// ([`BeadOverrides`]) adjust the policy and bound each attempt's
+/// wall-clock time. Shared by the serial loop and the parallel slots, which
+/// drive their own sessions.
+#[derive(Debug, Clone)]
+pub struct BeadAttempts {
+    bead: BeadId,
+    policy: RetryPolicy,
+    timeout: Option<Duration>,
+    retries_used: u32,
+    previous_failure: Option<String>,
+    model: Option<String>,
+}
+
+impl BeadAttempts {
+    pub fn new(bead: &Bead, policy: &RetryPolicy) -> Self {
+        let overrides = BeadOverrides::from_labels(&bead.labels);
+        let policy = overrides.policy(policy);
+        Self {
+            bead: bead.id.clone(),
+            model: policy.model_for(0).map(str::to_string),
+            policy,
+            timeout: overrides.timeout,
+            retries_used: 0,
+            previous_failure: None,
+        }
+    }
+
+    /// 0 for the first run, then 1, 2, ... per retry.
+    pub fn attempt(&self) -> u32 {
+        self.retries_used
+    }
+
+    /// The ladder rung (or `model:` label) the next attempt runs on; `None`
+    /// keeps the phase's model.
+    pub fn model(&self) -> Option<&str> {
+        self.model.as_deref()
+    }
+
+    /// The failed attempt's body to inject into the next prompt.
+    pub fn previous_failure(&self) -> Option<&str> {
+        self.previous_failure.as_deref()
+    }
+
+    /// The bead's `timeout:` label, if any.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Log the attempt about to run.
+    pub fn announce(&self) {
+        info!(
+            bead = %self.bead,
+            attempt = self.retries_used,
+            model = self.model().unwrap_or("(phase default)"),
+            "loom run: attempt",
+        );
+    }
+
+    /// Record the current attempt's `outcome`. Returns the bead's final
+    /// result, or `None` when it should run again with the updated model and
+    /// `previous_failure`.
+    pub fn settle(
+        &mut self,
+        outcome: AgentOutcome,
+        attempts: &mut Vec<AttemptRecord>,
+    ) -> Option<BeadResult> {
+        attempts.push(AttemptRecord {
+            bead: self.bead.clone(),
+            attempt: self.retries_used,
+            model: self.model.clone(),
+            succeeded: outcome == AgentOutcome::Success,
+        });
+        match outcome {
+            AgentOutcome::Success => Some(BeadResult::Done),
+            AgentOutcome::Failure { error } => {
+                match self.policy.decide(self.retries_used, error.clone()) {
+                    RetryDecision::Retry {
+                        previous_failure,
+                        model,
+                    } => {
+                        self.retries_used += 1;
+                        self.previous_failure = Some(previous_failure);
+                        self.model = model;
+                        None
+                    }
+                    RetryDecision::GiveUp => Some(BeadResult::Clarified { last_error: error }),
+                }
+            }
+        }
+    }
+}
+
+/// Run a single bead through [`BeadAttempts`], recording each attempt in
+/// `attempts`.
 async fn process_one_bead<C: AgentLoopController>(
     controller: &mut C,
     bead: &Bead,
-    policy: RetryPolicy,
+    policy: &RetryPolicy,
+    attempts: &mut Vec<AttemptRecord>,
 ) -> Result<BeadResult, RunError> {
-    let mut retries_used: u32 = 0;
-    let mut previous_failure: Option<String> = None;
+    let mut state = BeadAttempts::new(bead, policy);
     loop {
-        match controller.run_bead(bead, previous_failure.clone()).await? {
-            AgentOutcome::Success => return Ok(BeadResult::Done),
-            AgentOutcome::Failure { error } => match policy.decide(retries_used, error) {
-                RetryDecision::Retry {
-                    previous_failure: pf,
-                } => {
-                    retries_used += 1;
-                    previous_failure = Some(pf);
-                }
-                RetryDecision::GiveUp => {
-                    return Ok(BeadResult::Clarified {
-                        last_error: previous_failure.unwrap_or_default(),
-                    });
-                }
+        state.announce();
+        let attempt = controller.run_bead(bead, &state);
+        let outcome = match state.timeout() {
+            Some(limit) => match tokio::time::timeout(limit, attempt).await {
+                Ok(outcome) => outcome?,
+                Err(_) => AgentOutcome::Failure {
+                    error: timed_out(limit),
+                },
             },
+            None => attempt.await?,
+        };
+        if let Some(result) = state.settle(outcome, attempts) {
+            return Ok(result);
         }
     }
 }
@@ -161,6 +275,9 @@ mod tests {
         ready_queue: VecDeque<Bead>,
         agent_outcomes: VecDeque<AgentOutcome>,
         run_calls: Vec<(BeadId, Option<String>)>,
+        models: Vec<Option<String>>,
+        /// Attempts that never finish, so a `timeout:` label has to end them.
+        hung_attempts: u32,
         closed: Vec<BeadId>,
         clarified: Vec<BeadId>,
         check_calls: u32,
@@ -174,9 +291,17 @@ mod tests {
         async fn run_bead(
             &mut self,
             bead: &Bead,
-            previous_failure: Option<String>,
+            state: &BeadAttempts,
         ) -> Result<AgentOutcome, RunError> {
-            self.run_calls.push((bead.id.clone(), previous_failure));
+            self.run_calls.push((
+                bead.id.clone(),
+                state.previous_failure().map(str::to_string),
+            ));
+            self.models.push(state.model().map(str::to_string));
+            if self.hung_attempts > 0 {
+                self.hung_attempts -= 1;
+                std::future::pending::<()>().await;
+            }
             Ok(self
                 .agent_outcomes
                 .pop_front()
@@ -208,6 +333,13 @@ mod tests {
             priority: 2,
             issue_type: "task".into(),
             labels: labels.iter().map(|s| Label::new(*s)).collect(),
+            created_at: None,
+            updated_at: None,
+            closed_at: None,
+            notes: String::new(),
+            assignee: None,
+            parent: None,
+            dependencies: Vec::new(),
         }
     }
 
@@ -289,7 +421,7 @@ mod tests {
             });
         }
 
-        let summary = run_loop(&mut c, RunMode::Once, RetryPolicy { max_retries: 2 }).await?;
+        let summary = run_loop(&mut c, RunMode::Once, RetryPolicy::default()).await?;
 
         assert_eq!(c.run_calls.len(), 3, "initial + 2 retries");
         // Attempt 1 has no previous_failure.
@@ -313,7 +445,7 @@ mod tests {
         });
         c.agent_outcomes.push_back(AgentOutcome::Success);
 
-        let summary = run_loop(&mut c, RunMode::Once, RetryPolicy { max_retries: 2 }).await?;
+        let summary = run_loop(&mut c, RunMode::Once, RetryPolicy::default()).await?;
 
         assert_eq!(c.run_calls.len(), 2);
         assert_eq!(c.run_calls[1].1.as_deref(), Some("boom"));
@@ -322,4 +454,125 @@ mod tests {
         assert_eq!(summary.beads_clarified, 0);
         Ok(())
     }
+
+    #[tokio::test]
+    async fn retries_escalate_along_the_model_ladder() -> Result<(), RunError> {
+        let mut c = FakeController::default();
+        c.ready_queue.push_back(bead("wx-1", &[]));
+        for i in 0..2 {
+            c.agent_outcomes.push_back(AgentOutcome::Failure {
+                error: format!("err-{i}"),
+            });
+        }
+        c.agent_outcomes.push_back(AgentOutcome::Success);
+        let policy = RetryPolicy {
+            max_retries: 2,
+            retry_models: vec!["cheap".into(), "strong".into()],
+        };
+
+        let summary = run_loop(&mut c, RunMode::Once, policy).await?;
+
+        let models: Vec<_> = c.models.iter().map(Option::as_deref).collect();
+        assert_eq!(models, [Some("cheap"), Some("strong"), Some("strong")]);
+        let attempts: Vec<_> = summary
+            .attempts
+            .iter()
+            .map(|a| (a.attempt, a.model.as_deref(), a.succeeded))
+            .collect();
+        assert_eq!(
+            attempts,
+            [
+                (0, Some("cheap"), false),
+                (1, Some("strong"), false),
+                (2, Some("strong"), true),
+            ]
+        );
+        assert_eq!(c.closed, vec![BeadId::new("wx-1").expect("valid")]);
+        Ok(())
+    }
+
+    #[test]
+    fn bead_attempts_carry_the_failure_and_climb_the_ladder_until_giving_up() {
+        let policy = RetryPolicy {
+            max_retries: 1,
+            retry_models: vec!["cheap".into(), "strong".into()],
+        };
+        let mut state = BeadAttempts::new(&bead("wx-1", &[]), &policy);
+        let mut attempts = Vec::new();
+        assert_eq!((state.attempt(), state.model()), (0, Some("cheap")));
+        assert_eq!(state.previous_failure(), None);
+
+        let failure = |error: &str| AgentOutcome::Failure {
+            error: error.into(),
+        };
+        assert_eq!(state.settle(failure("digest-0"), &mut attempts), None);
+        assert_eq!((state.attempt(), state.model()), (1, Some("strong")));
+        assert_eq!(state.previous_failure(), Some("digest-0"));
+
+        assert_eq!(
+            state.settle(failure("digest-1"), &mut attempts),
+            Some(BeadResult::Clarified {
+                last_error: "digest-1".into()
+            })
+        );
+        let models: Vec<_> = attempts.iter().map(|a| a.model.as_deref()).collect();
+        assert_eq!(models, [Some("cheap"), Some("strong")]);
+    }
+
+    #[test]
+    fn bead_attempts_take_the_retries_label_over_the_policy() {
+        let mut state = BeadAttempts::new(&bead("wx-1", &["retries:0"]), &RetryPolicy::default());
+        let result = state.settle(
+            AgentOutcome::Failure {
+                error: "boom".into(),
+            },
+            &mut Vec::new(),
+        );
+        assert_eq!(
+            result,
+            Some(BeadResult::Clarified {
+                last_error: "boom".into()
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn bead_labels_override_retries_and_model() -> Result<(), RunError> {
+        let mut c = FakeController::default();
+        c.ready_queue
+            .push_back(bead("wx-1", &["model:anthropic/opus", "retries:1"]));
+        for i in 0..3 {
+            c.agent_outcomes.push_back(AgentOutcome::Failure {
+                error: format!("err-{i}"),
+            });
+        }
+        let policy = RetryPolicy {
+            max_retries: 2,
+            retry_models: vec!["cheap".into(), "strong".into()],
+        };
+
+        let summary = run_loop(&mut c, RunMode::Once, policy).await?;
+
+        let models: Vec<_> = c.models.iter().map(Option::as_deref).collect();
+        assert_eq!(models, [Some("anthropic/opus"), Some("anthropic/opus")]);
+        assert_eq!(summary.beads_clarified, 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn timeout_label_fails_a_hung_attempt() -> Result<(), RunError> {
+        let mut c = FakeController::default();
+        c.ready_queue.push_back(bead("wx-1", &["timeout:1s"]));
+        c.hung_attempts = 1;
+        c.agent_outcomes.push_back(AgentOutcome::Success);
+
+        let summary = run_loop(&mut c, RunMode::Once, RetryPolicy::default()).await?;
+
+        assert_eq!(c.run_calls.len(), 2);
+        let previous = c.run_calls[1].1.as_deref().expect("retry carries failure");
+        assert!(previous.contains("timed out after 1s"), "{previous}");
+        assert!(!summary.attempts[0].succeeded && summary.attempts[1].succeeded);
+        assert_eq!(c.closed, vec![BeadId::new("wx-1").expect("valid")]);
+        Ok(())
+    }
 }
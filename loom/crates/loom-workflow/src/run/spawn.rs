@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use loom_core::agent::{RePinContent, SpawnConfig};
+use loom_core::agent::{RePinContent, SpawnConfig, ToolPolicy};
 
 /// Build the [`SpawnConfig`] handed to `wrapix run-bead --spawn-config` for a
 /// `loom run` bead spawn.
@@ -27,6 +27,9 @@ pub fn build_spawn_config(
         agent_args,
         repin,
         model: None,
+        thinking: None,
+        command_protocol: None,
+        tool_policy: ToolPolicy::default(),
     }
 }
 
@@ -30,6 +30,8 @@ pub struct Annotation {
     pub file: Option<PathBuf>,
     pub function: Option<String>,
     pub checked: bool,
+    /// 1-based line of the `- [ ]` / `- [x]` item in the spec file.
+    pub line: usize,
 }
 
 /// Parse all annotations in `spec_path`. Returns the rows in order.
@@ -50,11 +52,10 @@ fn parse_body(body: &str, spec_dir: &Path) -> Option<Vec<Annotation>> {
     let mut out = Vec::new();
     let mut in_criteria = false;
     let mut in_fence = false;
-    let mut pending: Option<(String, bool)> = None;
+    let mut pending: Option<(String, bool, usize)> = None;
     let mut saw_criteria = false;
 
-    for raw in body.lines() {
-        let line = raw;
+    for (index, line) in body.lines().enumerate() {
         let trimmed = line.trim_start();
         if trimmed.starts_with("```") {
             in_fence = !in_fence;
@@ -68,13 +69,14 @@ fn parse_body(body: &str, spec_dir: &Path) -> Option<Vec<Annotation>> {
             continue;
         }
         if in_criteria && is_other_h2(line) {
-            if let Some((text, checked)) = pending.take() {
+            if let Some((text, checked, line)) = pending.take() {
                 out.push(Annotation {
                     criterion: text,
                     kind: AnnotationKind::None,
                     file: None,
                     function: None,
                     checked,
+                    line,
                 });
             }
             break;
@@ -83,20 +85,21 @@ fn parse_body(body: &str, spec_dir: &Path) -> Option<Vec<Annotation>> {
             continue;
         }
         if let Some((checked, text)) = parse_checkbox(line) {
-            if let Some((prev, prev_checked)) = pending.take() {
+            if let Some((prev, prev_checked, prev_line)) = pending.take() {
                 out.push(Annotation {
                     criterion: prev,
                     kind: AnnotationKind::None,
                     file: None,
                     function: None,
                     checked: prev_checked,
+                    line: prev_line,
                 });
             }
-            pending = Some((text, checked));
+            pending = Some((text, checked, index + 1));
             saw_criteria = true;
             continue;
         }
-        if let Some((text, checked)) = pending.as_ref()
+        if let Some((text, checked, checkbox_line)) = pending.as_ref()
             && let Some((kind, target)) = parse_annotation_line(line)
         {
             let (file, function) = resolve_annotation_link(&target, spec_dir);
@@ -106,17 +109,19 @@ fn parse_body(body: &str, spec_dir: &Path) -> Option<Vec<Annotation>> {
                 file: Some(file),
                 function,
                 checked: *checked,
+                line: *checkbox_line,
             });
             pending = None;
         }
     }
-    if let Some((text, checked)) = pending {
+    if let Some((text, checked, line)) = pending {
         out.push(Annotation {
             criterion: text,
             kind: AnnotationKind::None,
             file: None,
             function: None,
             checked,
+            line,
         });
     }
     saw_criteria.then_some(out)
@@ -143,7 +148,7 @@ fn is_other_h2(line: &str) -> bool {
     !rest.starts_with("Success Criteria")
 }
 
-fn parse_checkbox(line: &str) -> Option<(bool, String)> {
+pub(super) fn parse_checkbox(line: &str) -> Option<(bool, String)> {
     let trimmed = line.trim_start();
     let rest = trimmed.strip_prefix("- ")?;
     let rest = rest.strip_prefix('[')?;
@@ -262,6 +267,7 @@ mod tests {
         assert_eq!(row.file.as_deref(), Some(Path::new("tests/x.sh")));
         assert_eq!(row.function.as_deref(), Some("test_thing"));
         assert!(!row.checked);
+        assert_eq!(row.line, 5);
         Ok(())
     }
 
@@ -0,0 +1,538 @@
+//! `loom spec --verify` / `--judge` — check success criteria.
+//!
+//! Port of `run_spec_tests` / `run_verify_test` in `lib/ralph/cmd/spec.sh`:
+//! each `[verify](path#fn)` annotation runs `path fn` (or `path` alone)
+//! from the workspace root. Exit 0 passes, 77 and 78 skip (skipped / not
+//! implemented), anything else — including a missing file or a timeout —
+//! fails. `[judge]` annotations go to [`super::judge`]. Results are grouped
+//! per spec and end with a summary line.
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::Duration;
+
+use loom_core::agent::{ProtocolError, SpawnConfig};
+use loom_core::identifier::{MoleculeId, SpecLabel};
+use tokio::process::Command;
+
+use super::annotations::{Annotation, AnnotationKind, parse_spec_annotations};
+use super::error::SpecError;
+use super::judge::judge;
+use crate::agent::AgentReply;
+
+/// Per-check wall-clock budget when `--timeout` is not given.
+pub const DEFAULT_CHECK_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Exit code a test uses to report itself skipped.
+const EXIT_SKIPPED: i32 = 77;
+/// Exit code a test uses to report itself not implemented yet.
+const EXIT_NOT_IMPLEMENTED: i32 = 78;
+
+/// Lines of captured output echoed under a failing check.
+const FAILURE_TAIL_LINES: usize = 5;
+
+/// Which annotations a run checks: `--verify`, `--judge`, or both (`-a`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CheckOpts {
+    pub verify: bool,
+    pub judge: bool,
+    /// Budget for each test run or judge session.
+    pub timeout: Duration,
+}
+
+impl CheckOpts {
+    fn title(&self) -> &'static str {
+        match (self.verify, self.judge) {
+            (true, true) => "Verify+Judge",
+            (false, true) => "Judge",
+            _ => "Verify",
+        }
+    }
+}
+
+/// Outcome of one success criterion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    Skip,
+}
+
+impl Verdict {
+    fn tag(self) -> &'static str {
+        match self {
+            Self::Pass => "PASS",
+            Self::Fail => "FAIL",
+            Self::Skip => "SKIP",
+        }
+    }
+}
+
+/// One success criterion after its test ran (or was skipped).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckResult {
+    pub criterion: String,
+    pub kind: AnnotationKind,
+    /// The criterion's checkbox line, from [`Annotation::line`].
+    pub line: usize,
+    pub verdict: Verdict,
+    /// Second report line, e.g. `tests/a.sh::test_a (exit 0)`. Empty when
+    /// the verdict needs no explanation.
+    pub detail: String,
+    /// Captured stdout followed by stderr.
+    pub output: String,
+}
+
+impl CheckResult {
+    pub(super) fn of(
+        annotation: &Annotation,
+        verdict: Verdict,
+        detail: String,
+        output: String,
+    ) -> Self {
+        Self {
+            criterion: annotation.criterion.clone(),
+            kind: annotation.kind,
+            line: annotation.line,
+            verdict,
+            detail,
+            output,
+        }
+    }
+}
+
+/// The checks of one spec, in criterion order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpecReport {
+    pub label: SpecLabel,
+    /// Active molecule, shown in the header when known.
+    pub molecule: Option<MoleculeId>,
+    pub results: Vec<CheckResult>,
+}
+
+/// Pass/fail/skip counts over one or more [`SpecReport`]s.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Tally {
+    pub passed: usize,
+    pub failed: usize,
+    pub skipped: usize,
+}
+
+impl Tally {
+    pub fn of(reports: &[SpecReport]) -> Self {
+        let mut tally = Self::default();
+        for result in reports.iter().flat_map(|r| &r.results) {
+            match result.verdict {
+                Verdict::Pass => tally.passed += 1,
+                Verdict::Fail => tally.failed += 1,
+                Verdict::Skip => tally.skipped += 1,
+            }
+        }
+        tally
+    }
+}
+
+/// Check the criteria `opts` selects in `filter`, or in every spec under
+/// `<workspace>/specs/` (minus `README.md`) when `None`. Specs without a
+/// `## Success Criteria` section are silently left out of the multi-spec
+/// run; a filtered spec without one is an error. `ask` runs one judge
+/// session for the spec it names; it is never called without `opts.judge`.
+pub async fn check_specs<A, F>(
+    workspace: &Path,
+    filter: Option<&SpecLabel>,
+    opts: CheckOpts,
+    mut ask: A,
+) -> Result<Vec<SpecReport>, SpecError>
+where
+    A: FnMut(&SpecLabel, SpawnConfig) -> F,
+    F: Future<Output = Result<AgentReply, ProtocolError>>,
+{
+    let specs = match filter {
+        Some(label) => vec![label.clone()],
+        None => spec_labels(&workspace.join("specs"))?,
+    };
+    let mut reports = Vec::with_capacity(specs.len());
+    for label in specs {
+        let spec_path = workspace
+            .join("specs")
+            .join(format!("{}.md", label.as_str()));
+        let annotations = match parse_spec_annotations(&spec_path) {
+            Ok(rows) => rows,
+            Err(SpecError::NoSuccessCriteria { .. }) if filter.is_none() => continue,
+            Err(e) => return Err(e),
+        };
+        let mut results = Vec::with_capacity(annotations.len());
+        let mut ask_spec = |spawn| ask(&label, spawn);
+        for annotation in &annotations {
+            if let Some(result) = check(workspace, annotation, opts, &mut ask_spec).await {
+                results.push(result);
+            }
+        }
+        reports.push(SpecReport {
+            label,
+            molecule: None,
+            results,
+        });
+    }
+    Ok(reports)
+}
+
+/// Sorted labels of `specs_dir/*.md`, excluding `README.md`.
+fn spec_labels(specs_dir: &Path) -> Result<Vec<SpecLabel>, SpecError> {
+    let io = |source| SpecError::Io {
+        path: specs_dir.to_path_buf(),
+        source,
+    };
+    let mut labels = Vec::new();
+    for entry in std::fs::read_dir(specs_dir).map_err(io)? {
+        let path = entry.map_err(io)?.path();
+        if path.extension().is_some_and(|e| e == "md")
+            && let Some(stem) = path.file_stem().and_then(|s| s.to_str())
+            && stem != "README"
+        {
+            labels.push(SpecLabel::new(stem));
+        }
+    }
+    labels.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+    Ok(labels)
+}
+
+/// Result for one annotation. Criteria of a kind `opts` leaves out are
+/// omitted (`None`); unannotated ones are skipped.
+async fn check<A, F>(
+    workspace: &Path,
+    annotation: &Annotation,
+    opts: CheckOpts,
+    ask: &mut A,
+) -> Option<CheckResult>
+where
+    A: FnMut(SpawnConfig) -> F,
+    F: Future<Output = Result<AgentReply, ProtocolError>>,
+{
+    match (annotation.kind, &annotation.file) {
+        (AnnotationKind::Verify, _) if !opts.verify => None,
+        (AnnotationKind::Judge, _) if !opts.judge => None,
+        (AnnotationKind::Verify, Some(file)) => {
+            Some(run_test(workspace, annotation, file, opts.timeout).await)
+        }
+        (AnnotationKind::Judge, Some(file)) => {
+            Some(judge(workspace, annotation, file, opts.timeout, ask).await)
+        }
+        (_, _) => {
+            let mut skipped =
+                CheckResult::of(annotation, Verdict::Skip, String::new(), String::new());
+            skipped.criterion.push_str(" (no annotation)");
+            Some(skipped)
+        }
+    }
+}
+
+async fn run_test(
+    workspace: &Path,
+    annotation: &Annotation,
+    file: &Path,
+    timeout: Duration,
+) -> CheckResult {
+    let function = annotation.function.as_deref();
+    let shown = file.strip_prefix(workspace).unwrap_or(file).display();
+    let target = match function {
+        Some(f) => format!("{shown}::{f}"),
+        None => shown.to_string(),
+    };
+    let result = |verdict, detail, output| CheckResult::of(annotation, verdict, detail, output);
+    let path: PathBuf = workspace.join(file);
+    if !path.is_file() {
+        return result(Verdict::Fail, format!("{target} not found"), String::new());
+    }
+
+    let mut cmd = Command::new(&path);
+    cmd.args(function)
+        .current_dir(workspace)
+        .stdin(Stdio::null())
+        .kill_on_drop(true);
+    let output = match tokio::time::timeout(timeout, cmd.output()).await {
+        Ok(Ok(output)) => output,
+        Ok(Err(e)) => {
+            return result(Verdict::Fail, format!("{target} ({e})"), String::new());
+        }
+        Err(_) => {
+            return result(
+                Verdict::Fail,
+                format!("{target} (timed out after {timeout:?})"),
+                String::new(),
+            );
+        }
+    };
+    let mut captured = String::from_utf8_lossy(&output.stdout).into_owned();
+    captured.push_str(&String::from_utf8_lossy(&output.stderr));
+    match output.status.code() {
+        Some(0) => result(Verdict::Pass, format!("{target} (exit 0)"), captured),
+        Some(EXIT_SKIPPED) => result(
+            Verdict::Skip,
+            format!("{target} (exit {EXIT_SKIPPED} — skipped)"),
+            captured,
+        ),
+        Some(EXIT_NOT_IMPLEMENTED) => result(
+            Verdict::Skip,
+            format!("{target} (exit {EXIT_NOT_IMPLEMENTED} — not implemented)"),
+            captured,
+        ),
+        Some(code) => result(Verdict::Fail, format!("{target} (exit {code})"), captured),
+        None => result(
+            Verdict::Fail,
+            format!("{target} (killed by signal)"),
+            captured,
+        ),
+    }
+}
+
+/// Render `reports` the way `ralph spec --verify` prints them: a header per
+/// spec, one `[PASS]`/`[FAIL]`/`[SKIP]` line per criterion with its detail
+/// (plus the tail of the output for failures), then the summary. `single`
+/// selects the one-spec summary without the spec count.
+pub fn render(reports: &[SpecReport], opts: CheckOpts, single: bool) -> String {
+    let mut out = String::new();
+    for report in reports {
+        let mut header = format!("Loom {}: {}", opts.title(), report.label);
+        if let Some(molecule) = &report.molecule {
+            let _ = write!(header, " ({molecule})");
+        }
+        let _ = writeln!(out, "{header}\n{}", "=".repeat(header.chars().count()));
+        for result in &report.results {
+            let _ = writeln!(out, "  [{}] {}", result.verdict.tag(), result.criterion);
+            if !result.detail.is_empty() {
+                let _ = writeln!(out, "         {}", result.detail);
+            }
+            if result.verdict == Verdict::Fail {
+                let lines: Vec<&str> = result.output.lines().collect();
+                let tail = lines.len().saturating_sub(FAILURE_TAIL_LINES);
+                for line in &lines[tail..] {
+                    let _ = writeln!(out, "         | {line}");
+                }
+            }
+        }
+        out.push('\n');
+    }
+    let tally = Tally::of(reports);
+    let counts = format!(
+        "{} passed, {} failed, {} skipped",
+        tally.passed, tally.failed, tally.skipped
+    );
+    if single {
+        let _ = writeln!(out, "{counts}");
+    } else {
+        let _ = writeln!(out, "Summary: {counts} ({} specs)", reports.len());
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+
+    fn script(dir: &Path, name: &str, body: &str) -> Result<()> {
+        let path = dir.join("tests").join(name);
+        fs::create_dir_all(dir.join("tests"))?;
+        fs::write(&path, format!("#!/bin/sh\n{body}\n"))?;
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755))?;
+        Ok(())
+    }
+
+    fn verify_only(timeout: Duration) -> CheckOpts {
+        CheckOpts {
+            verify: true,
+            judge: false,
+            timeout,
+        }
+    }
+
+    fn no_judge(
+        _: &SpecLabel,
+        _: SpawnConfig,
+    ) -> std::future::Ready<Result<AgentReply, ProtocolError>> {
+        std::future::ready(Err(ProtocolError::UnexpectedEof))
+    }
+
+    fn spec(dir: &Path, label: &str, body: &str) -> Result<()> {
+        fs::create_dir_all(dir.join("specs"))?;
+        fs::write(dir.join("specs").join(format!("{label}.md")), body)?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn verdicts_follow_exit_codes() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        script(
+            dir.path(),
+            "a.sh",
+            "case $1 in ok) echo fine;; skip) exit 77;; *) echo boom >&2; exit 3;; esac",
+        )?;
+        spec(
+            dir.path(),
+            "alpha",
+            "## Success Criteria\n\n\
+             - [ ] passes\n  [verify](../tests/a.sh#ok)\n\
+             - [ ] skips\n  [verify](../tests/a.sh#skip)\n\
+             - [ ] fails\n  [verify](../tests/a.sh#bad)\n\
+             - [ ] missing\n  [verify](../tests/gone.sh)\n\
+             - [ ] judged\n  [judge](../tests/a.sh#ok)\n\
+             - [ ] bare\n",
+        )?;
+        let label = SpecLabel::new("alpha");
+        let reports = check_specs(
+            dir.path(),
+            Some(&label),
+            verify_only(DEFAULT_CHECK_TIMEOUT),
+            no_judge,
+        )
+        .await?;
+        let verdicts: Vec<_> = reports[0]
+            .results
+            .iter()
+            .map(|r| (r.criterion.as_str(), r.verdict))
+            .collect();
+        assert_eq!(
+            verdicts,
+            [
+                ("passes", Verdict::Pass),
+                ("skips", Verdict::Skip),
+                ("fails", Verdict::Fail),
+                ("missing", Verdict::Fail),
+                ("bare (no annotation)", Verdict::Skip),
+            ]
+        );
+        assert_eq!(reports[0].results[0].output, "fine\n");
+        assert_eq!(reports[0].results[2].detail, "tests/a.sh::bad (exit 3)");
+
+        let rendered = render(&reports, verify_only(DEFAULT_CHECK_TIMEOUT), true);
+        assert!(rendered.starts_with("Loom Verify: alpha\n==================\n"));
+        assert!(rendered.contains("  [FAIL] fails\n"));
+        assert!(rendered.contains("         | boom\n"));
+        assert!(rendered.ends_with("1 passed, 2 failed, 2 skipped\n"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn slow_test_fails_on_timeout() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        script(dir.path(), "slow.sh", "sleep 5")?;
+        spec(
+            dir.path(),
+            "alpha",
+            "## Success Criteria\n\n- [ ] slow\n  [verify](../tests/slow.sh)\n",
+        )?;
+        let label = SpecLabel::new("alpha");
+        let reports = check_specs(
+            dir.path(),
+            Some(&label),
+            verify_only(Duration::from_millis(100)),
+            no_judge,
+        )
+        .await?;
+        let result = &reports[0].results[0];
+        assert_eq!(result.verdict, Verdict::Fail);
+        assert!(result.detail.contains("timed out"), "{}", result.detail);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn all_specs_skip_readme_and_specs_without_criteria() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        script(dir.path(), "ok.sh", "exit 0")?;
+        let ok = "## Success Criteria\n\n- [ ] ok\n  [verify](../tests/ok.sh)\n";
+        spec(dir.path(), "beta", ok)?;
+        spec(dir.path(), "alpha", ok)?;
+        spec(dir.path(), "README", ok)?;
+        spec(dir.path(), "notes", "# Notes\n")?;
+        let reports = check_specs(
+            dir.path(),
+            None,
+            verify_only(DEFAULT_CHECK_TIMEOUT),
+            no_judge,
+        )
+        .await?;
+        let labels: Vec<_> = reports.iter().map(|r| r.label.as_str()).collect();
+        assert_eq!(labels, ["alpha", "beta"]);
+        assert!(
+            render(&reports, verify_only(DEFAULT_CHECK_TIMEOUT), false)
+                .ends_with("Summary: 2 passed, 0 failed, 0 skipped (2 specs)\n")
+        );
+
+        let notes = SpecLabel::new("notes");
+        assert!(matches!(
+            check_specs(
+                dir.path(),
+                Some(&notes),
+                verify_only(DEFAULT_CHECK_TIMEOUT),
+                no_judge
+            )
+            .await,
+            Err(SpecError::NoSuccessCriteria { .. })
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn all_mode_runs_verify_and_judge() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        script(dir.path(), "ok.sh", "exit 0")?;
+        fs::create_dir_all(dir.path().join("tests/judges"))?;
+        fs::write(dir.path().join("src.rs"), "fn main() {}\n")?;
+        fs::write(
+            dir.path().join("tests/judges/alpha.sh"),
+            "test_a() {\n  judge_files src.rs\n  judge_criterion \"has a main\"\n}\n",
+        )?;
+        spec(
+            dir.path(),
+            "alpha",
+            "## Success Criteria\n\n\
+             - [ ] runs\n  [verify](../tests/ok.sh)\n\
+             - [ ] reads well\n  [judge](../tests/judges/alpha.sh#test_a)\n",
+        )?;
+        let mut prompts = Vec::new();
+        let opts = CheckOpts {
+            verify: true,
+            judge: true,
+            timeout: DEFAULT_CHECK_TIMEOUT,
+        };
+        let label = SpecLabel::new("alpha");
+        let reports = check_specs(
+            dir.path(),
+            Some(&label),
+            opts,
+            |_: &SpecLabel, spawn: SpawnConfig| {
+                prompts.push(spawn.initial_prompt);
+                async {
+                    Ok(AgentReply {
+                        outcome: loom_core::agent::SessionOutcome {
+                            exit_code: 0,
+                            cost_usd: None,
+                        },
+                        text: "PASS\nThere is a main.".into(),
+                    })
+                }
+            },
+        )
+        .await?;
+        let results: Vec<_> = reports[0]
+            .results
+            .iter()
+            .map(|r| (r.verdict, r.detail.as_str()))
+            .collect();
+        assert_eq!(
+            results,
+            [
+                (Verdict::Pass, "tests/ok.sh (exit 0)"),
+                (Verdict::Pass, "\"There is a main.\""),
+            ]
+        );
+        assert_eq!(prompts.len(), 1);
+        assert!(prompts[0].contains("has a main"));
+        assert!(render(&reports, opts, true).starts_with("Loom Verify+Judge: alpha\n"));
+        Ok(())
+    }
+}
@@ -191,6 +191,7 @@ mod tests {
             file: Some(PathBuf::from("tests/missing.sh")),
             function: None,
             checked: false,
+            line: 1,
         }];
         let pkgs = collect_deps(dir.path(), &anns)?;
         assert!(pkgs.is_empty());
@@ -211,6 +212,7 @@ mod tests {
                 file: Some(PathBuf::from("tests/a.sh")),
                 function: None,
                 checked: false,
+                line: 1,
             },
             Annotation {
                 criterion: "ignored".into(),
@@ -218,6 +220,7 @@ mod tests {
                 file: None,
                 function: None,
                 checked: false,
+                line: 2,
             },
         ];
         let pkgs = collect_deps(dir.path(), &anns)?;
@@ -0,0 +1,279 @@
+//! `loom spec --judge` — put `[judge]` criteria to an agent.
+//!
+//! Port of `run_judge_test` / `run_judge` in `lib/ralph/cmd/`: the rubric
+//! file is sourced by `bash` with `judge_files` / `judge_criterion` shims
+//! and its function called, the named files are inlined into
+//! `judge.md`, and the reply's first `PASS`/`FAIL` line is the verdict with
+//! the lines after it as reasoning. Anything that stops a verdict from being
+//! reached — a missing rubric or source file, an empty rubric, a failed
+//! session, an unparseable reply — fails the criterion with the reason.
+
+use std::path::Path;
+use std::process::Stdio;
+use std::time::Duration;
+
+use askama::Template;
+use loom_core::agent::{ProtocolError, RePinContent, SpawnConfig, ToolPolicy};
+use loom_templates::judge::{JudgeContext, JudgeFile};
+use tokio::process::Command;
+
+use super::annotations::Annotation;
+use super::checks::{CheckResult, Verdict};
+use crate::agent::AgentReply;
+
+/// Container the judge session runs in.
+const JUDGE_IMAGE: &str = "wrapix-base:latest";
+
+/// Reasoning lines kept from a reply, as `run_judge` does.
+const MAX_REASONING_LINES: usize = 5;
+
+/// Reply bytes quoted back when the verdict cannot be parsed.
+const MAX_QUOTED_REPLY: usize = 200;
+
+/// Sources the rubric (`$1`) with recording shims, calls its function
+/// (`$2`, when given), then prints the files on the first line and the
+/// criterion after it.
+const RUBRIC_SHIM: &str = r#"judge_files() { JUDGE_FILES="$*"; }
+judge_criterion() { JUDGE_CRITERION="$1"; }
+source "$1" || exit
+if [ -n "$2" ]; then "$2" || exit; fi
+printf '%s\n%s' "$JUDGE_FILES" "$JUDGE_CRITERION""#;
+
+/// What a rubric function asks the judge to look at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rubric {
+    pub files: Vec<String>,
+    pub criterion: String,
+}
+
+/// Judge one `[judge]` criterion: read the rubric, build the prompt, ask
+/// through `ask` with `timeout` for the whole session, and turn the reply
+/// into a [`CheckResult`].
+pub(super) async fn judge<A, F>(
+    workspace: &Path,
+    annotation: &Annotation,
+    file: &Path,
+    timeout: Duration,
+    ask: &mut A,
+) -> CheckResult
+where
+    A: FnMut(SpawnConfig) -> F,
+    F: Future<Output = Result<AgentReply, ProtocolError>>,
+{
+    let fail = |detail| CheckResult::of(annotation, Verdict::Fail, detail, String::new());
+    let shown = file.strip_prefix(workspace).unwrap_or(file).display();
+    let function = annotation.function.as_deref();
+    let prompt = match read_rubric(workspace, file, function).await {
+        Ok(rubric) => match build_prompt(workspace, &rubric) {
+            Ok(prompt) => prompt,
+            Err(reason) => return fail(reason),
+        },
+        Err(reason) => return fail(format!("{shown}: {reason}")),
+    };
+    let spawn = SpawnConfig {
+        image: JUDGE_IMAGE.to_string(),
+        workspace: workspace.to_path_buf(),
+        env: vec![],
+        initial_prompt: prompt,
+        agent_args: vec![],
+        repin: RePinContent {
+            orientation: String::new(),
+            pinned_context: String::new(),
+            partial_bodies: vec![],
+        },
+        model: None,
+        thinking: None,
+        command_protocol: None,
+        tool_policy: ToolPolicy::default(),
+    };
+    let reply = match tokio::time::timeout(timeout, ask(spawn)).await {
+        Ok(Ok(reply)) if reply.outcome.exit_code == 0 => reply,
+        Ok(Ok(reply)) => {
+            return fail(format!("judge session exited {}", reply.outcome.exit_code));
+        }
+        Ok(Err(e)) => return fail(format!("judge session failed: {e}")),
+        Err(_) => return fail(format!("judge session timed out after {timeout:?}")),
+    };
+    match parse_verdict(&reply.text) {
+        Some((verdict, reasoning)) => CheckResult::of(
+            annotation,
+            verdict,
+            format!("\"{reasoning}\""),
+            String::new(),
+        ),
+        None => {
+            let quoted: String = reply.text.chars().take(MAX_QUOTED_REPLY).collect();
+            fail(format!("could not parse verdict from reply: {quoted}"))
+        }
+    }
+}
+
+/// Source `file` under `bash` and call `function` to capture its rubric.
+pub async fn read_rubric(
+    workspace: &Path,
+    file: &Path,
+    function: Option<&str>,
+) -> Result<Rubric, String> {
+    let path = workspace.join(file);
+    if !path.is_file() {
+        return Err("not found".to_string());
+    }
+    let output = Command::new("bash")
+        .arg("-c")
+        .arg(RUBRIC_SHIM)
+        .arg("loom-judge")
+        .arg(&path)
+        .arg(function.unwrap_or_default())
+        .current_dir(workspace)
+        .stdin(Stdio::null())
+        .output()
+        .await
+        .map_err(|e| format!("bash: {e}"))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!(
+            "rubric failed ({}): {}",
+            output.status,
+            stderr.trim()
+        ));
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let (files, criterion) = stdout.split_once('\n').unwrap_or((&stdout, ""));
+    Ok(Rubric {
+        files: files.split_whitespace().map(str::to_string).collect(),
+        criterion: criterion.trim().to_string(),
+    })
+}
+
+/// Render `judge.md` over `rubric`, reading each named file from
+/// `workspace`.
+pub fn build_prompt(workspace: &Path, rubric: &Rubric) -> Result<String, String> {
+    if rubric.files.is_empty() {
+        return Err("no source files specified (call judge_files)".to_string());
+    }
+    if rubric.criterion.is_empty() {
+        return Err("no criterion specified (call judge_criterion)".to_string());
+    }
+    let files = rubric
+        .files
+        .iter()
+        .map(|path| {
+            std::fs::read_to_string(workspace.join(path))
+                .map(|body| JudgeFile {
+                    path: path.clone(),
+                    body,
+                })
+                .map_err(|e| format!("source file {path}: {e}"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    JudgeContext {
+        criterion: rubric.criterion.clone(),
+        files,
+    }
+    .render()
+    .map_err(|e| format!("rendering judge.md: {e}"))
+}
+
+/// The verdict from the first line starting with `PASS` or `FAIL`, and up
+/// to [`MAX_REASONING_LINES`] non-blank lines after it joined with spaces
+/// (`(no reasoning provided)` when there are none).
+pub fn parse_verdict(reply: &str) -> Option<(Verdict, String)> {
+    let mut lines = reply.lines();
+    let verdict = lines.by_ref().find_map(|line| {
+        if line.starts_with("PASS") {
+            Some(Verdict::Pass)
+        } else if line.starts_with("FAIL") {
+            Some(Verdict::Fail)
+        } else {
+            None
+        }
+    })?;
+    let reasoning: Vec<&str> = lines
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .take(MAX_REASONING_LINES)
+        .collect();
+    let reasoning = if reasoning.is_empty() {
+        "(no reasoning provided)".to_string()
+    } else {
+        reasoning.join(" ")
+    };
+    Some((verdict, reasoning))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use std::fs;
+
+    #[test]
+    fn parse_verdict_takes_first_verdict_line_and_reasoning() {
+        let reply =
+            "Looking at the files.\nPASS — looks right\n\nIt prints a summary.\nAnd exits 1.\n";
+        assert_eq!(
+            parse_verdict(reply),
+            Some((
+                Verdict::Pass,
+                "It prints a summary. And exits 1.".to_string()
+            ))
+        );
+        assert_eq!(
+            parse_verdict("FAIL"),
+            Some((Verdict::Fail, "(no reasoning provided)".to_string()))
+        );
+        assert_eq!(parse_verdict("maybe?"), None);
+    }
+
+    #[tokio::test]
+    async fn read_rubric_sources_file_and_calls_function() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        fs::create_dir_all(dir.path().join("tests/judges"))?;
+        fs::write(
+            dir.path().join("tests/judges/alpha.sh"),
+            "test_a() {\n  judge_files \"src/a.rs\" \"src/b.rs\"\n  judge_criterion \"A does B\"\n}\n\
+             test_b() {\n  judge_files \"src/c.rs\"\n  judge_criterion \"C\"\n}\n",
+        )?;
+        let rubric = read_rubric(
+            dir.path(),
+            Path::new("tests/judges/alpha.sh"),
+            Some("test_a"),
+        )
+        .await
+        .map_err(anyhow::Error::msg)?;
+        assert_eq!(
+            rubric,
+            Rubric {
+                files: vec!["src/a.rs".into(), "src/b.rs".into()],
+                criterion: "A does B".into(),
+            }
+        );
+        assert!(
+            read_rubric(dir.path(), Path::new("tests/judges/gone.sh"), None)
+                .await
+                .is_err()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn build_prompt_inlines_files_and_rejects_missing_ones() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        fs::write(dir.path().join("a.rs"), "fn a() {}\n")?;
+        let rubric = Rubric {
+            files: vec!["a.rs".into()],
+            criterion: "A exists".into(),
+        };
+        let prompt = build_prompt(dir.path(), &rubric).map_err(anyhow::Error::msg)?;
+        assert!(prompt.contains("--- a.rs ---\nfn a() {}\n"));
+        assert!(prompt.contains("## Criterion\n\nA exists\n"));
+
+        let missing = Rubric {
+            files: vec!["gone.rs".into()],
+            ..rubric
+        };
+        let err = build_prompt(dir.path(), &missing).err().unwrap_or_default();
+        assert!(err.starts_with("source file gone.rs"), "{err}");
+        Ok(())
+    }
+}
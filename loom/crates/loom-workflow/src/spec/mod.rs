@@ -3,20 +3,33 @@
 //! The default mode prints `[verify]`/`[judge]` annotations parsed from the
 //! active spec's `## Success Criteria` section. The `--deps` mode walks each
 //! annotated test file and emits the set of nixpkgs names referenced by the
-//! tools they invoke (a port of `ralph sync --deps`).
+//! tools they invoke (a port of `ralph sync --deps`). The `--verify` and
+//! `--judge` modes run those `[verify]` tests on the host and put the
+//! `[judge]` rubrics to an agent, reporting PASS/FAIL/SKIP per criterion (a
+//! port of `ralph spec --verify` / `--judge`); `--verify --update` then
+//! ticks the checkboxes of passing criteria and unticks failing ones.
 //!
-//! Read-only — no lock acquired (per the lock matrix in
-//! `specs/loom-harness.md`).
+//! No lock acquired (per the lock matrix in `specs/loom-harness.md`): every
+//! mode only reads, except `--update`, which rewrites the checkboxes of the
+//! spec files it checked and nothing in `.wrapix/loom/`.
 
 mod annotations;
+mod checks;
 mod deps;
 mod error;
+mod judge;
+mod update;
 
 use std::path::Path;
 
 pub use annotations::{Annotation, AnnotationKind, parse_spec_annotations};
+pub use checks::{
+    CheckOpts, CheckResult, DEFAULT_CHECK_TIMEOUT, SpecReport, Tally, Verdict, check_specs, render,
+};
 pub use deps::{collect_deps, scan_file_body};
 pub use error::SpecError;
+pub use judge::{Rubric, build_prompt, parse_verdict, read_rubric};
+pub use update::{Tick, render_ticks, tick_criteria};
 
 use loom_core::identifier::SpecLabel;
 
@@ -0,0 +1,169 @@
+//! `loom spec --verify --update` — sync checkboxes with verify results.
+//!
+//! Rewrites only the `[ ]` / `[x]` mark on the lines of criteria whose
+//! `[verify]` test passed (checked) or failed (unchecked). Every other byte
+//! of the spec — line endings, fenced blocks, judge and skipped criteria —
+//! is left alone, so the change reads as a small diff in review.
+
+use std::fmt::Write as _;
+use std::path::Path;
+
+use super::annotations::{AnnotationKind, parse_checkbox};
+use super::checks::{CheckResult, Verdict};
+use super::error::SpecError;
+
+/// One checkbox line flipped by [`tick_criteria`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tick {
+    /// 1-based line in the spec.
+    pub line: usize,
+    pub before: String,
+    pub after: String,
+}
+
+/// Check passing and uncheck failing verify criteria of `results` in the
+/// spec at `spec_path`, writing the file back only when a mark changed.
+/// A line that no longer holds the same criterion (the spec was edited
+/// while the tests ran) is left untouched.
+pub fn tick_criteria(spec_path: &Path, results: &[CheckResult]) -> Result<Vec<Tick>, SpecError> {
+    let io = |source| SpecError::Io {
+        path: spec_path.to_path_buf(),
+        source,
+    };
+    let body = std::fs::read_to_string(spec_path).map_err(io)?;
+    let mut lines: Vec<String> = body.split_inclusive('\n').map(str::to_string).collect();
+    let mut ticks = Vec::new();
+    for result in results {
+        let checked = match (result.kind, result.verdict) {
+            (AnnotationKind::Verify, Verdict::Pass) => true,
+            (AnnotationKind::Verify, Verdict::Fail) => false,
+            _ => continue,
+        };
+        let Some(line) = result.line.checked_sub(1).and_then(|i| lines.get_mut(i)) else {
+            continue;
+        };
+        let Some((was, text)) = parse_checkbox(line.trim_end_matches(['\n', '\r'])) else {
+            continue;
+        };
+        if was == checked || text != result.criterion {
+            continue;
+        }
+        let Some(open) = line.find("- [") else {
+            continue;
+        };
+        let mark = open + "- [".len();
+        let before = line.trim_end_matches(['\n', '\r']).to_string();
+        line.replace_range(mark..mark + 1, if checked { "x" } else { " " });
+        ticks.push(Tick {
+            line: result.line,
+            before,
+            after: line.trim_end_matches(['\n', '\r']).to_string(),
+        });
+    }
+    if !ticks.is_empty() {
+        std::fs::write(spec_path, lines.concat()).map_err(io)?;
+    }
+    Ok(ticks)
+}
+
+/// `ticks` as a unified diff of `path` with one hunk per flipped line.
+pub fn render_ticks(path: &str, ticks: &[Tick]) -> String {
+    let mut out = String::new();
+    if ticks.is_empty() {
+        return out;
+    }
+    let _ = writeln!(out, "--- a/{path}\n+++ b/{path}");
+    for tick in ticks {
+        let _ = writeln!(
+            out,
+            "@@ -{0} +{0} @@\n-{1}\n+{2}",
+            tick.line, tick.before, tick.after
+        );
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spec::parse_spec_annotations;
+    use anyhow::Result;
+    use std::fs;
+
+    fn result(annotation: &crate::spec::Annotation, verdict: Verdict) -> CheckResult {
+        CheckResult::of(annotation, verdict, String::new(), String::new())
+    }
+
+    #[test]
+    fn ticks_passing_and_unticks_failing_verify_criteria_only() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("alpha.md");
+        let body = "# Alpha\r\n\
+                    \r\n\
+                    ```\r\n\
+                    - [ ] not a criterion\r\n\
+                    ```\r\n\
+                    ## Success Criteria\r\n\
+                    \r\n\
+                    - [ ] now passes\r\n  [verify](tests/a.sh#ok)\r\n\
+                    - [x] now fails\r\n  [verify](tests/a.sh#bad)\r\n\
+                    - [x] still passes\r\n  [verify](tests/a.sh#ok)\r\n\
+                    - [ ] judged\r\n  [judge](tests/j.sh#x)\r\n";
+        fs::write(&path, body)?;
+        let rows = parse_spec_annotations(&path)?;
+        let results = [
+            result(&rows[0], Verdict::Pass),
+            result(&rows[1], Verdict::Fail),
+            result(&rows[2], Verdict::Pass),
+            result(&rows[3], Verdict::Pass),
+        ];
+
+        let ticks = tick_criteria(&path, &results)?;
+        assert_eq!(
+            ticks,
+            [
+                Tick {
+                    line: 8,
+                    before: "- [ ] now passes".into(),
+                    after: "- [x] now passes".into(),
+                },
+                Tick {
+                    line: 10,
+                    before: "- [x] now fails".into(),
+                    after: "- [ ] now fails".into(),
+                },
+            ]
+        );
+        let expected = body
+            .replace("- [ ] now passes", "- [x] now passes")
+            .replace("- [x] now fails", "- [ ] now fails");
+        assert_eq!(fs::read_to_string(&path)?, expected);
+        assert_eq!(
+            render_ticks("specs/alpha.md", &ticks),
+            "--- a/specs/alpha.md\n+++ b/specs/alpha.md\n\
+             @@ -8 +8 @@\n-- [ ] now passes\n+- [x] now passes\n\
+             @@ -10 +10 @@\n-- [x] now fails\n+- [ ] now fails\n"
+        );
+
+        // A second pass has nothing left to flip and leaves the file alone.
+        assert!(tick_criteria(&path, &results)?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn edited_lines_are_left_alone() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("alpha.md");
+        fs::write(
+            &path,
+            "## Success Criteria\n\n- [ ] a\n  [verify](tests/a.sh)\n",
+        )?;
+        let rows = parse_spec_annotations(&path)?;
+        fs::write(
+            &path,
+            "## Success Criteria\n\n- [ ] renamed\n  [verify](tests/a.sh)\n",
+        )?;
+        assert!(tick_criteria(&path, &[result(&rows[0], Verdict::Pass)])?.is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,23 @@
+use displaydoc::Display;
+use thiserror::Error;
+
+use loom_core::lock::LockError;
+use loom_core::state::StateError;
+
+use crate::logs_cmd::LogsError;
+
+/// Failures raised by [`load`](super::load) and [`load_live`](super::load_live).
+#[derive(Debug, Display, Error)]
+pub enum StatusError {
+    /// state-db read failed
+    State(#[from] StateError),
+
+    /// probing spec locks failed
+    Lock(#[from] LockError),
+
+    /// reading the last run log failed
+    Logs(#[from] LogsError),
+
+    /// io failure while tailing logs or drawing the dashboard
+    Io(#[from] std::io::Error),
+}
@@ -0,0 +1,40 @@
+//! `loom status` — read-only snapshot of the active spec and its molecule.
+//!
+//! No locks are acquired (per the `Concurrency & Locking` lock matrix in
+//! `specs/loom-harness.md`). [`load`] opens the state DB read-only and
+//! fetches `current_spec` plus that spec's active molecule. [`load_live`]
+//! layers the live view on top, matching the `ralph status` presentation in
+//! `specs/live-specs.md`:
+//!
+//! - molecule progress from `bd mol progress` (completed / in-progress /
+//!   total, current step);
+//! - the spec's open beads with their state and `profile:<X>` label;
+//! - outstanding `loom:clarify` beads as `[awaiting]` rows with their
+//!   question and age;
+//! - specs with a held per-spec lock (a `loom run` / `loom check` in
+//!   flight), probed without taking a lock of our own;
+//! - the last recorded attempt's outcome, read back from its NDJSON log.
+//!
+//! A `bd` failure does not fail the command — the DB half of the report is
+//! still useful — and is surfaced in the rendered output instead.
+//!
+//! [`render`] formats the status to a `String` so the binary can route it to
+//! stdout or the test harness can assert on the body verbatim.
+//!
+//! [`watch`] is the `--watch` dashboard: it redraws the report on an
+//! interval and tails the active per-bead NDJSON logs below it, one pane per
+//! running slot, through the same [`TerminalRenderer`] formatting `loom run`
+//! prints live. It needs no tmux — panes are laid out side by side in a
+//! plain ANSI-cleared frame ([`compose_frame`]).
+//!
+//! [`TerminalRenderer`]: loom_core::logging::TerminalRenderer
+
+mod error;
+mod render;
+mod report;
+mod watch;
+
+pub use error::StatusError;
+pub use render::{format_age, render};
+pub use report::{AwaitingRow, BeadRow, BeadState, LastRun, StatusReport, load, load_live};
+pub use watch::{PaneTail, WatchOpts, active_logs, compose_frame, watch};
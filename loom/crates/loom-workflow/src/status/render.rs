@@ -0,0 +1,239 @@
+use std::time::Duration;
+
+use super::report::StatusReport;
+
+/// Column where the question line under an `[awaiting]` row starts; lines
+/// it up under the bead title as in `specs/live-specs.md`.
+const AWAITING_INDENT: usize = 13;
+
+/// Render [`StatusReport`] as a multi-line, human-friendly string. Layout is
+/// stable so tests can assert against the exact body.
+///
+/// The first three lines (spec, molecule, iteration) are always present;
+/// every live section is omitted when [`load_live`](super::load_live) left
+/// it empty.
+pub fn render(report: &StatusReport) -> String {
+    let mut out = String::new();
+    match &report.current_spec {
+        Some(label) => out.push_str(&format!("active spec: {label}\n")),
+        None => out.push_str("active spec: <unset> (run `loom use <label>`)\n"),
+    }
+    match &report.molecule {
+        Some(mol) => {
+            out.push_str(&format!("molecule: {}\n", mol.id));
+            out.push_str(&format!("iteration: {}\n", mol.iteration_count));
+        }
+        None => {
+            out.push_str("molecule: <none>\n");
+            out.push_str("iteration: 0\n");
+        }
+    }
+
+    if let Some(p) = &report.progress {
+        out.push_str(&format!(
+            "progress: {}/{} done, {} in progress ({:.0}%)\n",
+            p.completed, p.total, p.in_progress, p.percent,
+        ));
+        if let Some(step) = &p.current_step_id {
+            let title = report
+                .beads
+                .iter()
+                .find(|b| b.id.as_str() == step)
+                .map(|b| format!("  {}", b.title))
+                .unwrap_or_default();
+            out.push_str(&format!("current step: {step}{title}\n"));
+        }
+    }
+    if !report.running.is_empty() {
+        let labels: Vec<&str> = report.running.iter().map(|l| l.as_str()).collect();
+        out.push_str(&format!(
+            "running: {} (spec lock held)\n",
+            labels.join(", ")
+        ));
+    }
+    if let Some(last) = &report.last_run {
+        let age = last
+            .age
+            .map(|a| format!(" ({})", format_age(a)))
+            .unwrap_or_default();
+        out.push_str(&format!(
+            "last run: {} {}{age}\n",
+            last.bead,
+            last.outcome.as_str()
+        ));
+    }
+    if let Some(err) = &report.bd_error {
+        out.push_str(&format!("bd: unavailable ({err})\n"));
+    }
+
+    if !report.beads.is_empty() {
+        out.push_str("\nbeads:\n");
+        let width = report
+            .beads
+            .iter()
+            .map(|b| b.id.as_str().len())
+            .max()
+            .unwrap_or(0);
+        for bead in &report.beads {
+            let profile = bead
+                .profile
+                .as_ref()
+                .map(|p| format!("    [profile:{p}]"))
+                .unwrap_or_default();
+            out.push_str(&format!(
+                "  {tag:<10} {id:<width$}  {title}{profile}\n",
+                tag = bead.state.tag(),
+                id = bead.id.as_str(),
+                title = bead.title,
+            ));
+        }
+    }
+
+    if !report.awaiting.is_empty() {
+        out.push_str("\nawaiting input:\n");
+        for row in &report.awaiting {
+            let spec = match &row.spec {
+                Some(s) if report.current_spec.as_deref() != Some(s.as_str()) => {
+                    format!("    [spec:{s}]")
+                }
+                _ => String::new(),
+            };
+            out.push_str(&format!("  [awaiting] {}  {}{spec}\n", row.id, row.title));
+            let age = row.age.map(format_age);
+            let detail = match (&row.question, age) {
+                (Some(q), Some(a)) => Some(format!("\"{q}\" ({a})")),
+                (Some(q), None) => Some(format!("\"{q}\"")),
+                (None, Some(a)) => Some(format!("({a})")),
+                (None, None) => None,
+            };
+            if let Some(detail) = detail {
+                out.push_str(&format!("{:AWAITING_INDENT$}{detail}\n", ""));
+            }
+        }
+    }
+    out
+}
+
+/// Coarse relative age: `just now`, `5m ago`, `2h ago`, `3d ago`.
+pub fn format_age(age: Duration) -> String {
+    let secs = age.as_secs();
+    match secs {
+        0..60 => "just now".to_string(),
+        60..3_600 => format!("{}m ago", secs / 60),
+        3_600..86_400 => format!("{}h ago", secs / 3_600),
+        _ => format!("{}d ago", secs / 86_400),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logs_cmd::AttemptOutcome;
+    use crate::status::{AwaitingRow, BeadRow, BeadState, LastRun};
+    use anyhow::Result;
+    use loom_core::bd::MolProgress;
+    use loom_core::identifier::{BeadId, MoleculeId, ProfileName, SpecLabel};
+    use loom_core::state::MoleculeRow;
+
+    #[test]
+    fn format_age_buckets() {
+        assert_eq!(format_age(Duration::from_secs(5)), "just now");
+        assert_eq!(format_age(Duration::from_secs(300)), "5m ago");
+        assert_eq!(format_age(Duration::from_secs(7_200)), "2h ago");
+        assert_eq!(format_age(Duration::from_secs(3 * 86_400 + 5)), "3d ago");
+    }
+
+    #[test]
+    fn live_report_renders_awaiting_presentation() -> Result<()> {
+        let report = StatusReport {
+            current_spec: Some("loom-harness".to_string()),
+            molecule: Some(MoleculeRow {
+                id: MoleculeId::new("wx-3hhwq"),
+                spec_label: SpecLabel::new("loom-harness"),
+                base_commit: None,
+                iteration_count: 2,
+            }),
+            progress: Some(MolProgress {
+                molecule_id: MoleculeId::new("wx-3hhwq"),
+                molecule_title: "Epic".to_string(),
+                completed: 3,
+                in_progress: 1,
+                total: 8,
+                percent: 37.5,
+                current_step_id: Some("wx-3hhwq.4".to_string()),
+            }),
+            beads: vec![
+                BeadRow {
+                    id: BeadId::new("wx-3hhwq.4")?,
+                    title: "Parser".to_string(),
+                    state: BeadState::Running,
+                    profile: Some(ProfileName::new("rust")),
+                },
+                BeadRow {
+                    id: BeadId::new("wx-3hhwq.10")?,
+                    title: "Cross-platform CI".to_string(),
+                    state: BeadState::Awaiting,
+                    profile: None,
+                },
+            ],
+            awaiting: vec![
+                AwaitingRow {
+                    id: BeadId::new("wx-3hhwq.10")?,
+                    spec: Some(SpecLabel::new("loom-harness")),
+                    title: "Cross-platform CI".to_string(),
+                    question: Some("Should CI use GitHub Actions or Buildkite?".to_string()),
+                    age: Some(Duration::from_secs(7_200)),
+                },
+                AwaitingRow {
+                    id: BeadId::new("wx-9a")?,
+                    spec: Some(SpecLabel::new("profiles")),
+                    title: "Pick a base image".to_string(),
+                    question: None,
+                    age: None,
+                },
+            ],
+            running: vec![SpecLabel::new("loom-harness")],
+            last_run: Some(LastRun {
+                bead: BeadId::new("wx-3hhwq.3")?,
+                outcome: AttemptOutcome::Failed,
+                age: Some(Duration::from_secs(600)),
+            }),
+            bd_error: None,
+        };
+
+        let body = render(&report);
+        let expected = "\
+active spec: loom-harness
+molecule: wx-3hhwq
+iteration: 2
+progress: 3/8 done, 1 in progress (38%)
+current step: wx-3hhwq.4  Parser
+running: loom-harness (spec lock held)
+last run: wx-3hhwq.3 failed (10m ago)
+
+beads:
+  [running]  wx-3hhwq.4   Parser    [profile:rust]
+  [awaiting] wx-3hhwq.10  Cross-platform CI
+
+awaiting input:
+  [awaiting] wx-3hhwq.10  Cross-platform CI
+             \"Should CI use GitHub Actions or Buildkite?\" (2h ago)
+  [awaiting] wx-9a  Pick a base image    [spec:profiles]
+";
+        assert_eq!(body, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn bd_error_is_surfaced() {
+        let report = StatusReport {
+            bd_error: Some("failed to spawn `bd`".to_string()),
+            ..StatusReport::default()
+        };
+        let body = render(&report);
+        assert!(
+            body.contains("bd: unavailable (failed to spawn `bd`)"),
+            "{body}"
+        );
+    }
+}
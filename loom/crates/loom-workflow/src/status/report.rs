@@ -0,0 +1,390 @@
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+use loom_core::bd::{BdClient, BdError, Bead, CommandRunner, Label, ListOpts, MolProgress};
+use loom_core::identifier::{BeadId, ProfileName, SpecLabel};
+use loom_core::lock::LockManager;
+use loom_core::logging::parse_rfc3339;
+use loom_core::state::{MoleculeRow, StateDb};
+use tracing::warn;
+
+use super::error::StatusError;
+use crate::logs_cmd::{AttemptOutcome, latest_attempt};
+use crate::msg::{filter_clarifies, parse_options, spec_label_of};
+
+/// Snapshot returned by [`load`] / [`load_live`]. `None` for `current_spec`
+/// means the user has not yet run `loom use <label>`. `molecule` is `None`
+/// when the active spec has no live molecule.
+///
+/// The remaining fields are only populated by [`load_live`]; [`load`]
+/// leaves them empty so the DB-only view renders exactly as before.
+#[derive(Debug, Clone, Default)]
+pub struct StatusReport {
+    pub current_spec: Option<String>,
+    pub molecule: Option<MoleculeRow>,
+    pub progress: Option<MolProgress>,
+    /// Open beads of the active spec, in `bd list` order.
+    pub beads: Vec<BeadRow>,
+    /// Outstanding `loom:clarify` beads across every spec.
+    pub awaiting: Vec<AwaitingRow>,
+    /// Specs whose per-spec lock is currently held — by another process,
+    /// since `loom status` takes no lock of its own.
+    pub running: Vec<SpecLabel>,
+    pub last_run: Option<LastRun>,
+    /// Rendered `bd` failure when the live half could not be fetched.
+    pub bd_error: Option<String>,
+}
+
+/// One open bead of the active spec.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BeadRow {
+    pub id: BeadId,
+    pub title: String,
+    pub state: BeadState,
+    pub profile: Option<ProfileName>,
+}
+
+/// Per-bead indicator shown in the `beads:` section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BeadState {
+    Open,
+    Running,
+    Blocked,
+    /// Carries `loom:clarify` — waiting on a human, not truly ready.
+    Awaiting,
+}
+
+impl BeadState {
+    /// `loom:clarify` wins over the `bd` status: a clarified bead is not
+    /// runnable regardless of whether it was claimed first.
+    fn of(bead: &Bead) -> Self {
+        if bead.labels.iter().any(Label::is_clarify) {
+            return Self::Awaiting;
+        }
+        match bead.status.as_str() {
+            "in_progress" => Self::Running,
+            "blocked" => Self::Blocked,
+            _ => Self::Open,
+        }
+    }
+
+    pub fn tag(self) -> &'static str {
+        match self {
+            Self::Open => "[open]",
+            Self::Running => "[running]",
+            Self::Blocked => "[blocked]",
+            Self::Awaiting => "[awaiting]",
+        }
+    }
+}
+
+/// One outstanding clarify, presented as an `[awaiting]` row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AwaitingRow {
+    pub id: BeadId,
+    pub spec: Option<SpecLabel>,
+    pub title: String,
+    /// `## Options — <summary>` line when present and distinct from the
+    /// title; `None` otherwise.
+    pub question: Option<String>,
+    /// Time since the bead was last updated (when the clarify label landed),
+    /// falling back to its creation time. `None` when `bd` supplied neither
+    /// timestamp or they failed to parse.
+    pub age: Option<Duration>,
+}
+
+/// Outcome of the most recent recorded attempt for the active spec.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LastRun {
+    pub bead: BeadId,
+    pub outcome: AttemptOutcome,
+    pub age: Option<Duration>,
+}
+
+/// Read [`current_spec`](StateDb::current_spec) and — if present — the active
+/// molecule for that spec from `db`. Read-only.
+pub fn load(db: &StateDb) -> Result<StatusReport, StatusError> {
+    let current = db.current_spec()?;
+    let molecule = match &current {
+        Some(label) => db.active_molecule(label)?,
+        None => None,
+    };
+    Ok(StatusReport {
+        current_spec: current.map(|s| s.to_string()),
+        molecule,
+        ..StatusReport::default()
+    })
+}
+
+/// [`load`] plus the live view: molecule progress and bead states from
+/// `bd`, held spec locks from `locks`, and the last attempt under
+/// `logs_root`. Ages are measured against `now`.
+///
+/// `bd` failures are recorded in [`StatusReport::bd_error`] rather than
+/// returned so the rest of the report still renders.
+pub async fn load_live<R: CommandRunner>(
+    db: &StateDb,
+    bd: &BdClient<R>,
+    locks: &LockManager,
+    logs_root: &Path,
+    now: SystemTime,
+) -> Result<StatusReport, StatusError> {
+    let mut report = load(db)?;
+    let label = report.current_spec.as_deref().map(SpecLabel::new);
+
+    report.running = locks.held_spec_locks()?;
+    if let Some(label) = &label {
+        report.last_run = latest_attempt(logs_root, label)?.map(|a| LastRun {
+            bead: a.bead,
+            outcome: a.outcome,
+            age: now.duration_since(a.started).ok(),
+        });
+    }
+
+    if let Err(e) = fetch_bd(&mut report, bd, label.as_ref(), now).await {
+        warn!(error = %e, "loom status: bd unavailable; showing state-db view only");
+        report.bd_error = Some(e.to_string());
+    }
+    Ok(report)
+}
+
+async fn fetch_bd<R: CommandRunner>(
+    report: &mut StatusReport,
+    bd: &BdClient<R>,
+    label: Option<&SpecLabel>,
+    now: SystemTime,
+) -> Result<(), BdError> {
+    if let Some(label) = label {
+        let beads = bd
+            .list(ListOpts {
+                status: None,
+                label: Some(format!("spec:{label}")),
+            })
+            .await?;
+        let molecule_id = report.molecule.as_ref().map(|m| m.id.as_str());
+        report.beads = beads
+            .iter()
+            .filter(|b| b.status != "closed" && b.issue_type != "epic")
+            .filter(|b| Some(b.id.as_str()) != molecule_id)
+            .map(|b| BeadRow {
+                id: b.id.clone(),
+                title: b.title.clone(),
+                state: BeadState::of(b),
+                profile: b.labels.iter().find_map(Label::profile_name),
+            })
+            .collect();
+    }
+    if let Some(mol) = &report.molecule {
+        report.progress = Some(bd.mol_progress(&mol.id).await?);
+    }
+
+    let clarifies = bd
+        .list(ListOpts {
+            status: None,
+            label: Some("loom:clarify".to_string()),
+        })
+        .await?;
+    report.awaiting = filter_clarifies(&clarifies, None)
+        .into_iter()
+        .map(|b| awaiting_row(b, now))
+        .collect();
+    Ok(())
+}
+
+fn awaiting_row(bead: &Bead, now: SystemTime) -> AwaitingRow {
+    let summary = parse_options(&bead.description).summary;
+    let question = (!summary.is_empty() && summary != bead.title).then_some(summary);
+    let since = bead
+        .updated_at
+        .as_deref()
+        .or(bead.created_at.as_deref())
+        .and_then(parse_rfc3339);
+    AwaitingRow {
+        id: bead.id.clone(),
+        spec: spec_label_of(bead),
+        title: bead.title.clone(),
+        question,
+        age: since.and_then(|t| now.duration_since(t).ok()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::status::render;
+    use anyhow::Result;
+    use loom_core::bd::ScriptedRunner;
+    use loom_core::identifier::MoleculeId;
+    use loom_core::state::ActiveMolecule;
+
+    fn fresh_db(workspace: &std::path::Path) -> Result<StateDb> {
+        Ok(StateDb::open(workspace.join(".wrapix/loom/state.db"))?)
+    }
+
+    fn seeded_workspace(dir: &std::path::Path) -> Result<StateDb> {
+        std::fs::create_dir_all(dir.join("specs"))?;
+        std::fs::write(dir.join("specs/loom-harness.md"), "# x\n")?;
+        let db = fresh_db(dir)?;
+        db.rebuild(
+            dir,
+            &[ActiveMolecule {
+                id: MoleculeId::new("wx-3hhwq"),
+                spec_label: SpecLabel::new("loom-harness"),
+                base_commit: None,
+            }],
+        )?;
+        db.set_current_spec(&SpecLabel::new("loom-harness"))?;
+        Ok(db)
+    }
+
+    const SPEC_BEADS: &str = r#"[
+      {"id": "wx-3hhwq", "title": "Epic", "status": "open", "issue_type": "epic",
+       "labels": ["spec:loom-harness", "loom:active"]},
+      {"id": "wx-3hhwq.4", "title": "Parser", "status": "in_progress",
+       "issue_type": "task", "labels": ["spec:loom-harness", "profile:rust"]},
+      {"id": "wx-3hhwq.5", "title": "Docs", "status": "open", "issue_type": "task",
+       "labels": ["spec:loom-harness"]},
+      {"id": "wx-3hhwq.6", "title": "Cross-platform CI", "status": "open",
+       "issue_type": "task", "labels": ["spec:loom-harness", "loom:clarify"]}
+    ]"#;
+
+    const PROGRESS: &str = r#"{"molecule_id": "wx-3hhwq", "molecule_title": "Epic",
+      "completed": 3, "in_progress": 1, "total": 8, "percent": 37.5,
+      "current_step_id": "wx-3hhwq.4"}"#;
+
+    const CLARIFIES: &str = r###"[
+      {"id": "wx-3hhwq.6", "title": "Cross-platform CI", "status": "open",
+       "description": "## Options — Should CI use GitHub Actions or Buildkite?\n",
+       "labels": ["spec:loom-harness", "loom:clarify"],
+       "created_at": "2026-05-03T08:00:00Z", "updated_at": "2026-05-03T10:30:45Z"}
+    ]"###;
+
+    #[test]
+    fn empty_state_reports_unset_spec() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let db = fresh_db(dir.path())?;
+        let report = load(&db)?;
+        assert!(report.current_spec.is_none());
+        assert!(report.molecule.is_none());
+        let body = render(&report);
+        assert!(body.contains("<unset>"), "body: {body}");
+        assert!(body.contains("iteration: 0"), "body: {body}");
+        Ok(())
+    }
+
+    #[test]
+    fn populated_state_reports_label_and_iteration() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let db = seeded_workspace(dir.path())?;
+        db.increment_iteration(&MoleculeId::new("wx-3hhwq"))?;
+        db.increment_iteration(&MoleculeId::new("wx-3hhwq"))?;
+
+        let report = load(&db)?;
+        assert_eq!(report.current_spec.as_deref(), Some("loom-harness"));
+        let mol = report
+            .molecule
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("molecule must be present"))?;
+        assert_eq!(mol.iteration_count, 2);
+
+        let body = render(&report);
+        assert!(body.contains("loom-harness"));
+        assert!(body.contains("wx-3hhwq"));
+        assert!(body.contains("iteration: 2"));
+        Ok(())
+    }
+
+    /// `load` must be safe to call without any explicit lock; the lock-matrix
+    /// row for read-only commands is "no lock acquired". This sanity check
+    /// confirms the function compiles without borrowing a `LockGuard` and
+    /// that an active spec lock does not influence the call.
+    #[test]
+    fn no_lock_required_to_call_load() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let mgr = loom_core::lock::LockManager::new(dir.path())?;
+        let _spec_guard = mgr.acquire_spec(&SpecLabel::new("alpha"))?;
+        let db = fresh_db(dir.path())?;
+        let _ = load(&db)?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn load_live_collects_progress_beads_awaiting_and_locks() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let db = seeded_workspace(dir.path())?;
+        let locks = LockManager::new(dir.path())?;
+        let _running = locks.acquire_spec(&SpecLabel::new("loom-harness"))?;
+        let logs_root = dir.path().join(".wrapix/loom/logs");
+        std::fs::create_dir_all(logs_root.join("loom-harness"))?;
+        std::fs::write(
+            logs_root.join("loom-harness/wx-3hhwq.3-20260503T120000Z.ndjson"),
+            "{\"kind\":\"session_complete\",\"exit_code\":0,\"cost_usd\":null}\n",
+        )?;
+        let bd = BdClient::with_runner(ScriptedRunner::new(&[SPEC_BEADS, PROGRESS, CLARIFIES]));
+        // 2026-05-03T12:30:45Z
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_777_811_445);
+
+        let report = load_live(&db, &bd, &locks, &logs_root, now).await?;
+
+        assert!(report.bd_error.is_none(), "{:?}", report.bd_error);
+        let progress = report
+            .progress
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("progress must be present"))?;
+        assert_eq!((progress.completed, progress.total), (3, 8));
+        let states: Vec<(&str, BeadState)> = report
+            .beads
+            .iter()
+            .map(|r| (r.id.as_str(), r.state))
+            .collect();
+        assert_eq!(
+            states,
+            [
+                ("wx-3hhwq.4", BeadState::Running),
+                ("wx-3hhwq.5", BeadState::Open),
+                ("wx-3hhwq.6", BeadState::Awaiting),
+            ]
+        );
+        assert_eq!(report.beads[0].profile, Some(ProfileName::new("rust")));
+        assert_eq!(report.awaiting.len(), 1);
+        assert_eq!(
+            report.awaiting[0].question.as_deref(),
+            Some("Should CI use GitHub Actions or Buildkite?")
+        );
+        assert_eq!(report.awaiting[0].age, Some(Duration::from_secs(7200)));
+        assert_eq!(report.running, [SpecLabel::new("loom-harness")]);
+        let last = report
+            .last_run
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("last run must be present"))?;
+        assert_eq!(last.bead, BeadId::new("wx-3hhwq.3")?);
+        assert_eq!(last.outcome, AttemptOutcome::Done);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn load_live_degrades_when_bd_fails() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let db = seeded_workspace(dir.path())?;
+        let locks = LockManager::new(dir.path())?;
+        let bd = BdClient::with_runner(ScriptedRunner::new(&[]));
+
+        let report = load_live(
+            &db,
+            &bd,
+            &locks,
+            &dir.path().join(".wrapix/loom/logs"),
+            SystemTime::now(),
+        )
+        .await?;
+
+        assert!(report.molecule.is_some());
+        assert!(report.progress.is_none());
+        let err = report
+            .bd_error
+            .as_deref()
+            .ok_or_else(|| anyhow::anyhow!("bd error must be recorded"))?;
+        assert!(err.contains("no scripted response"), "{err}");
+        Ok(())
+    }
+}
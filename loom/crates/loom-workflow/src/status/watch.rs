@@ -0,0 +1,410 @@
+use std::collections::VecDeque;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+use loom_core::agent::AgentEvent;
+use loom_core::bd::{BdClient, CommandRunner};
+use loom_core::identifier::{BeadId, SpecLabel};
+use loom_core::lock::LockManager;
+use loom_core::logging::{RenderMode, TerminalRenderer};
+use loom_core::state::StateDb;
+use serde_json::Value;
+
+use super::error::StatusError;
+use super::render::render;
+use super::report::{StatusReport, load_live};
+use crate::logs_cmd::{AttemptOutcome, LogReader, OutcomeTracker, list_logs, read_attempt};
+
+/// Rendered lines retained per pane; older lines scroll off.
+const PANE_SCROLLBACK: usize = 200;
+
+/// Narrowest pane before extra slots stop being laid out side by side.
+const MIN_PANE_WIDTH: usize = 30;
+
+/// Gutter drawn between side-by-side panes.
+const PANE_GUTTER: &str = " │ ";
+
+/// Clear the screen and home the cursor before each frame.
+const CLEAR_SCREEN: &str = "\x1b[H\x1b[2J";
+
+/// Knobs for [`watch`].
+#[derive(Debug, Clone, Copy)]
+pub struct WatchOpts {
+    /// Delay between refreshes.
+    pub interval: Duration,
+    /// Terminal size in columns × rows. Frames are clipped to it.
+    pub width: usize,
+    pub height: usize,
+    pub mode: RenderMode,
+}
+
+/// Incremental tail of one per-bead NDJSON log.
+///
+/// Each [`poll`](Self::poll) reads the bytes appended since the last call,
+/// decodes complete lines back into [`AgentEvent`]s, and drives them through
+/// a [`TerminalRenderer`] — so the pane reads exactly like the live `loom
+/// run` output. A trailing partial line is held until its newline arrives.
+pub struct PaneTail {
+    bead: BeadId,
+    reader: LogReader,
+    renderer: TerminalRenderer,
+    rendered: SharedBuf,
+    lines: VecDeque<String>,
+    tracker: OutcomeTracker,
+}
+
+impl PaneTail {
+    pub fn open(bead: BeadId, path: PathBuf, mode: RenderMode) -> Self {
+        let rendered = SharedBuf::default();
+        let renderer = TerminalRenderer::new(rendered.clone(), mode, bead.clone(), false, false);
+        Self {
+            bead,
+            reader: LogReader::open(path),
+            renderer,
+            rendered,
+            lines: VecDeque::new(),
+            tracker: OutcomeTracker::default(),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        self.reader.path()
+    }
+
+    pub fn outcome(&self) -> AttemptOutcome {
+        self.tracker.outcome()
+    }
+
+    /// Read and render everything appended since the previous poll.
+    pub fn poll(&mut self) -> io::Result<()> {
+        for line in self.reader.read_new()? {
+            let Ok(value) = serde_json::from_str::<Value>(&line) else {
+                continue;
+            };
+            self.tracker.observe(&value);
+            if let Ok(event) = serde_json::from_value::<AgentEvent>(value) {
+                self.renderer.render_event(&event)?;
+            }
+        }
+        self.collect_rendered();
+        Ok(())
+    }
+
+    /// Move complete renderer lines into the scrollback. Verbose-mode text
+    /// deltas without a newline stay buffered until one arrives.
+    fn collect_rendered(&mut self) {
+        let mut buf = self.rendered.0.lock().unwrap_or_else(|p| p.into_inner());
+        let Some(end) = buf.iter().rposition(|b| *b == b'\n') else {
+            return;
+        };
+        let complete: Vec<u8> = buf.drain(..=end).collect();
+        drop(buf);
+        for line in String::from_utf8_lossy(&complete).lines() {
+            self.lines.push_back(line.to_string());
+        }
+        while self.lines.len() > PANE_SCROLLBACK {
+            self.lines.pop_front();
+        }
+    }
+
+    fn header(&self) -> String {
+        format!("▸ {}  [{}]", self.bead, self.outcome().as_str())
+    }
+
+    fn tail(&self, n: usize) -> impl Iterator<Item = &str> {
+        self.lines
+            .iter()
+            .skip(self.lines.len().saturating_sub(n))
+            .map(String::as_str)
+    }
+}
+
+/// Logs worth a pane for `spec`.
+///
+/// While the spec lock is held (`running`), every bead whose latest attempt
+/// has no terminal event is a live slot — one under plain `loom run`, up to
+/// N under `--parallel N`. Otherwise, and when no live slot is found, the
+/// single most recent attempt is returned so the view still shows recent
+/// activity.
+pub fn active_logs(
+    logs_root: &Path,
+    spec: &SpecLabel,
+    running: bool,
+) -> Result<Vec<(BeadId, PathBuf)>, StatusError> {
+    let logs = list_logs(logs_root, spec)?;
+    let mut live = Vec::new();
+    if running {
+        let mut seen: Vec<&BeadId> = Vec::new();
+        // Newest first so each bead contributes only its latest attempt.
+        for log in logs.iter().rev() {
+            if seen.contains(&&log.bead) {
+                continue;
+            }
+            seen.push(&log.bead);
+            if let Some(attempt) = read_attempt(&log.path)?
+                && attempt.outcome == AttemptOutcome::Incomplete
+            {
+                live.push((log.bead.clone(), log.path.clone()));
+            }
+        }
+        live.reverse();
+    }
+    if live.is_empty()
+        && let Some(last) = logs.last()
+    {
+        live.push((last.bead.clone(), last.path.clone()));
+    }
+    Ok(live)
+}
+
+/// Compose one dashboard frame: the [`render`]ed status on top, a rule, then
+/// the panes laid out side by side. Every line is clipped to `width` and the
+/// frame to `height` rows; pane bodies get whatever rows the status leaves.
+///
+/// Panes that do not fit at [`MIN_PANE_WIDTH`] are dropped from the right
+/// and counted in the rule so the user knows to widen the terminal.
+pub fn compose_frame(
+    report: &StatusReport,
+    panes: &[PaneTail],
+    width: usize,
+    height: usize,
+) -> String {
+    let width = width.max(MIN_PANE_WIDTH);
+    let status = render(report);
+    let mut rows: Vec<String> = status.lines().map(|l| clip(l, width)).collect();
+
+    let gutter = PANE_GUTTER.chars().count();
+    let fit = ((width + gutter) / (MIN_PANE_WIDTH + gutter)).max(1);
+    let shown = &panes[..panes.len().min(fit)];
+    let hidden = panes.len() - shown.len();
+    let rule = if hidden > 0 {
+        format!("── agent ({hidden} more slot(s) hidden — widen the terminal) ")
+    } else {
+        "── agent ".to_string()
+    };
+    let rule_len = rule.chars().count();
+    rows.push(clip(
+        &format!("{rule}{}", "─".repeat(width.saturating_sub(rule_len))),
+        width,
+    ));
+
+    if shown.is_empty() {
+        rows.push("(no agent activity)".to_string());
+    } else {
+        let body_rows = height.saturating_sub(rows.len() + 1).max(1);
+        let col = (width - gutter * (shown.len() - 1)) / shown.len();
+        let columns: Vec<Vec<String>> = shown
+            .iter()
+            .map(|pane| {
+                let mut lines = vec![pad(&pane.header(), col)];
+                lines.extend(pane.tail(body_rows).map(|l| pad(l, col)));
+                lines
+            })
+            .collect();
+        let depth = columns.iter().map(Vec::len).max().unwrap_or(0);
+        let blank = " ".repeat(col);
+        for i in 0..depth {
+            let cells: Vec<&str> = columns
+                .iter()
+                .map(|c| c.get(i).map(String::as_str).unwrap_or(&blank))
+                .collect();
+            rows.push(cells.join(PANE_GUTTER).trim_end().to_string());
+        }
+    }
+
+    rows.truncate(height.max(1));
+    let mut out = rows.join("\n");
+    out.push('\n');
+    out
+}
+
+/// Run the dashboard until Ctrl-C: refresh the live [`StatusReport`], keep
+/// one [`PaneTail`] per [`active_logs`] entry (reusing tails across frames
+/// so only new bytes are read), and redraw the whole frame to `out`.
+pub async fn watch<R: CommandRunner, W: Write>(
+    db: &StateDb,
+    bd: &BdClient<R>,
+    locks: &LockManager,
+    logs_root: &Path,
+    opts: WatchOpts,
+    out: &mut W,
+) -> Result<(), StatusError> {
+    let mut panes: Vec<PaneTail> = Vec::new();
+    loop {
+        let report = load_live(db, bd, locks, logs_root, SystemTime::now()).await?;
+        let wanted = match report.current_spec.as_deref().map(SpecLabel::new) {
+            Some(spec) => active_logs(logs_root, &spec, report.running.contains(&spec))?,
+            None => Vec::new(),
+        };
+        let mut next = Vec::with_capacity(wanted.len());
+        for (bead, path) in wanted {
+            let pane = match panes.iter().position(|p| p.path() == path) {
+                Some(i) => panes.swap_remove(i),
+                None => PaneTail::open(bead, path, opts.mode),
+            };
+            next.push(pane);
+        }
+        panes = next;
+        for pane in &mut panes {
+            pane.poll()?;
+        }
+
+        let frame = compose_frame(&report, &panes, opts.width, opts.height);
+        out.write_all(CLEAR_SCREEN.as_bytes())?;
+        out.write_all(frame.as_bytes())?;
+        out.flush()?;
+
+        tokio::select! {
+            _ = tokio::time::sleep(opts.interval) => {}
+            _ = tokio::signal::ctrl_c() => return Ok(()),
+        }
+    }
+}
+
+fn clip(line: &str, width: usize) -> String {
+    if line.chars().count() <= width {
+        return line.to_string();
+    }
+    let mut out: String = line.chars().take(width.saturating_sub(1)).collect();
+    out.push('…');
+    out
+}
+
+fn pad(line: &str, width: usize) -> String {
+    let clipped = clip(line, width);
+    let len = clipped.chars().count();
+    format!("{clipped}{}", " ".repeat(width - len))
+}
+
+/// Cloneable in-memory writer handed to each pane's [`TerminalRenderer`].
+#[derive(Clone, Default)]
+struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedBuf {
+    fn write(&mut self, b: &[u8]) -> io::Result<usize> {
+        self.0
+            .lock()
+            .map_err(|_| io::Error::other("poisoned"))?
+            .extend_from_slice(b);
+        Ok(b.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    const TOOL_CALL: &str =
+        r#"{"kind":"tool_call","id":"t1","tool":"Read","params":{"file_path":"src/lib.rs"}}"#;
+
+    fn append(path: &Path, body: &str) -> Result<()> {
+        let mut f = std::fs::File::options()
+            .create(true)
+            .append(true)
+            .open(path)?;
+        f.write_all(body.as_bytes())?;
+        Ok(())
+    }
+
+    #[test]
+    fn pane_renders_only_new_complete_lines() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("wx-1-20260503T120000Z.ndjson");
+        append(&path, &format!("{TOOL_CALL}\n{{\"kind\":\"error\",\"mess"))?;
+        let mut pane = PaneTail::open(BeadId::new("wx-1")?, path.clone(), RenderMode::Default);
+
+        pane.poll()?;
+        let lines: Vec<&str> = pane.tail(10).collect();
+        assert_eq!(lines.len(), 1, "{lines:?}");
+        assert!(lines[0].contains("Read") && lines[0].contains("src/lib.rs"));
+        assert_eq!(pane.outcome(), AttemptOutcome::Incomplete);
+
+        append(&path, "age\":\"boom\"}\n")?;
+        pane.poll()?;
+        let lines: Vec<&str> = pane.tail(10).collect();
+        assert_eq!(lines, [lines[0], "  error: boom"]);
+        assert_eq!(pane.outcome(), AttemptOutcome::Failed);
+        Ok(())
+    }
+
+    #[test]
+    fn active_logs_lists_incomplete_slots_while_running() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let spec = SpecLabel::new("alpha");
+        let spec_dir = dir.path().join("alpha");
+        std::fs::create_dir_all(&spec_dir)?;
+        let done = "{\"kind\":\"session_complete\",\"exit_code\":0,\"cost_usd\":null}\n";
+        // wx-1 retried: the old attempt is incomplete, the latest finished.
+        append(&spec_dir.join("wx-1-20260503T120000Z.ndjson"), "")?;
+        append(&spec_dir.join("wx-1-20260503T121000Z.ndjson"), done)?;
+        append(&spec_dir.join("wx-2-20260503T121500Z.ndjson"), TOOL_CALL)?;
+        append(&spec_dir.join("wx-3-20260503T122000Z.ndjson"), "")?;
+
+        let running = active_logs(dir.path(), &spec, true)?;
+        let beads: Vec<&str> = running.iter().map(|(b, _)| b.as_str()).collect();
+        assert_eq!(beads, ["wx-2", "wx-3"]);
+
+        let idle = active_logs(dir.path(), &spec, false)?;
+        let beads: Vec<&str> = idle.iter().map(|(b, _)| b.as_str()).collect();
+        assert_eq!(beads, ["wx-3"]);
+
+        assert!(active_logs(dir.path(), &SpecLabel::new("beta"), true)?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn frame_lays_panes_side_by_side() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let mut panes = Vec::new();
+        for id in ["wx-1", "wx-2"] {
+            let path = dir.path().join(format!("{id}-20260503T120000Z.ndjson"));
+            append(&path, &format!("{TOOL_CALL}\n"))?;
+            let mut pane = PaneTail::open(BeadId::new(id)?, path, RenderMode::Default);
+            pane.poll()?;
+            panes.push(pane);
+        }
+        let frame = compose_frame(&StatusReport::default(), &panes, 70, 20);
+        let rows: Vec<&str> = frame.lines().collect();
+
+        assert!(rows[0].starts_with("active spec: <unset>"), "{frame}");
+        assert!(rows[3].starts_with("── agent ─"), "{frame}");
+        assert!(rows[4].starts_with("▸ wx-1  [incomplete]"), "{frame}");
+        assert!(rows[4].contains(" │ ▸ wx-2  [incomplete]"), "{frame}");
+        assert_eq!(rows[5].matches("src/lib.rs").count(), 2, "{frame}");
+        assert!(rows.iter().all(|r| r.chars().count() <= 70), "{frame}");
+        Ok(())
+    }
+
+    #[test]
+    fn frame_hides_slots_that_do_not_fit() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let panes: Vec<PaneTail> = ["wx-1", "wx-2", "wx-3"]
+            .iter()
+            .map(|id| -> Result<PaneTail> {
+                Ok(PaneTail::open(
+                    BeadId::new(id)?,
+                    dir.path().join(format!("{id}.ndjson")),
+                    RenderMode::Default,
+                ))
+            })
+            .collect::<Result<_>>()?;
+        let frame = compose_frame(&StatusReport::default(), &panes, 40, 10);
+        assert!(frame.contains("2 more slot(s) hidden"), "{frame}");
+        assert!(frame.contains("▸ wx-1"));
+        assert!(!frame.contains("▸ wx-2"));
+        Ok(())
+    }
+
+    #[test]
+    fn frame_without_panes_says_so() {
+        let frame = compose_frame(&StatusReport::default(), &[], 80, 10);
+        assert!(frame.ends_with("(no agent activity)\n"), "{frame}");
+    }
+}
@@ -1,4 +1,5 @@
 use loom_core::identifier::{MoleculeId, SpecLabel};
+use loom_templates::overlay::ProjectOverlay;
 use loom_templates::todo::{TodoNewContext, TodoUpdateContext};
 
 use super::tier::{DiffCandidate, TierDecision};
@@ -18,6 +19,8 @@ pub struct TemplateBaseFields {
     pub companion_paths: Vec<String>,
     pub implementation_notes: Vec<String>,
     pub exit_signals: String,
+    /// Project prompt fragments for the template's overlay slots.
+    pub overlay: ProjectOverlay,
 }
 
 /// Build the appropriate template context for a given [`TierDecision`].
@@ -41,6 +44,7 @@ pub fn build_template_context(
         companion_paths,
         implementation_notes,
         exit_signals,
+        overlay,
     } = base;
 
     match tier {
@@ -56,6 +60,7 @@ pub fn build_template_context(
                 existing_tasks: None,
                 molecule_id,
                 exit_signals,
+                overlay,
             })
         }
         TierDecision::Tasks { molecule } => TodoTemplateContext::Update(TodoUpdateContext {
@@ -68,6 +73,7 @@ pub fn build_template_context(
             existing_tasks,
             molecule_id: Some(molecule.clone()),
             exit_signals,
+            overlay,
         }),
         TierDecision::New => TodoTemplateContext::New(TodoNewContext {
             pinned_context,
@@ -76,6 +82,7 @@ pub fn build_template_context(
             companion_paths,
             implementation_notes,
             exit_signals,
+            overlay,
         }),
     }
 }
@@ -117,6 +124,7 @@ mod tests {
             companion_paths: vec![],
             implementation_notes: vec![],
             exit_signals: "LOOM_COMPLETE".to_string(),
+            overlay: ProjectOverlay::default(),
         }
     }
 
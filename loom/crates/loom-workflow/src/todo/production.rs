@@ -9,7 +9,7 @@
 
 use std::path::PathBuf;
 
-use loom_core::agent::{RePinContent, SessionOutcome, SpawnConfig};
+use loom_core::agent::{RePinContent, SessionOutcome, SpawnConfig, ToolPolicy};
 use loom_core::identifier::SpecLabel;
 use tracing::info;
 
@@ -55,6 +55,9 @@ impl TodoController for ProductionTodoController {
                 partial_bodies: vec![],
             },
             model: None,
+            thinking: None,
+            command_protocol: None,
+            tool_policy: ToolPolicy::default(),
         })
     }
 
@@ -74,7 +74,7 @@ where
 )]
 mod tests {
     use super::*;
-    use loom_core::agent::RePinContent;
+    use loom_core::agent::{RePinContent, ToolPolicy};
     use std::path::PathBuf;
     use std::sync::atomic::{AtomicU32, Ordering};
 
@@ -106,6 +106,9 @@ mod tests {
                     partial_bodies: vec![],
                 },
                 model: None,
+                thinking: None,
+                command_protocol: None,
+                tool_policy: ToolPolicy::default(),
             })
         }
 
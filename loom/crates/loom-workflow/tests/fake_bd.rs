@@ -0,0 +1,154 @@
+//! End-to-end flows over [`FakeBd`]: the production run controller raising
+//! a clarify and following a bead's override labels, `loom msg` clearing
+//! the clarify, the resume decision that follows and the `loom check`
+//! handoff — all against one in-memory bead graph instead of a real `bd`.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use loom_core::bd::{BdBackend, BdClient, CreateOpts, FakeBd, UpdateOpts};
+use loom_core::identifier::{BeadId, SpecLabel};
+use loom_workflow::msg::{ResumeStep, clear_clarify, resume_step, unblocked_specs};
+use loom_workflow::run::{
+    AgentOutcome, BeadAttempts, ProductionAgentLoopController, RetryPolicy, RunMode, run_loop,
+};
+use tempfile::TempDir;
+
+/// Stub `loom` that records `$LOOM_BD_BACKEND` and its argv to
+/// `<dir>/handoff.log`.
+fn install_loom_stub(dir: &Path) -> Result<PathBuf> {
+    let bin = dir.join("loom-stub");
+    let log = dir.join("handoff.log");
+    std::fs::write(
+        &bin,
+        format!("#!/bin/sh\necho \"$LOOM_BD_BACKEND $@\" > {log:?}\n"),
+    )?;
+    let mut perm = std::fs::metadata(&bin)?.permissions();
+    perm.set_mode(0o755);
+    std::fs::set_permissions(&bin, perm)?;
+    Ok(bin)
+}
+
+async fn seed(bd: &BdClient, title: &str) -> Result<BeadId> {
+    Ok(bd
+        .create(CreateOpts {
+            title: title.into(),
+            description: format!("{title} body"),
+            labels: vec!["spec:alpha".into()],
+            ..CreateOpts::default()
+        })
+        .await?)
+}
+
+#[tokio::test]
+async fn clarify_round_trip_then_check_handoff() -> Result<()> {
+    let dir = TempDir::new()?;
+    let loom_bin = install_loom_stub(dir.path())?;
+    let fake = FakeBd::new();
+    let bd = BdClient::with_runner(BdBackend::Fake(fake.clone()));
+    let bead = seed(&bd, "wire the flag").await?;
+    let label = SpecLabel::new("alpha");
+
+    // The agent always fails, so the retry budget runs out and the bead is
+    // parked with `loom:clarify`.
+    let mut controller = ProductionAgentLoopController::new(
+        BdClient::with_runner(BdBackend::Fake(fake.clone())),
+        label.clone(),
+        loom_bin,
+        dir.path().to_path_buf(),
+        |_, _| async {
+            AgentOutcome::Failure {
+                error: "agent exited with code 1".into(),
+            }
+        },
+    );
+    let summary = run_loop(&mut controller, RunMode::Once, RetryPolicy::default()).await?;
+    assert_eq!((summary.beads_processed, summary.beads_clarified), (1, 1));
+    let parked = fake.bead(&bead).unwrap();
+    assert!(parked.labels.iter().any(|l| l.as_str() == "loom:clarify"));
+    assert_eq!(parked.status, "open");
+
+    clear_clarify(&bd, &bead, "Use --bd-backend").await?;
+    let cleared = fake.bead(&bead).unwrap();
+    assert!(cleared.labels.iter().all(|l| l.as_str() != "loom:clarify"));
+    assert!(
+        cleared.notes.contains("Use --bd-backend"),
+        "{}",
+        cleared.notes
+    );
+
+    let unblocked = unblocked_specs(&bd, std::slice::from_ref(&label)).await?;
+    assert_eq!(unblocked, [SpecLabel::new("alpha")]);
+    assert_eq!(resume_step(&bd, &label).await?, ResumeStep::Run);
+
+    // Once the spec's last bead closes the queue drains and the continuous
+    // loop hands off to `loom check`, passing the backend along.
+    bd.close(&bead, None).await?;
+    let summary = run_loop(&mut controller, RunMode::Continuous, RetryPolicy::default()).await?;
+    assert!(summary.execed_check);
+    let handoff = std::fs::read_to_string(dir.path().join("handoff.log"))?;
+    assert_eq!(handoff, "fake check -s alpha\n");
+    Ok(())
+}
+
+#[tokio::test]
+async fn serial_attempts_follow_the_bead_override_labels() -> Result<()> {
+    let dir = TempDir::new()?;
+    let fake = FakeBd::new();
+    let bd = BdClient::with_runner(BdBackend::Fake(fake.clone()));
+    let bead = seed(&bd, "gnarly").await?;
+    bd.update(
+        &bead,
+        UpdateOpts {
+            add_labels: vec!["model:anthropic/opus".into(), "retries:1".into()],
+            ..UpdateOpts::default()
+        },
+    )
+    .await?;
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let dispatched = Arc::clone(&seen);
+    let mut controller = ProductionAgentLoopController::new(
+        BdClient::with_runner(BdBackend::Fake(fake.clone())),
+        SpecLabel::new("alpha"),
+        install_loom_stub(dir.path())?,
+        dir.path().to_path_buf(),
+        move |_, state: BeadAttempts| {
+            dispatched
+                .lock()
+                .unwrap()
+                .push((state.attempt(), state.model().map(str::to_string)));
+            async {
+                AgentOutcome::Failure {
+                    error: "agent exited with code 1".into(),
+                }
+            }
+        },
+    );
+    let policy = RetryPolicy {
+        max_retries: 3,
+        retry_models: vec!["cheap".into(), "strong".into()],
+    };
+    let summary = run_loop(&mut controller, RunMode::Once, policy).await?;
+
+    let opus = Some("anthropic/opus".to_string());
+    assert_eq!(*seen.lock().unwrap(), [(0, opus.clone()), (1, opus)]);
+    assert_eq!(summary.beads_clarified, 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn closed_spec_resumes_with_check() -> Result<()> {
+    let fake = FakeBd::new();
+    let bd = BdClient::with_runner(BdBackend::Fake(fake));
+    let bead = seed(&bd, "done already").await?;
+    bd.close(&bead, Some("shipped")).await?;
+
+    let label = SpecLabel::new("alpha");
+    assert_eq!(resume_step(&bd, &label).await?, ResumeStep::Check);
+    Ok(())
+}
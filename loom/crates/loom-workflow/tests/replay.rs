@@ -0,0 +1,112 @@
+//! Replay recorded agent wire captures through the real parsers and the
+//! workflow driver — the harness for turning a production failure into a
+//! deterministic regression test. No container or model is involved.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use std::time::SystemTime;
+
+use anyhow::Result;
+use loom_agent::{WireRecording, replay_session};
+use loom_core::identifier::{BeadId, SpecLabel};
+use loom_core::logging::{WireDirection, WireRecord, WireSink, bead_log_path};
+use loom_workflow::{AgentEvent, AgentKind, ProtocolError, drive_session};
+
+const PI_CAPTURE: &str = concat!(
+    r#"{"type":"response","id":"loom-pi-probe","command":"get_commands","success":true,"data":["prompt","steer","abort","set_model"]}"#,
+    "\n",
+    r#"{"type":"agent_start"}"#,
+    "\n",
+    r#"{"type":"tool_execution_start","toolCallId":"tc-1","toolName":"Bash","args":{"command":"cargo test"}}"#,
+    "\n",
+    r#"{"type":"tool_execution_end","toolCallId":"tc-1","toolName":"Bash","result":"1 failed","isError":true}"#,
+    "\n",
+    r#"{"type":"message_update","assistantMessageEvent":{"type":"text_delta","text":"fixing"}}"#,
+    "\n",
+);
+
+#[tokio::test]
+async fn pi_capture_drives_the_same_events_as_production() -> Result<()> {
+    let mut text = PI_CAPTURE.to_string();
+    text.push_str(r#"{"type":"agent_end","messages":[]}"#);
+    let recording = WireRecording::parse(&text);
+    assert_eq!(recording.detect_kind(), Some(AgentKind::Pi));
+
+    let session = replay_session(&recording, AgentKind::Pi).await?;
+    let mut kinds = Vec::new();
+    let outcome = drive_session(session, "replay", |event| {
+        kinds.push(match event {
+            AgentEvent::ToolCall { tool, .. } => format!("call {tool}"),
+            AgentEvent::ToolResult { is_error, .. } => format!("result error={is_error}"),
+            AgentEvent::MessageDelta { text } => format!("text {text}"),
+            AgentEvent::SessionComplete { exit_code, .. } => format!("complete {exit_code}"),
+            other => format!("{other:?}"),
+        });
+    })
+    .await?;
+
+    assert_eq!(outcome.exit_code, 0);
+    assert_eq!(
+        kinds,
+        [
+            "call Bash",
+            "result error=true",
+            "text fixing",
+            "complete 0"
+        ]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn truncated_capture_surfaces_unexpected_eof() -> Result<()> {
+    // An agent that died mid-session: no `agent_end`, stdout just closes.
+    let recording = WireRecording::parse(PI_CAPTURE);
+    let session = replay_session(&recording, AgentKind::Pi).await?;
+    let err = drive_session(session, "replay", |_| {})
+        .await
+        .expect_err("capture has no terminal event");
+    assert!(matches!(err, ProtocolError::UnexpectedEof), "{err:?}");
+    Ok(())
+}
+
+#[tokio::test]
+async fn wire_capture_records_both_directions_and_replays() -> Result<()> {
+    let recording = WireRecording::parse(concat!(
+        r#"{"type":"control_request","id":"req_01","tool":"Read","input":{"path":"/tmp/x"}}"#,
+        "\n",
+        r#"{"type":"result","subtype":"success","total_cost_usd":0.1}"#,
+        "\n",
+    ));
+    let dir = tempfile::tempdir()?;
+    let log_path = bead_log_path(
+        dir.path(),
+        &SpecLabel::new("alpha"),
+        &BeadId::new("wx-1")?,
+        SystemTime::now(),
+    );
+    let sink = WireSink::beside(&log_path)?;
+    let wire_path = sink.path().to_path_buf();
+
+    let session = replay_session(&recording, AgentKind::Claude)
+        .await?
+        .with_wire_capture(sink);
+    drive_session(session, "go", |_| {}).await?;
+
+    let records = WireRecord::parse_log(&std::fs::read_to_string(&wire_path)?);
+    let dirs: Vec<WireDirection> = records.iter().map(|r| r.dir).collect();
+    assert_eq!(
+        dirs,
+        [
+            WireDirection::In,  // prompt
+            WireDirection::Out, // control_request
+            WireDirection::In,  // control_response
+            WireDirection::Out, // result
+        ]
+    );
+    assert!(records[2].line.contains("control_response"), "{records:?}");
+
+    // The capture is itself a replayable recording.
+    assert_eq!(WireRecording::load(&wire_path)?, recording);
+    Ok(())
+}
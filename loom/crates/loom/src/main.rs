@@ -6,29 +6,39 @@
 //! `run`, `check`, `msg`. There is no `sync` or `tune` — Askama compiled
 //! templates make per-project sync unnecessary (see `specs/loom-harness.md`).
 
+use std::io::Write as _;
 use std::path::{Path, PathBuf};
 use std::process::ExitCode;
 
 use clap::{Parser, Subcommand, ValueEnum};
 
-use loom_agent::{ClaudeBackend, PiBackend};
-use loom_core::agent::{AgentKind, ProtocolError, SessionOutcome, SpawnConfig};
-use loom_core::bd::{BdClient, ListOpts, UpdateOpts};
-use loom_core::config::{LoomConfig, Phase};
-use loom_core::identifier::{BeadId, SpecLabel};
+use loom_agent::{ClaudeBackend, CommandBackend, PiBackend, WireRecording, replay_session};
+use loom_core::agent::{AgentKind, ProtocolError, SpawnConfig, ToolPolicy};
+use loom_core::bd::{
+    BdBackend, BdClient, BdStats, Bead, DEFAULT_READ_CACHE_TTL, FAKE_BD_STORE, FakeBd, ListOpts,
+    SqlReader, UpdateOpts,
+};
+use loom_core::config::{BeadsBackend, BeadsConfig, LoomConfig, Phase};
+use loom_core::identifier::{BeadId, ProfileName, SpecLabel};
 use loom_core::lock::LockManager;
-use loom_core::state::StateDb;
+use loom_core::logging::{
+    BeadOutcome, RenderMode, TerminalRenderer, bead_log_path, phase_log_path,
+};
+use loom_core::state::{AttemptRow, StateDb};
 use loom_workflow::check::{IterationCap, ProductionCheckController, check_loop as run_check_loop};
 use loom_workflow::msg::{
-    DISMISS_NOTE, FastReply, build_fast_reply, build_rows, filter_clarifies, resolve_target,
+    DISMISS_NOTE, build_fast_reply, build_rows, clear_clarify, filter_clarifies, resolve_target,
     spec_label_of,
 };
 use loom_workflow::run::{
-    Parallelism, ProductionAgentLoopController, RetryPolicy, RunMode, run_loop,
+    AgentOutcome, BeadAttempts, BeadOverrides, BeadResult, Parallelism,
+    ProductionAgentLoopController, RetryPolicy, RunMode, WorktreeBead, run_loop, timed_out,
 };
-use loom_workflow::run_agent;
 use loom_workflow::todo::{ProductionTodoController, run as run_todo_workflow};
-use loom_workflow::{init, logs_cmd, plan, spec, status, use_spec};
+use loom_workflow::{
+    AgentReply, AgentRun, AttemptHeader, SessionLog, ask_agent, drive_session, run_agent,
+};
+use loom_workflow::{init, logs_cmd, msg, plan, prompt, spec, status, use_spec};
 
 /// Top-level CLI surface.
 #[derive(Debug, Parser)]
@@ -45,18 +55,38 @@ struct Cli {
     #[arg(long, global = true, value_enum, value_name = "BACKEND")]
     agent: Option<AgentBackendArg>,
 
+    /// Bead store to talk to. `bd` (the default) shells out to the real
+    /// CLI; `fake` uses an in-memory bead graph persisted to
+    /// `.wrapix/loom/fake-bd.json`, for demos and tests without Dolt.
+    /// Child `loom` processes inherit the choice.
+    #[arg(
+        long,
+        global = true,
+        value_enum,
+        value_name = "BACKEND",
+        env = BdBackend::ENV,
+        hide_env_values = true,
+        default_value = "bd"
+    )]
+    bd_backend: BdBackendArg,
+
     #[command(subcommand)]
     command: Command,
 }
 
-/// CLI surface for `--agent`. Maps one-to-one with [`AgentKind`] so the
-/// dispatcher does not need to re-parse strings — clap's value-enum
-/// validation owns the rejection of unknown names.
+/// CLI surface for `--agent`. Maps onto [`AgentKind`] so the dispatcher
+/// does not need to re-parse strings — clap's value-enum validation owns
+/// the rejection of unknown names. `[backends.<name>]` command agents are
+/// selected by name in config only. `mock` exists only in `mock`-feature
+/// test builds and is kept out of `--help`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
 #[value(rename_all = "lowercase")]
 enum AgentBackendArg {
     Claude,
     Pi,
+    #[cfg(feature = "mock")]
+    #[value(hide = true)]
+    Mock,
 }
 
 impl From<AgentBackendArg> for AgentKind {
@@ -64,6 +94,67 @@ impl From<AgentBackendArg> for AgentKind {
         match arg {
             AgentBackendArg::Claude => AgentKind::Claude,
             AgentBackendArg::Pi => AgentKind::Pi,
+            #[cfg(feature = "mock")]
+            AgentBackendArg::Mock => AgentKind::Mock,
+        }
+    }
+}
+
+/// CLI surface for `--bd-backend`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "lowercase")]
+enum BdBackendArg {
+    Bd,
+    Fake,
+}
+
+impl BdBackendArg {
+    fn open(self, workspace: &Path) -> anyhow::Result<BdBackend> {
+        Ok(match self {
+            Self::Bd => BdBackend::default(),
+            Self::Fake => BdBackend::Fake(FakeBd::open(workspace.join(FAKE_BD_STORE))?),
+        })
+    }
+}
+
+/// A [`BdClient`] over `backend`, reading over Dolt SQL when `[beads]
+/// backend = "sql"`. The fake backend has no Dolt server behind it, so it
+/// always keeps its reads.
+fn bd_client(workspace: &Path, backend: &BdBackend, beads: &BeadsConfig) -> BdClient {
+    let bd = BdClient::with_runner(backend.clone());
+    match (beads.backend, backend) {
+        (BeadsBackend::Sql, BdBackend::Cli(_)) => bd.with_sql_reads(SqlReader::socket(
+            &workspace.join(&beads.socket),
+            &beads.database,
+        )),
+        _ => bd,
+    }
+}
+
+/// CLI surface for `loom prompt <PHASE>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "lowercase")]
+enum PromptPhaseArg {
+    Plan,
+    Todo,
+    Run,
+    Check,
+    Msg,
+}
+
+/// CLI surface for `loom logs --render`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "lowercase")]
+enum RenderArg {
+    Default,
+    Verbose,
+}
+
+impl From<RenderArg> for RenderMode {
+    fn from(arg: RenderArg) -> Self {
+        match arg {
+            RenderArg::Default => RenderMode::Default,
+            RenderArg::Verbose => RenderMode::Verbose,
         }
     }
 }
@@ -75,26 +166,126 @@ enum Command {
         /// Drop and repopulate the state DB from `specs/*.md` and active beads.
         #[arg(long)]
         rebuild: bool,
+        /// Scaffold `.wrapix/loom/prompts/` with inert prompt-overlay stubs.
+        #[arg(long)]
+        prompts: bool,
+    },
+    /// Print the active spec, molecule progress, bead states, and awaiting clarifies.
+    Status {
+        /// Live dashboard: refresh periodically and tail the active agent log(s).
+        #[arg(long, short = 'w')]
+        watch: bool,
+        /// Seconds between `--watch` refreshes.
+        #[arg(long, value_name = "SECS", default_value = "2", requires = "watch")]
+        interval: u64,
     },
-    /// Print the active spec, current molecule, and iteration counter.
-    Status,
     /// Set the active spec.
     #[command(name = "use")]
     UseSpec {
         /// Spec label (matches `<workspace>/specs/<label>.md`).
         label: String,
     },
-    /// Tail the most recent per-bead NDJSON log.
+    /// Show, follow, render, or filter the per-bead NDJSON logs.
     Logs {
         /// Restrict the search to a specific bead id.
         #[arg(long)]
         bead: Option<String>,
+        /// Restrict the search to one spec's logs.
+        #[arg(long, short = 's')]
+        spec: Option<String>,
+        /// Only logs started within this window (`30m`, `2h`, `1d`) or after a UTC timestamp.
+        #[arg(long, value_name = "WHEN")]
+        since: Option<String>,
+        /// Print the log's events and keep streaming new ones until the attempt completes.
+        #[arg(long, short = 'f')]
+        follow: bool,
+        /// Replay events as `loom run` prints them instead of raw NDJSON.
+        #[arg(
+            long,
+            value_enum,
+            value_name = "MODE",
+            num_args = 0..=1,
+            default_missing_value = "default"
+        )]
+        render: Option<RenderArg>,
+        /// Only show calls to this tool (and their results).
+        #[arg(long, value_name = "NAME")]
+        tool: Option<String>,
+        /// Only show errors: error events, failed tool results, failure digests, non-zero exits.
+        #[arg(long)]
+        errors: bool,
+        /// List every matching attempt with its outcome and duration.
+        #[arg(long, conflicts_with_all = ["follow", "render", "tool", "errors"])]
+        list: bool,
+    },
+    /// Re-run the session driver over a recorded wire capture, without a container.
+    Replay {
+        /// Wire capture (`<bead>-<utc>.wire.jsonl`, or bare agent stdout lines).
+        log: PathBuf,
+        /// Print events as `loom run` would instead of normalized NDJSON.
+        #[arg(
+            long,
+            value_enum,
+            value_name = "MODE",
+            num_args = 0..=1,
+            default_missing_value = "default"
+        )]
+        render: Option<RenderArg>,
     },
     /// Inspect spec annotations and tooling dependencies.
+    #[command(
+        group = clap::ArgGroup::new("checks").multiple(true),
+        group = clap::ArgGroup::new("verifying").multiple(true),
+    )]
     Spec {
         /// Print the unique nixpkgs names referenced by verify/judge tests.
-        #[arg(long)]
+        #[arg(long, conflicts_with = "checks")]
         deps: bool,
+        /// Run `[verify]` tests on the host across all specs (or `-s`).
+        /// Exits non-zero when any check fails.
+        #[arg(long, short = 'v', groups = ["checks", "verifying"])]
+        verify: bool,
+        /// Put `[judge]` rubrics to the check-phase agent across all specs
+        /// (or `-s`). Exits non-zero when any check fails.
+        #[arg(long, short = 'j', group = "checks")]
+        judge: bool,
+        /// Both `--verify` and `--judge`.
+        #[arg(long, short = 'a', groups = ["checks", "verifying"])]
+        all: bool,
+        /// With `--verify` or `-a`: check `- [ ]` criteria whose test passed
+        /// and uncheck ones that failed, rewriting the spec in place and
+        /// printing the diff.
+        #[arg(long, requires = "verifying")]
+        update: bool,
+        /// Restrict the checks to one spec (`specs/<label>.md`).
+        #[arg(long, short = 's', value_name = "LABEL", requires = "checks")]
+        spec: Option<String>,
+        /// Per-check timeout in seconds: one test run or judge session.
+        #[arg(
+            long,
+            value_name = "SECS",
+            default_value_t = spec::DEFAULT_CHECK_TIMEOUT.as_secs(),
+            requires = "checks"
+        )]
+        timeout: u64,
+    },
+    /// Print the prompt and re-pin payload a phase would send, without an agent.
+    Prompt {
+        /// Phase whose prompt to render.
+        #[arg(value_enum)]
+        phase: PromptPhaseArg,
+        /// Bead to render the `run` prompt for (required by `run`, only used there).
+        #[arg(long, value_name = "ID", required_if_eq("phase", "run"))]
+        bead: Option<String>,
+        /// Spec label (defaults to `current_spec`); `msg` covers every spec without it.
+        #[arg(long, short = 's', value_name = "LABEL", conflicts_with = "bead")]
+        spec: Option<String>,
+        /// Render the `run` retry prompt with this file as the previous failure.
+        #[arg(long, value_name = "FILE", requires = "bead")]
+        retry_with: Option<PathBuf>,
+        /// Override the bead's `profile:X` label, as `loom run --profile` does.
+        #[arg(long, value_name = "PROFILE", requires = "bead")]
+        profile: Option<String>,
     },
     /// Interactive spec interview (`-n <label>` new, `-u <label>` update).
     Plan {
@@ -143,6 +334,19 @@ enum Command {
         /// Dismiss the clarify (write canonical note + remove the label).
         #[arg(short = 'd')]
         dismiss: bool,
+        /// Walk every outstanding clarify in an interactive Drafter session.
+        #[arg(
+            long,
+            short = 'c',
+            conflicts_with_all = ["index", "id", "answer", "dismiss"]
+        )]
+        chat: bool,
+        /// Pick answers in a terminal UI: options side pane, editor, dismiss.
+        #[arg(long, conflicts_with_all = ["index", "id", "answer", "dismiss", "chat"])]
+        pick: bool,
+        /// Hand specs left with no clarifies back to `loom run` / `loom check`.
+        #[arg(long)]
+        resume: bool,
     },
     /// Decompose the active spec into beads (four-tier detection).
     Todo {
@@ -176,28 +380,103 @@ fn main() -> ExitCode {
         });
 
     let agent_override = cli.agent.map(AgentKind::from);
+    let backend = match cli.bd_backend.open(&workspace) {
+        Ok(backend) => backend,
+        Err(e) => {
+            eprintln!("loom: {e:#}");
+            return ExitCode::from(1);
+        }
+    };
 
     let result = match cli.command {
-        Command::Init { rebuild } => run_init(&workspace, rebuild),
-        Command::Status => run_status(&workspace),
+        Command::Init { rebuild, prompts } => run_init(&workspace, &backend, rebuild, prompts),
+        Command::Status { watch, interval } => run_status(&workspace, &backend, watch, interval),
         Command::UseSpec { label } => run_use(&workspace, &label),
-        Command::Logs { bead } => run_logs(&workspace, bead.as_deref()),
-        Command::Spec { deps } => run_spec(&workspace, deps),
+        Command::Logs {
+            bead,
+            spec,
+            since,
+            follow,
+            render,
+            tool,
+            errors,
+            list,
+        } => run_logs(
+            &workspace,
+            LogsArgs {
+                bead,
+                spec,
+                since,
+                follow,
+                render,
+                tool,
+                errors,
+                list,
+            },
+        ),
+        Command::Replay { log, render } => run_replay(&log, agent_override, render),
+        Command::Spec {
+            deps,
+            verify,
+            judge,
+            all,
+            update,
+            spec,
+            timeout,
+        } => {
+            let opts = spec::CheckOpts {
+                verify: verify || all,
+                judge: judge || all,
+                timeout: std::time::Duration::from_secs(timeout),
+            };
+            if opts.verify || opts.judge {
+                run_spec_checks(&workspace, spec, opts, update, agent_override)
+            } else {
+                run_spec(&workspace, deps)
+            }
+        }
+        Command::Prompt {
+            phase,
+            bead,
+            spec,
+            retry_with,
+            profile,
+        } => run_prompt(&workspace, &backend, phase, bead, spec, retry_with, profile),
         Command::Plan { new, update } => run_plan(&workspace, new, update),
         Command::Run {
             once,
             parallel,
             profile,
             spec,
-        } => run_run(&workspace, once, parallel, profile, spec, agent_override),
-        Command::Check { spec } => run_check(&workspace, spec),
+        } => run_run(
+            &workspace,
+            &backend,
+            once,
+            parallel,
+            profile,
+            spec,
+            agent_override,
+        ),
+        Command::Check { spec } => run_check(&workspace, &backend, spec),
         Command::Msg {
             spec,
             index,
             id,
             answer,
             dismiss,
-        } => run_msg(&workspace, spec, index, id, answer, dismiss),
+            chat,
+            pick,
+            resume,
+        } => {
+            let cleared = if chat {
+                run_msg_chat(&workspace, &backend, spec)
+            } else if pick {
+                run_msg_pick(&workspace, &backend, spec)
+            } else {
+                run_msg(&workspace, &backend, spec, index, id, answer, dismiss)
+            };
+            cleared.and_then(|specs| resume_unblocked(&workspace, &backend, &specs, resume))
+        }
         Command::Todo { spec, since } => run_todo(&workspace, spec, since, agent_override),
     };
 
@@ -210,17 +489,23 @@ fn main() -> ExitCode {
     }
 }
 
-fn run_init(workspace: &std::path::Path, rebuild: bool) -> anyhow::Result<()> {
+fn run_init(
+    workspace: &std::path::Path,
+    backend: &BdBackend,
+    rebuild: bool,
+    prompts: bool,
+) -> anyhow::Result<()> {
     let molecules = if rebuild {
+        let beads = LoomConfig::load(workspace.join(".wrapix/loom/config.toml"))?.beads;
         let runtime = tokio::runtime::Runtime::new()?;
         runtime.block_on(async {
-            let bd = BdClient::new();
+            let bd = bd_client(workspace, backend, &beads);
             init::fetch_active_molecules(&bd).await
         })?
     } else {
         Vec::new()
     };
-    let report = init::run(workspace, init::InitOpts { rebuild }, &molecules)?;
+    let report = init::run(workspace, init::InitOpts { rebuild, prompts }, &molecules)?;
     println!("loom init: workspace={}", workspace.display());
     println!(
         "  config: {} ({})",
@@ -238,16 +523,117 @@ fn run_init(workspace: &std::path::Path, rebuild: bool) -> anyhow::Result<()> {
             rb.specs, rb.molecules, rb.companions,
         );
     }
+    for path in &report.prompts_created {
+        println!("  prompt overlay: {} (created)", path.display());
+    }
     Ok(())
 }
 
-fn run_status(workspace: &std::path::Path) -> anyhow::Result<()> {
+fn run_prompt(
+    workspace: &Path,
+    backend: &BdBackend,
+    phase: PromptPhaseArg,
+    bead: Option<String>,
+    spec: Option<String>,
+    retry_with: Option<PathBuf>,
+    profile: Option<String>,
+) -> anyhow::Result<()> {
+    if bead.is_some() && phase != PromptPhaseArg::Run {
+        anyhow::bail!("--bead only applies to `loom prompt run`");
+    }
+    let target = match (phase, bead) {
+        (PromptPhaseArg::Run, Some(bead)) => {
+            let previous_failure = match retry_with {
+                Some(path) => Some(
+                    std::fs::read_to_string(&path)
+                        .map_err(|e| anyhow::anyhow!("reading {}: {e}", path.display()))?,
+                ),
+                None => None,
+            };
+            prompt::PreviewTarget::Run {
+                bead: BeadId::new(&bead)?,
+                previous_failure,
+                profile: profile.map(ProfileName::new),
+            }
+        }
+        (PromptPhaseArg::Msg, _) => prompt::PreviewTarget::Msg {
+            spec: spec.map(SpecLabel::new),
+        },
+        (PromptPhaseArg::Plan, _) => prompt::PreviewTarget::Plan {
+            spec: resolve_spec_label(workspace, spec)?,
+        },
+        (PromptPhaseArg::Todo, _) => prompt::PreviewTarget::Todo {
+            spec: resolve_spec_label(workspace, spec)?,
+        },
+        (PromptPhaseArg::Check, _) => prompt::PreviewTarget::Check {
+            spec: resolve_spec_label(workspace, spec)?,
+        },
+        (PromptPhaseArg::Run, None) => anyhow::bail!("`loom prompt run` needs --bead <ID>"),
+    };
+    let beads = LoomConfig::load(workspace.join(".wrapix/loom/config.toml"))
+        .map(|c| c.beads)
+        .unwrap_or_default();
+    let runtime = tokio::runtime::Runtime::new()?;
+    let preview = runtime.block_on(async {
+        let bd = bd_client(workspace, backend, &beads);
+        prompt::preview(workspace, &bd, target).await
+    })?;
+    // Previews get piped into `diff` / `head`; a closed pipe is not an error.
+    let written = writeln!(
+        std::io::stdout().lock(),
+        "{}\n\n----- re-pin payload -----\n\n{}",
+        preview.prompt.trim_end(),
+        preview.repin.to_prompt()
+    );
+    match written {
+        Err(e) if e.kind() != std::io::ErrorKind::BrokenPipe => Err(e.into()),
+        _ => Ok(()),
+    }
+}
+
+fn run_status(
+    workspace: &std::path::Path,
+    backend: &BdBackend,
+    watch: bool,
+    interval: u64,
+) -> anyhow::Result<()> {
     let db = loom_core::state::StateDb::open(workspace.join(".wrapix/loom/state.db"))?;
-    let report = status::load(&db)?;
+    let locks = LockManager::new(workspace)?;
+    let logs_root = workspace.join(".wrapix/loom/logs");
+    let beads = LoomConfig::load(workspace.join(".wrapix/loom/config.toml"))?.beads;
+    let runtime = tokio::runtime::Runtime::new()?;
+    if watch {
+        let opts = status::WatchOpts {
+            interval: std::time::Duration::from_secs(interval.max(1)),
+            width: terminal_dim("COLUMNS", 120),
+            height: terminal_dim("LINES", 40),
+            mode: RenderMode::Default,
+        };
+        runtime.block_on(async {
+            let bd = bd_client(workspace, backend, &beads);
+            let mut stdout = std::io::stdout();
+            status::watch(&db, &bd, &locks, &logs_root, opts, &mut stdout).await
+        })?;
+        return Ok(());
+    }
+    let report = runtime.block_on(async {
+        let bd = bd_client(workspace, backend, &beads);
+        status::load_live(&db, &bd, &locks, &logs_root, std::time::SystemTime::now()).await
+    })?;
     print!("{}", status::render(&report));
     Ok(())
 }
 
+/// Terminal size from the shell's `COLUMNS` / `LINES`, falling back to
+/// `default` when unset or unparsable.
+fn terminal_dim(var: &str, default: usize) -> usize {
+    std::env::var(var)
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .filter(|n| *n > 0)
+        .unwrap_or(default)
+}
+
 fn run_use(workspace: &std::path::Path, label: &str) -> anyhow::Result<()> {
     let label = SpecLabel::new(label);
     let db_path = workspace.join(".wrapix/loom/state.db");
@@ -256,19 +642,145 @@ fn run_use(workspace: &std::path::Path, label: &str) -> anyhow::Result<()> {
     Ok(())
 }
 
-fn run_logs(workspace: &std::path::Path, bead: Option<&str>) -> anyhow::Result<()> {
+/// Parsed `loom logs` flags, bundled to keep [`run_logs`] readable.
+struct LogsArgs {
+    bead: Option<String>,
+    spec: Option<String>,
+    since: Option<String>,
+    follow: bool,
+    render: Option<RenderArg>,
+    tool: Option<String>,
+    errors: bool,
+    list: bool,
+}
+
+fn run_logs(workspace: &std::path::Path, args: LogsArgs) -> anyhow::Result<()> {
     let logs_root = workspace.join(".wrapix/loom/logs");
-    let bead_id = bead.map(BeadId::new).transpose()?;
-    let path = logs_cmd::select_log(
-        &logs_root,
-        logs_cmd::LogsOpts {
-            bead: bead_id.as_ref(),
+    let bead_id = args.bead.as_deref().map(BeadId::new).transpose()?;
+    let spec = args.spec.as_deref().map(SpecLabel::new);
+    let since = args
+        .since
+        .as_deref()
+        .map(|v| logs_cmd::parse_since(v, std::time::SystemTime::now()))
+        .transpose()?;
+    let opts = logs_cmd::LogsOpts {
+        bead: bead_id.as_ref(),
+        spec: spec.as_ref(),
+        since,
+    };
+
+    if args.list {
+        let attempts = logs_cmd::list_attempts(&logs_root, opts)?;
+        if attempts.is_empty() {
+            println!("no logs under {}", logs_root.display());
+        }
+        for attempt in attempts {
+            let duration = attempt
+                .duration
+                .map_or_else(|| "-".to_string(), |d| format!("{}s", d.as_secs()));
+            println!(
+                "{bead:<12} {started}  {outcome:<10} {duration:>7}  {path}",
+                bead = attempt.bead,
+                started = loom_core::logging::format_utc_timestamp(attempt.started),
+                outcome = attempt.outcome.as_str(),
+                path = attempt.path.display(),
+            );
+        }
+        return Ok(());
+    }
+
+    let path = logs_cmd::select_log(&logs_root, opts)?;
+    let streaming = args.follow || args.render.is_some() || args.tool.is_some() || args.errors;
+    if !streaming {
+        println!("{}", path.display());
+        return Ok(());
+    }
+    let style = match args.render {
+        Some(mode) => logs_cmd::StreamStyle::Rendered(mode.into()),
+        None => logs_cmd::StreamStyle::Raw,
+    };
+    logs_cmd::stream_log(
+        &path,
+        logs_cmd::StreamOpts {
+            filter: logs_cmd::EventFilter::new(args.tool, args.errors),
+            style,
+            follow: args.follow,
         },
+        std::io::stdout(),
     )?;
-    println!("{}", path.display());
     Ok(())
 }
 
+fn run_replay(
+    log: &Path,
+    agent_override: Option<AgentKind>,
+    render: Option<RenderArg>,
+) -> anyhow::Result<()> {
+    let recording = WireRecording::load(log)
+        .map_err(|e| anyhow::anyhow!("reading wire capture {}: {e}", log.display()))?;
+    let kind = match agent_override.or_else(|| recording.detect_kind()) {
+        Some(kind) => kind,
+        None => anyhow::bail!(
+            "cannot tell which agent produced {}; pass --agent claude|pi",
+            log.display()
+        ),
+    };
+    let mut renderer = match render {
+        Some(mode) => Some(TerminalRenderer::new(
+            std::io::stdout(),
+            mode.into(),
+            replay_bead(log)?,
+            false,
+            false,
+        )),
+        None => None,
+    };
+    let mut write_err = None;
+    let runtime = tokio::runtime::Runtime::new()?;
+    let outcome = runtime.block_on(async {
+        let session = replay_session(&recording, kind).await?;
+        drive_session(session, "loom replay", |event| {
+            let written = match renderer.as_mut() {
+                Some(r) => r.render_event(event),
+                None => serde_json::to_string(event)
+                    .map_err(std::io::Error::other)
+                    .map(|line| println!("{line}")),
+            };
+            if let Err(e) = written {
+                write_err.get_or_insert(e);
+            }
+        })
+        .await
+    })?;
+    if let Some(e) = write_err {
+        return Err(e.into());
+    }
+    if let Some(renderer) = renderer {
+        let outcome = if outcome.exit_code == 0 {
+            BeadOutcome::Done
+        } else {
+            BeadOutcome::Failed
+        };
+        renderer.finish(outcome)?;
+    }
+    Ok(())
+}
+
+/// Bead id for the replay renderer, taken from a `<bead>-<utc>.wire.jsonl`
+/// file name. Captures named any other way replay as `loom-replay`.
+fn replay_bead(log: &Path) -> anyhow::Result<BeadId> {
+    let from_name = log
+        .file_name()
+        .and_then(|n| n.to_str())
+        .map(|n| n.strip_suffix(".wire.jsonl").unwrap_or(n))
+        .and_then(|stem| stem.rsplit_once('-'))
+        .and_then(|(bead, _)| BeadId::new(bead).ok());
+    match from_name {
+        Some(bead) => Ok(bead),
+        None => Ok(BeadId::new("loom-replay")?),
+    }
+}
+
 fn run_plan(
     workspace: &std::path::Path,
     new: Option<String>,
@@ -300,6 +812,7 @@ fn run_plan(
 
 fn run_run(
     workspace: &Path,
+    backend: &BdBackend,
     once: bool,
     parallel: Parallelism,
     _profile: Option<String>,
@@ -313,8 +826,8 @@ fn run_run(
     let config = LoomConfig::load(workspace.join(".wrapix/loom/config.toml"))?;
     // Resolve the per-phase backend up front so an unknown backend name in
     // the config (or via `--agent` — clap covers the latter) fails before
-    // any work begins. The resolution itself is the wiring; the dispatch
-    // closure handed to the parallel batch driver below is what consumes it.
+    // any work begins. Each attempt resolves it again in `dispatch_attempt`,
+    // adjusted by the bead's labels.
     let _selection = resolved_agent_for(&config, agent_override, Phase::Run)?;
 
     let loom_bin = current_loom_bin()?;
@@ -324,13 +837,22 @@ fn run_run(
         let parallel_n = parallel.get();
         let workspace_buf = workspace.to_path_buf();
         let label_for_async = label.clone();
+        let backend = backend.clone();
         let summary = runtime.block_on(async move {
-            run_parallel_run(workspace_buf, label_for_async, parallel_n, agent_override).await
+            run_parallel_run(
+                workspace_buf,
+                backend,
+                label_for_async,
+                parallel_n,
+                agent_override,
+            )
+            .await
         })?;
         println!(
             "loom run --parallel {parallel_n}: merged {}, conflicted {}, failed {}",
             summary.merged, summary.conflicted, summary.failed,
         );
+        println!("  {}", summary.bd_stats);
         return Ok(());
     }
 
@@ -339,15 +861,40 @@ fn run_run(
     } else {
         RunMode::Continuous
     };
-    let summary = runtime.block_on(async move {
-        let bd = BdClient::new();
+    let (summary, bd_stats) = runtime.block_on(async move {
+        let bd =
+            bd_client(workspace, backend, &config.beads).with_read_cache(DEFAULT_READ_CACHE_TTL);
+        let attempt_workspace = workspace.to_path_buf();
+        let attempt_backend = backend.clone();
+        let attempt_label = label.clone();
         let mut controller = ProductionAgentLoopController::new(
             bd,
             label.clone(),
             loom_bin,
             workspace.to_path_buf(),
+            move |bead: Bead, state: BeadAttempts| {
+                let workspace = attempt_workspace.clone();
+                let backend = attempt_backend.clone();
+                let label = attempt_label.clone();
+                async move {
+                    attempt_outcome(
+                        dispatch_attempt(
+                            &workspace,
+                            &backend,
+                            &label,
+                            agent_override,
+                            &bead,
+                            &workspace,
+                            &state,
+                        )
+                        .await,
+                    )
+                }
+            },
         );
-        run_loop(&mut controller, mode, RetryPolicy::default()).await
+        let policy = RetryPolicy::from_config(&config.loop_);
+        let summary = run_loop(&mut controller, mode, policy).await;
+        summary.map(|s| (s, controller.bd_stats()))
     })?;
     println!(
         "loom run: processed {} bead(s), clarified {}, molecule_complete={}, execed_check={}",
@@ -356,6 +903,16 @@ fn run_run(
         summary.molecule_complete,
         summary.execed_check,
     );
+    for attempt in &summary.attempts {
+        println!(
+            "  {} attempt {}: {} on {}",
+            attempt.bead,
+            attempt.attempt + 1,
+            if attempt.succeeded { "ok" } else { "failed" },
+            attempt.model.as_deref().unwrap_or("phase model"),
+        );
+    }
+    println!("  {bd_stats}");
     Ok(())
 }
 
@@ -365,18 +922,21 @@ struct ParallelRunSummary {
     merged: usize,
     conflicted: usize,
     failed: usize,
+    bd_stats: BdStats,
 }
 
 async fn run_parallel_run(
     workspace: PathBuf,
+    backend: BdBackend,
     label: SpecLabel,
     parallel_n: u32,
     agent_override: Option<AgentKind>,
 ) -> anyhow::Result<ParallelRunSummary> {
     use loom_core::git::GitClient;
-    use loom_workflow::run::{AgentOutcome, run_parallel_batch};
+    use loom_workflow::run::run_parallel_batch;
 
-    let bd = BdClient::new();
+    let config = LoomConfig::load(workspace.join(".wrapix/loom/config.toml"))?;
+    let bd = bd_client(&workspace, &backend, &config.beads);
     let beads = bd
         .ready(loom_core::bd::ReadyOpts {
             limit: Some(parallel_n),
@@ -388,75 +948,297 @@ async fn run_parallel_run(
             merged: 0,
             conflicted: 0,
             failed: 0,
+            bd_stats: bd.stats(),
         });
     }
 
     let git = GitClient::open(workspace.clone())?;
+    let slot_label = label.clone();
+    let policy = RetryPolicy::from_config(&config.loop_);
     let outcome = run_parallel_batch(&git, &label, beads, move |slot| {
         let workspace_inner = workspace.clone();
+        let backend_inner = backend.clone();
+        let label_inner = slot_label.clone();
+        let policy_inner = policy.clone();
         async move {
-            match dispatch_for_slot(&workspace_inner, agent_override, slot).await {
-                Ok(_) => AgentOutcome::Success,
-                Err(e) => AgentOutcome::Failure {
-                    error: format!("{e}"),
-                },
-            }
+            run_slot(
+                &workspace_inner,
+                &backend_inner,
+                &label_inner,
+                agent_override,
+                &policy_inner,
+                slot,
+            )
+            .await
         }
     })
     .await?;
 
+    // Merged beads are done, as a successful serial iteration would have
+    // closed them; one `bd close` covers the whole batch. Failed slots ran
+    // out of retries, so they get `loom:clarify` as a serial bead would —
+    // again in one `bd update`.
+    let merged = outcome.merged_ids();
+    bd.close_many(&merged, None).await?;
+    let failed = outcome.failure_ids();
+    bd.update_many(
+        &failed,
+        UpdateOpts {
+            add_labels: vec!["loom:clarify".to_string()],
+            ..UpdateOpts::default()
+        },
+    )
+    .await?;
+
     Ok(ParallelRunSummary {
-        merged: outcome.merged_ids().len(),
+        merged: merged.len(),
         conflicted: outcome.conflict_ids().len(),
-        failed: outcome.failure_ids().len(),
+        failed: failed.len(),
+        bd_stats: bd.stats(),
     })
 }
 
-/// One slot's dispatch: resolve the per-phase backend and drive a single
-/// agent session against the slot's worktree. Surfaces protocol failures
-/// and config-resolution failures uniformly so the caller can convert them
-/// into [`AgentOutcome::Failure`].
-async fn dispatch_for_slot(
+/// One slot's bead through the same retry state machine as the serial loop
+/// ([`BeadAttempts`]): each failed attempt feeds its failure digest into the
+/// next prompt and climbs the `[loop] retry_models` ladder, within the
+/// bead's `retries:` / `model:` / `timeout:` labels. Retries reuse the
+/// slot's worktree, so an attempt picks up where the last one stopped.
+async fn run_slot(
     workspace: &Path,
+    backend: &BdBackend,
+    label: &SpecLabel,
     agent_override: Option<AgentKind>,
-    slot: loom_workflow::run::WorktreeBead,
-) -> anyhow::Result<SessionOutcome> {
-    use loom_core::agent::RePinContent;
+    policy: &RetryPolicy,
+    slot: WorktreeBead,
+) -> AgentOutcome {
+    let mut state = BeadAttempts::new(&slot.bead, policy);
+    let mut attempts = Vec::new();
+    loop {
+        state.announce();
+        let attempt = dispatch_attempt(
+            workspace,
+            backend,
+            label,
+            agent_override,
+            &slot.bead,
+            &slot.worktree.path,
+            &state,
+        );
+        let run = match state.timeout() {
+            Some(limit) => tokio::time::timeout(limit, attempt)
+                .await
+                .unwrap_or_else(|_| Err(anyhow::anyhow!("{}", timed_out(limit)))),
+            None => attempt.await,
+        };
+        match state.settle(attempt_outcome(run), &mut attempts) {
+            None => {}
+            Some(BeadResult::Done) => return AgentOutcome::Success,
+            Some(BeadResult::Clarified { last_error }) => {
+                return AgentOutcome::Failure { error: last_error };
+            }
+        }
+    }
+}
 
+/// One attempt of a bead, from the serial loop or a `--parallel` slot:
+/// resolve the per-phase backend — adjusted by the bead's `agent:` label,
+/// with `--agent` still winning, and escalated to the attempt's model —
+/// render the bead's run prompt (overlays and the previous attempt's
+/// failure included) with the builder `loom prompt run` previews, and drive
+/// a single agent session in `worktree`. The session's log opens with an
+/// [`AttemptHeader`], and a session that ran is recorded in the state DB's
+/// `attempts` table. The caller bounds the attempt by the bead's `timeout:`
+/// label.
+async fn dispatch_attempt(
+    workspace: &Path,
+    backend: &BdBackend,
+    label: &SpecLabel,
+    agent_override: Option<AgentKind>,
+    bead: &Bead,
+    worktree: &Path,
+    state: &BeadAttempts,
+) -> anyhow::Result<AgentRun> {
     let config = LoomConfig::load(workspace.join(".wrapix/loom/config.toml"))?;
-    let selection = resolved_agent_for(&config, agent_override, Phase::Run)?;
+    let overrides = BeadOverrides::from_labels(&bead.labels);
+    let mut selection =
+        resolved_agent_for(&config, agent_override.or(overrides.agent), Phase::Run)?;
+    if let Some(model) = state.model() {
+        selection.escalate(model);
+    }
 
-    let spawn_config = SpawnConfig {
+    let bd = bd_client(workspace, backend, &config.beads);
+    let compiled = prompt::preview(
+        workspace,
+        &bd,
+        prompt::PreviewTarget::Run {
+            bead: bead.id.clone(),
+            previous_failure: state.previous_failure().map(str::to_string),
+            profile: None,
+        },
+    )
+    .await?;
+    let mut spawn_config = SpawnConfig {
         image: "wrapix-base:latest".to_string(),
-        workspace: slot.worktree.path.clone(),
+        workspace: worktree.to_path_buf(),
         env: vec![],
-        initial_prompt: format!("loom run: bead {}", slot.bead.id),
+        initial_prompt: compiled.prompt,
         agent_args: vec![],
-        repin: RePinContent {
-            orientation: String::new(),
-            pinned_context: String::new(),
-            partial_bodies: vec![],
-        },
+        repin: compiled.repin,
         model: None,
+        thinking: None,
+        command_protocol: None,
+        tool_policy: ToolPolicy::default(),
     };
 
-    Ok(dispatch(selection.kind, &spawn_config).await?)
+    let log = LogTarget::bead(workspace, &config, label, &bead.id).attempt(state);
+    let log_path = log.path.clone();
+    selection.apply_to(&mut spawn_config);
+    let run = dispatch(selection.kind, &spawn_config, log).await?;
+    record_attempt(
+        workspace,
+        &AttemptRow {
+            spec_label: label.clone(),
+            bead: bead.id.clone(),
+            attempt: state.attempt(),
+            model: state.model().map(str::to_string),
+            exit_code: run.outcome.exit_code,
+            cost_usd: run.outcome.cost_usd,
+            log_path,
+        },
+    );
+    Ok(run)
+}
+
+/// How a [`dispatch_attempt`] went, for [`BeadAttempts::settle`]: a session
+/// that ran carries its failure digest; protocol and config-resolution
+/// failures carry their error.
+fn attempt_outcome(run: anyhow::Result<AgentRun>) -> AgentOutcome {
+    match run {
+        Ok(run) => match run.previous_failure() {
+            None => AgentOutcome::Success,
+            Some(error) => AgentOutcome::Failure { error },
+        },
+        Err(e) => AgentOutcome::Failure {
+            error: format!("{e}"),
+        },
+    }
+}
+
+/// Add `row` to the state DB's `attempts` table. Best-effort like the
+/// session log: a DB that cannot take the row is reported, not fatal.
+fn record_attempt(workspace: &Path, row: &AttemptRow) {
+    let recorded = StateDb::open(workspace.join(".wrapix/loom/state.db"))
+        .and_then(|db| db.record_attempt(row));
+    if let Err(e) = recorded {
+        tracing::warn!(bead = %row.bead, error = %e, "attempt not recorded in the state DB");
+    }
+}
+
+/// Where one session's logs go: its NDJSON event log, plus the raw wire
+/// capture beside it when `[logs] raw_wire` is on.
+struct LogTarget {
+    path: PathBuf,
+    raw_wire: bool,
+    /// The `loom run` attempt and model, written as the log's first line.
+    attempt: Option<(u32, Option<String>)>,
+}
+
+impl LogTarget {
+    /// A bead's session: `<spec-label>/<bead-id>-<utc>.ndjson`.
+    fn bead(workspace: &Path, config: &LoomConfig, label: &SpecLabel, bead: &BeadId) -> Self {
+        Self {
+            path: bead_log_path(
+                &workspace.join(".wrapix/loom/logs"),
+                label,
+                bead,
+                std::time::SystemTime::now(),
+            ),
+            raw_wire: config.logs.raw_wire,
+            attempt: None,
+        }
+    }
+
+    /// A session with no bead of its own: `<spec-label>/<phase>-<utc>.ndjson`.
+    fn phase(workspace: &Path, config: &LoomConfig, label: &SpecLabel, phase: &str) -> Self {
+        Self {
+            path: phase_log_path(
+                &workspace.join(".wrapix/loom/logs"),
+                label,
+                phase,
+                std::time::SystemTime::now(),
+            ),
+            raw_wire: config.logs.raw_wire,
+            attempt: None,
+        }
+    }
+
+    /// Head the log with `state`'s attempt and model.
+    fn attempt(self, state: &BeadAttempts) -> Self {
+        Self {
+            attempt: Some((state.attempt(), state.model().map(str::to_string))),
+            ..self
+        }
+    }
+
+    /// Open the logs. They are best-effort: a log that cannot be opened is
+    /// reported and the session runs without it.
+    fn open(self) -> Option<SessionLog> {
+        let mut log = SessionLog::open(self.path, self.raw_wire)
+            .inspect_err(|e| tracing::warn!(error = %e, "session log disabled"))
+            .ok()?;
+        if let Some((attempt, model)) = &self.attempt {
+            let header = AttemptHeader {
+                attempt: *attempt,
+                model: model.as_deref(),
+            };
+            if let Err(e) = log.record(&header) {
+                tracing::warn!(error = %e, "session log disabled");
+                return None;
+            }
+        }
+        Some(log)
+    }
 }
 
 /// Backend-agnostic dispatcher. The match is the only place in the binary
 /// that knows the concrete backend types — `run_agent` is monomorphized once
-/// per arm at compile time, so the workflow modules never see them.
-async fn dispatch(kind: AgentKind, spawn: &SpawnConfig) -> Result<SessionOutcome, ProtocolError> {
+/// per arm at compile time, so the workflow modules never see them. Every
+/// session opens its [`LogTarget`] here.
+async fn dispatch(
+    kind: AgentKind,
+    spawn: &SpawnConfig,
+    log: LogTarget,
+) -> Result<AgentRun, ProtocolError> {
+    let log = log.open();
     match kind {
-        AgentKind::Pi => run_agent::<PiBackend>(spawn).await,
-        AgentKind::Claude => run_agent::<ClaudeBackend>(spawn).await,
+        AgentKind::Pi => run_agent::<PiBackend>(spawn, log).await,
+        AgentKind::Claude => run_agent::<ClaudeBackend>(spawn, log).await,
+        AgentKind::Command => run_agent::<CommandBackend>(spawn, log).await,
+        #[cfg(feature = "mock")]
+        AgentKind::Mock => run_agent::<loom_agent::MockBackend>(spawn, log).await,
+    }
+}
+
+/// [`dispatch`] for sessions whose answer is the agent's text.
+async fn ask_dispatch(
+    kind: AgentKind,
+    spawn: &SpawnConfig,
+    log: LogTarget,
+) -> Result<AgentReply, ProtocolError> {
+    let log = log.open();
+    match kind {
+        AgentKind::Pi => ask_agent::<PiBackend>(spawn, log).await,
+        AgentKind::Claude => ask_agent::<ClaudeBackend>(spawn, log).await,
+        AgentKind::Command => ask_agent::<CommandBackend>(spawn, log).await,
+        #[cfg(feature = "mock")]
+        AgentKind::Mock => ask_agent::<loom_agent::MockBackend>(spawn, log).await,
     }
 }
 
 /// Resolve `phase`'s [`AgentKind`] honoring the global `--agent` override.
 /// CLI override wins over `[agent.<phase>] backend` and `[agent] default`.
 /// Returns the full [`AgentSelection`] so callers retain access to provider /
-/// model / claude_settings.
+/// model / claude_settings / command.
 fn resolved_agent_for(
     config: &LoomConfig,
     agent_override: Option<AgentKind>,
@@ -466,65 +1248,175 @@ fn resolved_agent_for(
     if let Some(kind) = agent_override {
         selection.kind = kind;
         selection.claude_settings = match kind {
-            AgentKind::Claude => Some(loom_core::config::ClaudeSettings {
-                denied_tools: config.security.denied_tools.clone(),
-                post_result_grace_secs: config.claude.post_result_grace_secs,
-            }),
-            AgentKind::Pi => None,
+            AgentKind::Claude => Some(config.claude_settings(phase)?),
+            AgentKind::Pi | AgentKind::Command => None,
+            #[cfg(feature = "mock")]
+            AgentKind::Mock => None,
         };
+        if kind != AgentKind::Command {
+            selection.command = None;
+        }
     }
     Ok(selection)
 }
 
-fn run_check(workspace: &Path, spec: Option<String>) -> anyhow::Result<()> {
+fn run_check(workspace: &Path, backend: &BdBackend, spec: Option<String>) -> anyhow::Result<()> {
     let label = resolve_spec_label(workspace, spec)?;
+    let config = LoomConfig::load(workspace.join(".wrapix/loom/config.toml"))?;
     let lock_mgr = LockManager::new(workspace)?;
     let _guard = lock_mgr.acquire_spec(&label)?;
 
     let loom_bin = current_loom_bin()?;
     let runtime = tokio::runtime::Runtime::new()?;
-    let result = runtime.block_on(async move {
-        let bd = BdClient::new();
+    let (result, bd_stats) = runtime.block_on(async move {
+        let bd =
+            bd_client(workspace, backend, &config.beads).with_read_cache(DEFAULT_READ_CACHE_TTL);
         let mut controller =
             ProductionCheckController::new(bd, label.clone(), loom_bin, workspace.to_path_buf());
-        run_check_loop(&mut controller, IterationCap::default()).await
+        let result = run_check_loop(&mut controller, IterationCap::default()).await;
+        result.map(|r| (r, controller.bd_stats()))
     })?;
     println!("loom check: {result:?}");
+    println!("  {bd_stats}");
     Ok(())
 }
 
 fn run_msg(
     workspace: &Path,
+    backend: &BdBackend,
     spec: Option<String>,
     index: Option<u32>,
     id: Option<String>,
     answer: Option<String>,
     dismiss: bool,
-) -> anyhow::Result<()> {
+) -> anyhow::Result<Vec<SpecLabel>> {
     let spec_filter = spec.as_deref().map(SpecLabel::new);
     if let Some(label) = &spec_filter {
         let lock_mgr = LockManager::new(workspace)?;
         let _guard = lock_mgr.acquire_spec(label)?;
-        run_msg_inner(answer, dismiss, index, id, spec_filter)
+        run_msg_inner(workspace, backend, answer, dismiss, index, id, spec_filter)
     } else {
-        run_msg_inner(answer, dismiss, index, id, None)
+        run_msg_inner(workspace, backend, answer, dismiss, index, id, None)
+    }
+}
+
+fn run_msg_chat(
+    workspace: &Path,
+    backend: &BdBackend,
+    spec: Option<String>,
+) -> anyhow::Result<Vec<SpecLabel>> {
+    let spec_filter = spec.as_deref().map(SpecLabel::new);
+    let lock_mgr = LockManager::new(workspace)?;
+    let _guard = spec_filter
+        .as_ref()
+        .map(|label| lock_mgr.acquire_spec(label))
+        .transpose()?;
+
+    let beads = LoomConfig::load(workspace.join(".wrapix/loom/config.toml"))?.beads;
+    let runtime = tokio::runtime::Runtime::new()?;
+    let report = runtime.block_on(async {
+        let bd = bd_client(workspace, backend, &beads);
+        msg::chat(
+            workspace,
+            &bd,
+            msg::ChatOpts {
+                spec: spec_filter,
+                wrapix_bin: std::env::var_os("LOOM_WRAPIX_BIN").map(PathBuf::from),
+            },
+        )
+        .await
+    })?;
+    if !report.launched {
+        println!("(no outstanding clarifies)");
+        return Ok(Vec::new());
+    }
+    for cleared in &report.resolved {
+        match &cleared.spec {
+            Some(label) => println!(
+                "Clarify cleared on {}. Resume with: loom run -s {label}",
+                cleared.id
+            ),
+            None => println!("Clarify cleared on {}. Resume with: loom run", cleared.id),
+        }
     }
+    println!(
+        "loom msg: resolved {}, {} still outstanding",
+        report.resolved.len(),
+        report.remaining
+    );
+    Ok(report.resolved.into_iter().filter_map(|r| r.spec).collect())
+}
+
+fn run_msg_pick(
+    workspace: &Path,
+    backend: &BdBackend,
+    spec: Option<String>,
+) -> anyhow::Result<Vec<SpecLabel>> {
+    let spec_filter = spec.as_deref().map(SpecLabel::new);
+    let lock_mgr = LockManager::new(workspace)?;
+    let _guard = spec_filter
+        .as_ref()
+        .map(|label| lock_mgr.acquire_spec(label))
+        .transpose()?;
+
+    let beads = LoomConfig::load(workspace.join(".wrapix/loom/config.toml"))?.beads;
+    let mut term = msg::TtyTerminal::open()?;
+    let runtime = tokio::runtime::Runtime::new()?;
+    let report = runtime.block_on(async {
+        let bd = bd_client(workspace, backend, &beads);
+        msg::pick(
+            &bd,
+            msg::PickOpts {
+                spec: spec_filter,
+                width: terminal_dim("COLUMNS", 120),
+                height: terminal_dim("LINES", 40),
+            },
+            &mut term,
+        )
+        .await
+    });
+    // Restore the terminal before printing the summary.
+    drop(term);
+    let report = report?;
+    if !report.launched {
+        println!("(no outstanding clarifies)");
+        return Ok(Vec::new());
+    }
+    for picked in &report.resolved {
+        if picked.dismissed {
+            println!("dismissed {}: {}", picked.id, picked.note);
+        } else {
+            println!("answered {}: {}", picked.id, picked.note);
+        }
+        if let Some(label) = &picked.spec {
+            println!("resume: loom run -s {label}");
+        }
+    }
+    println!(
+        "loom msg: resolved {}, {} still outstanding",
+        report.resolved.len(),
+        report.remaining
+    );
+    Ok(report.resolved.into_iter().filter_map(|r| r.spec).collect())
 }
 
 fn run_msg_inner(
+    workspace: &Path,
+    backend: &BdBackend,
     answer: Option<String>,
     dismiss: bool,
     index: Option<u32>,
     id: Option<String>,
     spec_filter: Option<SpecLabel>,
-) -> anyhow::Result<()> {
+) -> anyhow::Result<Vec<SpecLabel>> {
     if answer.is_some() && dismiss {
         anyhow::bail!("use either -a <choice> or -d, not both");
     }
 
+    let config = LoomConfig::load(workspace.join(".wrapix/loom/config.toml"))?;
+    let bd = bd_client(workspace, backend, &config.beads);
     let runtime = tokio::runtime::Runtime::new()?;
     let beads = runtime.block_on(async {
-        let bd = BdClient::new();
         bd.list(ListOpts {
             status: None,
             label: Some("loom:clarify".to_string()),
@@ -537,7 +1429,7 @@ fn run_msg_inner(
         let rows = build_rows(&kept, spec_filter.as_ref());
         if rows.is_empty() {
             println!("(no outstanding clarifies)");
-            return Ok(());
+            return Ok(Vec::new());
         }
         for row in rows {
             match row.spec {
@@ -548,7 +1440,7 @@ fn run_msg_inner(
                 None => println!("{:>3}. {} {}", row.index, row.bead_id, row.summary),
             }
         }
-        return Ok(());
+        return Ok(Vec::new());
     }
 
     let (target, _pos) = resolve_target(&kept, index, id.as_deref())?;
@@ -558,52 +1450,55 @@ fn run_msg_inner(
         .copied()
         .ok_or_else(|| anyhow::anyhow!("bead {target} not in filtered list"))?;
 
-    if let Some(choice) = answer {
-        let reply = build_fast_reply(&target, &choice, &bead.description)?;
-        let note = match &reply {
-            FastReply::Option { note, .. } => note.clone(),
-            FastReply::Verbatim { note } => note.clone(),
-        };
-        let runtime = tokio::runtime::Runtime::new()?;
-        let id_clone = target.clone();
-        runtime.block_on(async move {
-            let bd = BdClient::new();
-            bd.update(
-                &id_clone,
-                UpdateOpts {
-                    remove_labels: vec!["loom:clarify".to_string()],
-                    ..UpdateOpts::default()
-                },
-            )
-            .await
-        })?;
+    let note = match answer {
+        Some(choice) => build_fast_reply(&target, &choice, &bead.description)?
+            .note()
+            .to_string(),
+        None => DISMISS_NOTE.to_string(),
+    };
+    runtime.block_on(clear_clarify(&bd, &target, &note))?;
+    if dismiss {
+        println!("dismissed {target}: {note}");
+    } else {
         println!("answered {target}: {note}");
-        if let Some(label) = spec_label_of(bead) {
-            println!("resume: loom run -s {label}");
-        }
-        return Ok(());
     }
+    let spec = spec_label_of(bead);
+    if let Some(label) = &spec {
+        println!("resume: loom run -s {label}");
+    }
+    Ok(spec.into_iter().collect())
+}
 
-    if dismiss {
-        let runtime = tokio::runtime::Runtime::new()?;
-        let id_clone = target.clone();
-        runtime.block_on(async move {
-            let bd = BdClient::new();
-            bd.update(
-                &id_clone,
-                UpdateOpts {
-                    remove_labels: vec!["loom:clarify".to_string()],
-                    ..UpdateOpts::default()
-                },
-            )
-            .await
-        })?;
-        println!("dismissed {target}: {DISMISS_NOTE}");
-        if let Some(label) = spec_label_of(bead) {
-            println!("resume: loom run -s {label}");
-        }
+/// After `loom msg` cleared clarifies on `cleared` specs, find the ones with
+/// none left and either print the handoff or, with `--resume`, exec it. Runs
+/// after the msg spec lock is released — the child `loom run` / `loom
+/// check` takes it.
+fn resume_unblocked(
+    workspace: &Path,
+    backend: &BdBackend,
+    cleared: &[SpecLabel],
+    resume: bool,
+) -> anyhow::Result<()> {
+    if cleared.is_empty() {
+        return Ok(());
     }
-    Ok(())
+    let beads = LoomConfig::load(workspace.join(".wrapix/loom/config.toml"))?.beads;
+    let runtime = tokio::runtime::Runtime::new()?;
+    runtime.block_on(async {
+        let bd = bd_client(workspace, backend, &beads);
+        for label in msg::unblocked_specs(&bd, cleared).await? {
+            let step = msg::resume_step(&bd, &label).await?;
+            let command = format!("loom {} -s {label}", step.subcommand());
+            if resume {
+                println!("{label}: no clarifies left, resuming with `{command}`");
+                let loom_bin = current_loom_bin()?;
+                msg::exec_resume(&loom_bin, workspace, backend, &label, step).await?;
+            } else {
+                println!("{label}: no clarifies left; run `{command}` or pass --resume");
+            }
+        }
+        Ok(())
+    })
 }
 
 fn run_todo(
@@ -618,19 +1513,21 @@ fn run_todo(
 
     let config = LoomConfig::load(workspace.join(".wrapix/loom/config.toml"))?;
     let selection = resolved_agent_for(&config, agent_override, Phase::Todo)?;
+    let log = LogTarget::phase(workspace, &config, &label, "todo");
 
     let runtime = tokio::runtime::Runtime::new()?;
     let workspace_buf = workspace.to_path_buf();
     let label_for_async = label.clone();
-    let kind = selection.kind;
     let summary = runtime.block_on(async move {
         let mut controller = ProductionTodoController::new(
             label_for_async,
             workspace_buf,
             "wrapix-base:latest".to_string(),
         );
-        run_todo_workflow(&mut controller, |spawn_cfg| async move {
-            dispatch(kind, &spawn_cfg).await
+        run_todo_workflow(&mut controller, |mut spawn_cfg| {
+            selection.apply_to(&mut spawn_cfg);
+            let kind = selection.kind;
+            async move { dispatch(kind, &spawn_cfg, log).await.map(|run| run.outcome) }
         })
         .await
     })?;
@@ -658,6 +1555,69 @@ fn current_loom_bin() -> anyhow::Result<PathBuf> {
     Ok(std::env::current_exe()?)
 }
 
+fn run_spec_checks(
+    workspace: &Path,
+    spec: Option<String>,
+    opts: spec::CheckOpts,
+    update: bool,
+    agent_override: Option<AgentKind>,
+) -> anyhow::Result<()> {
+    let filter = spec.map(SpecLabel::new);
+    // Only `--judge` spawns agents; plain verify runs need no config.
+    let judge = if opts.judge {
+        let config = LoomConfig::load(workspace.join(".wrapix/loom/config.toml"))?;
+        let selection = resolved_agent_for(&config, agent_override, Phase::Check)?;
+        Some((config, selection))
+    } else {
+        None
+    };
+    let runtime = tokio::runtime::Runtime::new()?;
+    let mut reports = runtime.block_on(spec::check_specs(
+        workspace,
+        filter.as_ref(),
+        opts,
+        |label, mut spawn| {
+            let (kind, log) = match &judge {
+                Some((config, selection)) => {
+                    selection.apply_to(&mut spawn);
+                    let log = LogTarget::phase(workspace, config, label, "judge");
+                    (selection.kind, log)
+                }
+                // Unreachable: `check_specs` only asks when judging.
+                None => (
+                    AgentKind::Claude,
+                    LogTarget::phase(workspace, &LoomConfig::default(), label, "judge"),
+                ),
+            };
+            async move { ask_dispatch(kind, &spawn, log).await }
+        },
+    ))?;
+    // Header molecules are best-effort: a missing or stale state DB only
+    // costs the `(<molecule>)` suffix.
+    if let Ok(db) = StateDb::open(workspace.join(".wrapix/loom/state.db")) {
+        for report in &mut reports {
+            report.molecule = db
+                .active_molecule(&report.label)
+                .ok()
+                .flatten()
+                .map(|m| m.id);
+        }
+    }
+    print!("{}", spec::render(&reports, opts, filter.is_some()));
+    if update {
+        for report in &reports {
+            let rel = format!("specs/{}.md", report.label);
+            let ticks = spec::tick_criteria(&workspace.join(&rel), &report.results)?;
+            print!("{}", spec::render_ticks(&rel, &ticks));
+        }
+    }
+    let failed = spec::Tally::of(&reports).failed;
+    if failed > 0 {
+        anyhow::bail!("{failed} spec check(s) failed");
+    }
+    Ok(())
+}
+
 fn run_spec(workspace: &std::path::Path, deps: bool) -> anyhow::Result<()> {
     let db = loom_core::state::StateDb::open(workspace.join(".wrapix/loom/state.db"))?;
     let label = db
@@ -56,11 +56,21 @@ fn loom_logs_help_snapshot() {
     insta::assert_snapshot!(loom_help(&["logs"]));
 }
 
+#[test]
+fn loom_replay_help_snapshot() {
+    insta::assert_snapshot!(loom_help(&["replay"]));
+}
+
 #[test]
 fn loom_spec_help_snapshot() {
     insta::assert_snapshot!(loom_help(&["spec"]));
 }
 
+#[test]
+fn loom_prompt_help_snapshot() {
+    insta::assert_snapshot!(loom_help(&["prompt"]));
+}
+
 #[test]
 fn loom_plan_help_snapshot() {
     insta::assert_snapshot!(loom_help(&["plan"]));
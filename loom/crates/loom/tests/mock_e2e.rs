@@ -0,0 +1,291 @@
+//! End-to-end `loom run` against the mock agent backend.
+//!
+//! Built only with the `mock` feature (`cargo test --features loom/mock`).
+//! Each test sets up a real git repo, the fake `bd` (`LOOM_BD_BACKEND=fake`)
+//! seeded with two open beads, and `[agent] default = "mock"` with a
+//! scenario that edits a per-bead file and commits it. The loop then runs
+//! unchanged: with `--parallel`, worktree per bead, mock sessions per slot
+//! until one succeeds or the retries run out, merge-back, `bd close`;
+//! serially, the same attempts on the driver branch.
+
+#![cfg(feature = "mock")]
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+const BEADS: [&str; 2] = ["wx-e2e.1", "wx-e2e.2"];
+
+const SCENARIO: &str = r#"{
+    "fail_times": FAIL_TIMES,
+    "steps": [
+        { "say": "Implementing {workspace}." },
+        { "tool": { "name": "bash", "params": { "command": "cargo test" }, "output": "ok" } },
+        { "edit": { "path": "done/{workspace}.txt", "content": "{workspace}\n" } },
+        { "commit": "Implement {workspace}" }
+    ]
+}"#;
+
+/// The two open beads the runs start from, in the fake `bd` store that
+/// `LOOM_BD_BACKEND=fake` reads.
+fn seed_beads(workspace: &Path) {
+    let bead = |id: &str| {
+        serde_json::json!({
+            "id": id,
+            "title": format!("Bead {id}"),
+            "status": "open",
+            "issue_type": "task",
+            "labels": ["spec:loom-harness"],
+        })
+    };
+    let store = serde_json::json!({ "beads": [bead(BEADS[0]), bead(BEADS[1])] });
+    std::fs::write(
+        workspace.join(loom_core::bd::FAKE_BD_STORE),
+        serde_json::to_vec_pretty(&store).unwrap(),
+    )
+    .unwrap();
+}
+
+/// Each seeded bead's status after the run.
+fn bead_statuses(workspace: &Path) -> Vec<(String, String)> {
+    let fake = loom_core::bd::FakeBd::open(workspace.join(loom_core::bd::FAKE_BD_STORE)).unwrap();
+    fake.beads()
+        .into_iter()
+        .map(|b| (b.id.to_string(), b.status))
+        .collect()
+}
+
+fn git(workspace: &Path, args: &[&str]) -> String {
+    let out = Command::new("git")
+        .current_dir(workspace)
+        .args(args)
+        .output()
+        .expect("spawn git");
+    assert!(
+        out.status.success(),
+        "git {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+/// A committed git repo with loom state, config and scenario in place.
+/// `extra_config` is appended to the config, after `[agent]`.
+fn setup(workspace: &Path, fail_times: u32, extra_config: &str) -> PathBuf {
+    git(workspace, &["init", "-q", "-b", "main"]);
+    git(workspace, &["config", "user.name", "loom e2e"]);
+    git(workspace, &["config", "user.email", "e2e@loom.invalid"]);
+    std::fs::write(workspace.join(".gitignore"), ".wrapix/\n").unwrap();
+    git(workspace, &["add", "-A"]);
+    git(workspace, &["commit", "-q", "-m", "init"]);
+
+    std::fs::create_dir_all(workspace.join(".wrapix/loom")).unwrap();
+    let db = loom_core::state::StateDb::open(workspace.join(".wrapix/loom/state.db")).unwrap();
+    db.set_current_spec(&loom_core::identifier::SpecLabel::new("loom-harness"))
+        .unwrap();
+    drop(db);
+    seed_beads(workspace);
+    std::fs::write(
+        workspace.join(".wrapix/loom/config.toml"),
+        format!("[agent]\ndefault = \"mock\"\n{extra_config}"),
+    )
+    .unwrap();
+
+    let scenario = workspace.join(".wrapix/scenario.json");
+    std::fs::write(
+        &scenario,
+        SCENARIO.replace("FAIL_TIMES", &fail_times.to_string()),
+    )
+    .unwrap();
+    scenario
+}
+
+fn loom_run_parallel(workspace: &Path, scenario: &Path) -> Output {
+    loom_run(workspace, scenario, &["--parallel", "2"])
+}
+
+fn loom_run(workspace: &Path, scenario: &Path, args: &[&str]) -> Output {
+    let loom_bin = env!("CARGO_BIN_EXE_loom");
+    Command::new(loom_bin)
+        .arg("--workspace")
+        .arg(workspace)
+        .arg("run")
+        .args(args)
+        .env(loom_core::bd::BdBackend::ENV, "fake")
+        .env("LOOM_BIN", loom_bin)
+        .env(loom_agent::mock::ENV_MOCK_SCENARIO, scenario)
+        .output()
+        .expect("spawn loom")
+}
+
+#[test]
+fn parallel_run_merges_every_mock_bead() {
+    let dir = tempfile::tempdir().unwrap();
+    let workspace = dir.path();
+    let scenario = setup(workspace, 0, "");
+
+    let output = loom_run_parallel(workspace, &scenario);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stdout={stdout} stderr={stderr}");
+    assert!(
+        stdout.contains("merged 2, conflicted 0, failed 0"),
+        "stdout={stdout} stderr={stderr}",
+    );
+    for id in BEADS {
+        let body = std::fs::read_to_string(workspace.join(format!("done/{id}.txt"))).unwrap();
+        assert_eq!(body, format!("{id}\n"));
+    }
+    let log = git(workspace, &["log", "--format=%s"]);
+    assert!(log.contains("Implement wx-e2e.1") && log.contains("Implement wx-e2e.2"));
+    assert_eq!(
+        bead_statuses(workspace),
+        BEADS.map(|id| (id.to_string(), "closed".to_string())),
+    );
+}
+
+#[test]
+fn parallel_run_reports_a_scripted_failure() {
+    let dir = tempfile::tempdir().unwrap();
+    let workspace = dir.path();
+    let scenario = setup(workspace, 1, "[loop]\nmax_retries = 0\n");
+
+    let output = loom_run_parallel(workspace, &scenario);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stdout={stdout} stderr={stderr}");
+    assert!(
+        stdout.contains("merged 1, conflicted 0, failed 1"),
+        "stdout={stdout} stderr={stderr}",
+    );
+    let done: Vec<_> = std::fs::read_dir(workspace.join("done")).unwrap().collect();
+    assert_eq!(done.len(), 1, "only the succeeding slot's edit merges");
+    let mut statuses: Vec<_> = bead_statuses(workspace)
+        .into_iter()
+        .map(|(_, s)| s)
+        .collect();
+    statuses.sort();
+    assert_eq!(statuses, ["closed", "open"]);
+    // The failed slot is out of retries, so it waits on a clarify.
+    let fake = loom_core::bd::FakeBd::open(workspace.join(loom_core::bd::FAKE_BD_STORE)).unwrap();
+    let parked: Vec<_> = fake
+        .beads()
+        .into_iter()
+        .filter(|b| b.labels.iter().any(|l| l.as_str() == "loom:clarify"))
+        .map(|b| b.status)
+        .collect();
+    assert_eq!(parked, ["open"]);
+    assert!(stdout.contains(" mutation(s)"), "stdout={stdout}");
+}
+
+#[test]
+fn parallel_run_retries_a_failed_slot_on_the_next_model() {
+    let dir = tempfile::tempdir().unwrap();
+    let workspace = dir.path();
+    let scenario = setup(
+        workspace,
+        1,
+        "[loop]\nretry_models = [\"cheap\", \"strong\"]\n",
+    );
+
+    let output = loom_run_parallel(workspace, &scenario);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stdout={stdout} stderr={stderr}");
+    assert!(
+        stdout.contains("merged 2, conflicted 0, failed 0"),
+        "stdout={stdout} stderr={stderr}",
+    );
+    assert_eq!(
+        bead_statuses(workspace),
+        BEADS.map(|id| (id.to_string(), "closed".to_string())),
+    );
+
+    let db = loom_core::state::StateDb::open(workspace.join(".wrapix/loom/state.db")).unwrap();
+    let mut attempts: Vec<_> = db
+        .attempts(&loom_core::identifier::SpecLabel::new("loom-harness"))
+        .unwrap()
+        .into_iter()
+        .map(|row| (row.attempt, row.model, row.exit_code, row.log_path))
+        .collect();
+    attempts.sort_by_key(|(attempt, model, exit_code, _)| (*attempt, model.clone(), *exit_code));
+    let summary: Vec<_> = attempts
+        .iter()
+        .map(|(attempt, model, exit_code, _)| (*attempt, model.as_deref(), *exit_code))
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            (0, Some("cheap"), 0),
+            (0, Some("cheap"), 1),
+            (1, Some("strong"), 0)
+        ]
+    );
+
+    // Attempts stamped in the same second share a log file, so look for
+    // each attempt's header rather than at the first line.
+    let failed = std::fs::read_to_string(&attempts[1].3).unwrap();
+    let header = failed
+        .lines()
+        .position(|l| l == r#"{"kind":"attempt","attempt":0,"model":"cheap"}"#);
+    let digest = failed
+        .lines()
+        .position(|l| l.starts_with(r#"{"kind":"failure_digest""#));
+    assert!(header.is_some() && header < digest, "{failed}");
+    let retried = std::fs::read_to_string(&attempts[2].3).unwrap();
+    assert!(
+        retried
+            .lines()
+            .any(|l| l == r#"{"kind":"attempt","attempt":1,"model":"strong"}"#),
+        "{retried}"
+    );
+}
+
+#[test]
+fn serial_run_retries_with_the_failure_digest() {
+    let dir = tempfile::tempdir().unwrap();
+    let workspace = dir.path();
+    let scenario = setup(workspace, 1, "[logs]\nraw_wire = true\n");
+
+    let output = loom_run(workspace, &scenario, &["--once"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stdout={stdout} stderr={stderr}");
+    assert!(
+        stdout.contains("processed 1 bead(s), clarified 0"),
+        "stdout={stdout} stderr={stderr}",
+    );
+    let mut statuses: Vec<_> = bead_statuses(workspace)
+        .into_iter()
+        .map(|(_, s)| s)
+        .collect();
+    statuses.sort();
+    assert_eq!(statuses, ["closed", "open"]);
+
+    // The retry's prompt, as written to the agent's stdin, carries the
+    // digest of the failed first session.
+    let logs = workspace.join(".wrapix/loom/logs/loom-harness");
+    let prompts: Vec<String> = std::fs::read_dir(&logs)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.to_string_lossy().ends_with(".wire.jsonl"))
+        .flat_map(|path| {
+            let capture = std::fs::read_to_string(path).unwrap();
+            capture
+                .lines()
+                .map(|l| serde_json::from_str::<serde_json::Value>(l).unwrap())
+                .filter(|record| record["dir"] == "in")
+                .map(|record| record["line"].as_str().unwrap().to_string())
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    assert_eq!(prompts.len(), 2, "{prompts:?}");
+    assert_eq!(
+        prompts
+            .iter()
+            .filter(|p| p.contains("The previous attempt exited with code 1."))
+            .count(),
+        1,
+        "{prompts:?}"
+    );
+}
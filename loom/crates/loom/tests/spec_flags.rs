@@ -0,0 +1,40 @@
+//! CLI surface tests for `loom spec --update`: clap accepts it alongside
+//! either mode that runs `[verify]` tests (`--verify` or `-a`) and rejects
+//! it on its own.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use std::process::{Command, Output};
+
+/// `loom spec <args>` in a workspace with an empty `specs/`.
+fn loom_spec(args: &[&str]) -> Output {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir(dir.path().join("specs")).unwrap();
+    Command::new(env!("CARGO_BIN_EXE_loom"))
+        .arg("--workspace")
+        .arg(dir.path())
+        .arg("spec")
+        .args(args)
+        .output()
+        .expect("spawn loom")
+}
+
+#[test]
+fn update_is_accepted_with_verify_or_all() {
+    for args in [["--verify", "--update"], ["-a", "--update"]] {
+        let output = loom_spec(&args);
+        assert!(
+            output.status.success(),
+            "{args:?}: stderr={}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+}
+
+#[test]
+fn update_alone_is_rejected() {
+    let output = loom_spec(&["--update"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(output.status.code(), Some(2), "stderr={stderr}");
+    assert!(stderr.contains("<--verify|--all>"), "stderr={stderr}");
+}
@@ -259,6 +259,19 @@ test_cargo_test() {
     cargo_run test --workspace
 }
 
+#-----------------------------------------------------------------------------
+# test_mock_run_end_to_end — `loom run --parallel 2` with `[agent] default =
+# "mock"` against a real git repo and the fake `bd`: both scripted sessions
+# edit and commit in their worktrees, merge back and close their beads; a
+# `fail_times` scenario retries the failed slot, or reports and parks it
+# when retries are off. Serially, `loom run --once` retries on the driver
+# branch with the failure digest in the next prompt. Needs the `mock` cargo
+# feature.
+#-----------------------------------------------------------------------------
+test_mock_run_end_to_end() {
+    cargo_run test -p loom --features mock --test mock_e2e --quiet
+}
+
 #-----------------------------------------------------------------------------
 # Helpers for wrapix run-bead acceptance tests.
 #
@@ -1132,7 +1145,9 @@ test_run_profile_selection() {
 #-----------------------------------------------------------------------------
 # test_run_retry_with_context — a failing bead retries with `previous_failure`
 # threaded into the next attempt, gives up after `max_retries`, and the
-# RetryPolicy decision math is asserted directly.
+# RetryPolicy decision math is asserted directly. The production serial
+# controller hands each attempt's label-adjusted model and budget to the
+# binary's dispatch (over the fake bd).
 #-----------------------------------------------------------------------------
 test_run_retry_with_context() {
     run_cargo_test run::retry::tests::default_policy_is_two_retries
@@ -1141,10 +1156,24 @@ test_run_retry_with_context() {
     run_cargo_test run::retry::tests::zero_retries_gives_up_immediately
     run_cargo_test run::runner::tests::failed_bead_retries_with_previous_failure_then_clarifies
     run_cargo_test run::runner::tests::retry_succeeds_within_budget_and_closes
+    run_cargo_test run::runner::tests::bead_attempts_take_the_retries_label_over_the_policy
+    cargo_run test -p loom-workflow --test fake_bd serial_attempts_follow_the_bead_override_labels -- --exact --nocapture --quiet
     run_cargo_test run::context::tests::retry_input_wraps_previous_failure
     run_cargo_test run::context::tests::rendered_retry_prompt_includes_previous_failure_body
 }
 
+#-----------------------------------------------------------------------------
+# test_run_retry_models — `[loop] retry_models` moves each retry up the
+# ladder (the last rung repeats), the summary records each attempt's model,
+# and the state DB keeps a row per attempt with its model and cost.
+#-----------------------------------------------------------------------------
+test_run_retry_models() {
+    run_cargo_test run::retry::tests::retries_climb_the_model_ladder_and_stay_on_the_last_rung
+    run_cargo_test run::runner::tests::retries_escalate_along_the_model_ladder
+    run_cargo_test run::runner::tests::bead_attempts_carry_the_failure_and_climb_the_ladder_until_giving_up
+    state_db_cargo_test state_attempts_record_model_and_cost_per_spec
+}
+
 #-----------------------------------------------------------------------------
 # test_run_execs_check — molecule completion in continuous mode triggers
 # exactly one `loom check` exec; once mode never does.
@@ -1733,6 +1762,75 @@ test_claude_permission_autoapprove() {
         claude::parser::tests::control_request_denylist_does_not_affect_other_tools
 }
 
+#-----------------------------------------------------------------------------
+# test_claude_tool_policy — `[security]` rules compile into the claude
+# parser's `ToolGate`: path globs (normalized, any depth), Bash prefixes and
+# regexes, first match wins, phase-scoped rules, malformed patterns rejected
+# at `agent_for`, and a denial carries its reason in `control_response` and
+# as an `AgentEvent::Error`.
+#-----------------------------------------------------------------------------
+test_claude_tool_policy() {
+    cargo_run test -p loom-core --lib --quiet -- \
+        agent::policy::tests:: \
+        config::tests::security_rules_apply_only_in_their_phases \
+        config::tests::agent_for_rejects_a_malformed_security_rule \
+        config::agent::tests::apply_to_claude_carries_the_tool_policy &&
+    cargo_run test -p loom-agent --lib --quiet -- \
+        claude::parser::tests::control_request_denied_by_path_rule_returns_its_reason
+}
+
+#-----------------------------------------------------------------------------
+# test_agent_model_params — `[agent.<phase>] model_id` / `thinking` /
+# `max_turns` reach each backend in the form it takes. For claude the
+# WRAPIX_DRY_RUN wrapper shows the container command: `loom --agent claude
+# todo` runs against a wrapix shim that keeps the spawn config it was handed,
+# then the real `wrapix run-bead` prints the claude stream-json invocation
+# with `--model` / `--max-turns` after it — never the flags on their own.
+#-----------------------------------------------------------------------------
+test_agent_model_params() {
+    cargo_run test -p loom-core --lib --quiet -- \
+        config::agent::tests::apply_to_claude_sets_flags_and_thinking_budget \
+        config::agent::tests::apply_to_pi_sets_model_and_thinking_rpcs_only || return 1
+    cargo_run test -p loom-agent --lib --quiet -- \
+        pi::backend::tests::set_thinking_level_reaches_mock_pi \
+        pi::backend::tests::rejected_set_thinking_level_does_not_fail_the_spawn \
+        pi::backend::tests::unlisted_set_thinking_level_is_skipped || return 1
+
+    local sandbox tmp loom cmd expected
+    sandbox=$(wrapix_bin)
+    cargo_run build -p loom --quiet
+    loom="${CARGO_TARGET_DIR:-$LOOM_DIR/target}/debug/loom"
+    tmp=$(mktemp -d)
+    trap 'rm -rf "$tmp"' RETURN
+
+    mkdir -p "$tmp/ws/.wrapix/loom"
+    cat >"$tmp/ws/.wrapix/loom/config.toml" <<'EOF'
+[agent.todo]
+model_id = "claude-opus-4"
+max_turns = 7
+EOF
+    cat >"$tmp/wrapix" <<EOF
+#!/usr/bin/env bash
+prev=''
+for a in "\$@"; do
+    [ "\$prev" = --spawn-config ] && cp "\$a" "$tmp/spawn.json"
+    prev="\$a"
+done
+EOF
+    chmod +x "$tmp/wrapix"
+    # The shim answers nothing, so the session itself fails; only the spawn
+    # config it was handed matters here.
+    LOOM_WRAPIX_BIN="$tmp/wrapix" timeout 60 "$loom" --workspace "$tmp/ws" \
+        --agent claude todo -s loom-agent >/dev/null 2>&1 || true
+    [ -f "$tmp/spawn.json" ] || { echo "loom never handed wrapix a spawn config" >&2; return 1; }
+
+    cmd=$(WRAPIX_DRY_RUN=1 "$sandbox/bin/wrapix" \
+        run-bead --spawn-config "$tmp/spawn.json" --stdio | sed -n 's/^CMD=//p' | tr '\n' ' ')
+    expected="claude --print --verbose --input-format stream-json --output-format stream-json"
+    expected+=" --permission-prompt-tool stdio --model claude-opus-4 --max-turns 7 "
+    [ "$cmd" = "$expected" ] || { echo "container command: $cmd" >&2; return 1; }
+}
+
 #-----------------------------------------------------------------------------
 # test_claude_repin_files — `ClaudeBackend` writes `repin.sh` and
 # `claude-settings.json` under `<workspace>/.wrapix/loom/runtime/` plus the
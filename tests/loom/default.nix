@@ -67,7 +67,13 @@ let
 
     useNextest = true;
     nativeCheckInputs = [ pkgs.git ];
-    cargoTestFlags = [ "--workspace" ];
+    # `loom/mock` builds the scripted mock agent backend so the
+    # `loom run --parallel` end-to-end tests (tests/mock_e2e.rs) run here.
+    cargoTestFlags = [
+      "--workspace"
+      "--features"
+      "loom/mock"
+    ];
     doCheck = true;
 
     # This derivation exists for its tests; the binary is built and
@@ -27,6 +27,17 @@
 #                               set_model) respond ok and echo the
 #                               provider/modelId pair into a later
 #                               message_delta after the prompt.
+#   thinking-ok               — probe ok, listing set_thinking_level;
+#                               answer it ok and echo its level into a
+#                               message_delta after the prompt.
+#   thinking-rejected         — probe ok, listing set_thinking_level;
+#                               reject it (a provider without reasoning
+#                               levels), then serve the prompt as
+#                               happy-path does.
+#   thinking-unlisted         — probe ok without set_thinking_level; a
+#                               prompt as the next command echoes
+#                               "thinking:skipped"; anything else goes
+#                               unanswered, as an old pi would.
 #   happy-path                — probe ok, prompt → message_delta →
 #                               agent_end. Used by the container smoke
 #                               and any test that wants the full
@@ -88,9 +99,10 @@ emit_compaction_end() {
 }
 
 # Read the first command (must be get_commands) and either echo a full
-# command set, or omit set_model when the first arg is "1".
+# command set, omit set_model when the first arg is "1", or add
+# set_thinking_level when it is "thinking".
 handle_probe() {
-    local missing_set_model="${1:-0}"
+    local variant="${1:-0}"
     local probe_line probe_id data
     IFS= read -r probe_line
     probe_id="$(extract_field id "$probe_line")"
@@ -98,8 +110,10 @@ handle_probe() {
         echo "mock-pi: probe missing id field" >&2
         exit 2
     fi
-    if [ "$missing_set_model" = "1" ]; then
+    if [ "$variant" = "1" ]; then
         data='["prompt","steer","abort"]'
+    elif [ "$variant" = "thinking" ]; then
+        data='["prompt","steer","abort","set_model","set_thinking_level","compact","get_session_stats"]'
     else
         data='["prompt","steer","abort","set_model","compact","get_session_stats"]'
     fi
@@ -173,6 +187,43 @@ run_set_model() {
     emit_agent_end
 }
 
+run_thinking() {
+    local reject="$1"
+    handle_probe thinking
+    local line st_id st_type level _prompt
+    IFS= read -r line
+    st_id="$(extract_field id "$line")"
+    st_type="$(extract_field type "$line")"
+    level="$(extract_field level "$line")"
+    if [ "$st_type" != "set_thinking_level" ]; then
+        emit_response_err "${st_id:-unknown}" "${st_type:-unknown}" "expected set_thinking_level"
+        return
+    fi
+    if [ "$reject" = "1" ]; then
+        emit_response_err "$st_id" "set_thinking_level" "provider does not support thinking levels"
+        level="default"
+    else
+        emit_response_ok "$st_id" "set_thinking_level"
+    fi
+
+    IFS= read -r _prompt
+    emit_message_delta "thinking:${level}"
+    emit_agent_end
+}
+
+run_thinking_unlisted() {
+    handle_probe 0
+    local line
+    IFS= read -r line
+    if [ "$(extract_field type "$line")" != "prompt" ]; then
+        # An old pi drops commands it does not know without answering;
+        # exit rather than hang so the test fails fast.
+        exit 1
+    fi
+    emit_message_delta "thinking:skipped"
+    emit_agent_end
+}
+
 case "$MODE" in
     probe-ok)
         run_probe_ok
@@ -192,6 +243,15 @@ case "$MODE" in
     set-model)
         run_set_model
         ;;
+    thinking-ok)
+        run_thinking 0
+        ;;
+    thinking-rejected)
+        run_thinking 1
+        ;;
+    thinking-unlisted)
+        run_thinking_unlisted
+        ;;
     happy-path)
         run_happy_path
         ;;